@@ -0,0 +1,38 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fmt;
+
+/// The indentation of one level of nesting in printed Leo code.
+const INDENT: &str = "    ";
+
+/// Displays the wrapped value with each of its lines indented by one level.
+/// Used to print the bodies of blocks, functions, structs, and program scopes.
+pub struct Indent<T>(pub T);
+
+impl<T: fmt::Display> fmt::Display for Indent<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, line) in self.0.to_string().lines().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            if !line.is_empty() {
+                write!(f, "{INDENT}{line}")?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -17,6 +17,9 @@
 pub mod identifier;
 pub use identifier::*;
 
+pub mod indent_display;
+pub use indent_display::*;
+
 pub mod imported_modules;
 pub use imported_modules::*;
 
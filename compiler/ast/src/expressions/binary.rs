@@ -177,7 +177,20 @@ pub struct BinaryExpression {
 
 impl fmt::Display for BinaryExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} {} {}", self.left, self.op, self.right)
+        use BinaryOperation::*;
+        match self.op {
+            // These operators are only written as method calls, e.g. `a.add_wrapped(b)`.
+            AddWrapped | DivWrapped | Mod | MulWrapped | Nand | Nor | PowWrapped | RemWrapped | ShlWrapped
+            | ShrWrapped | SubWrapped => {
+                let method = match self.op {
+                    Nand => "nand".to_string(),
+                    Nor => "nor".to_string(),
+                    op => op.to_string(),
+                };
+                write!(f, "{}.{method}({})", Operand(&self.left), self.right)
+            }
+            _ => write!(f, "{} {} {}", Operand(&self.left), self.op, Operand(&self.right)),
+        }
     }
 }
 
@@ -18,6 +18,35 @@ use super::*;
 
 use crate::Type;
 
+/// The semantics of a cast expression.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CastKind {
+    /// A checked cast, e.g. `x as u8`, which fails if the value does not fit in the target type.
+    #[default]
+    Checked,
+    /// A lossy cast, e.g. `x as_lossy u8`, which truncates the value to fit in the target type.
+    Lossy,
+    /// A saturating cast, e.g. `x as_saturating u8`, which clamps the value to the bounds of the target type.
+    Saturating,
+}
+
+impl CastKind {
+    /// Returns `true` if the cast is a checked cast.
+    pub fn is_checked(&self) -> bool {
+        matches!(self, CastKind::Checked)
+    }
+}
+
+impl fmt::Display for CastKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CastKind::Checked => write!(f, "as"),
+            CastKind::Lossy => write!(f, "as_lossy"),
+            CastKind::Saturating => write!(f, "as_saturating"),
+        }
+    }
+}
+
 /// A cast expression, e.g. `42u8 as u16`.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CastExpression {
@@ -25,6 +54,10 @@ pub struct CastExpression {
     pub expression: Box<Expression>,
     /// The type to be casted to, e.g. `u16` in `42u8 as u16`.
     pub type_: Type,
+    /// The kind of the cast, e.g. `as_lossy` in `256u16 as_lossy u8`.
+    /// Note that checked casts are not serialized, so that existing ASTs are unchanged.
+    #[serde(default, skip_serializing_if = "CastKind::is_checked")]
+    pub kind: CastKind,
     /// Span of the entire cast `42u8 as u16`.
     pub span: Span,
     /// The ID of the node.
@@ -33,7 +66,7 @@ pub struct CastExpression {
 
 impl fmt::Display for CastExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({} as {})", self.expression, self.type_)
+        write!(f, "{} {} {}", Operand(&self.expression), self.kind, self.type_.to_leo_string())
     }
 }
 
@@ -50,7 +50,7 @@ impl fmt::Display for Literal {
             Self::Group(group) => write!(f, "{group}group"),
             Self::Integer(type_, value, _, _) => write!(f, "{value}{type_}"),
             Self::Scalar(scalar, _, _) => write!(f, "{scalar}scalar"),
-            Self::String(string, _, _) => write!(f, "\"{string}\""),
+            Self::String(string, _, _) => write!(f, "{string:?}"),
         }
     }
 }
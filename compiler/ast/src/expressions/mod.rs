@@ -186,3 +186,18 @@ impl fmt::Display for Expression {
         }
     }
 }
+
+/// Displays an operand of an operator, parenthesizing the operands that the operator would otherwise bind to first.
+pub(crate) struct Operand<'a>(pub(crate) &'a Expression);
+
+impl fmt::Display for Operand<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Expression::Binary(_) | Expression::Cast(_) => write!(f, "({})", self.0),
+            Expression::Unary(unary) if matches!(unary.op, UnaryOperation::Negate | UnaryOperation::Not) => {
+                write!(f, "({})", self.0)
+            }
+            expression => expression.fmt(f),
+        }
+    }
+}
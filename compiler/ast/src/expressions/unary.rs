@@ -92,7 +92,15 @@ pub struct UnaryExpression {
 
 impl fmt::Display for UnaryExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}{}", self.op.as_str(), self.receiver)
+        match self.op {
+            // The parser folds the negation of a literal into the literal, so it is written as a method call.
+            UnaryOperation::Negate if matches!(*self.receiver, Expression::Literal(_)) => {
+                write!(f, "({}).neg()", self.receiver)
+            }
+            UnaryOperation::Negate => write!(f, "-{}", Operand(&self.receiver)),
+            UnaryOperation::Not => write!(f, "!{}", Operand(&self.receiver)),
+            op => write!(f, "{}.{}()", Operand(&self.receiver), op.as_str()),
+        }
     }
 }
 
@@ -21,12 +21,15 @@ use leo_span::Span;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-/// An annotation, e.g. @program.
+/// An annotation, e.g. @program or @inline(never).
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Annotation {
     // TODO: Consider using a symbol instead of an identifier.
     /// The name of the annotation.
     pub identifier: Identifier,
+    /// The arguments of the annotation, if any.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub arguments: Vec<Identifier>,
     /// A span locating where the annotation occurred in the source.
     pub span: Span,
     /// The ID of the node.
@@ -37,6 +40,14 @@ simple_node_impl!(Annotation);
 
 impl fmt::Display for Annotation {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "@{}", self.identifier)
+        write!(f, "@{}", self.identifier)?;
+        if !self.arguments.is_empty() {
+            write!(
+                f,
+                "({})",
+                self.arguments.iter().map(|argument| argument.to_string()).collect::<Vec<_>>().join(", ")
+            )?;
+        }
+        Ok(())
     }
 }
@@ -113,7 +113,10 @@ pub struct FunctionInput {
 
 impl FunctionInput {
     fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} {}: {}", self.mode, self.identifier, self.type_)
+        match self.mode {
+            Mode::None => write!(f, "{}: {}", self.identifier, self.type_.to_leo_string()),
+            _ => write!(f, "{} {}: {}", self.mode, self.identifier, self.type_.to_leo_string()),
+        }
     }
 }
 
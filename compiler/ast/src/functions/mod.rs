@@ -120,6 +120,9 @@ impl Function {
     /// Private formatting method used for optimizing [fmt::Debug] and [fmt::Display] implementations.
     ///
     fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for annotation in self.annotations.iter() {
+            writeln!(f, "{annotation}")?;
+        }
         match self.variant {
             Variant::Inline => write!(f, "inline ")?,
             Variant::Standard => write!(f, "function ")?,
@@ -127,19 +130,17 @@ impl Function {
         }
         write!(f, "{}", self.identifier)?;
 
-        let parameters = self.input.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",");
+        let parameters = self.input.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
         let returns = match self.output.len() {
-            0 => "()".to_string(),
-            1 => self.output[0].to_string(),
-            _ => self.output.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            0 => String::new(),
+            1 => format!(" -> {}", self.output[0]),
+            _ => format!(" -> ({})", self.output.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ")),
         };
-        write!(f, "({parameters}) -> {returns} {}", self.block)?;
+        write!(f, "({parameters}){returns} {}", self.block)?;
 
-        if let Some(finalize) = &self.finalize {
-            let parameters = finalize.input.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",");
-            write!(f, " finalize ({parameters}) {}", finalize.block)
-        } else {
-            Ok(())
+        match &self.finalize {
+            Some(finalize) => write!(f, "\n{finalize}"),
+            None => Ok(()),
         }
     }
 }
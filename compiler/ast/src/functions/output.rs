@@ -47,7 +47,8 @@ impl fmt::Display for Output {
         use Output::*;
         match self {
             Internal(output) => output.fmt(f),
-            External(output) => output.fmt(f),
+            // An external output has no name.
+            External(output) => write!(f, "{}.leo/{}.record", output.program_name, output.record),
         }
     }
 }
@@ -101,7 +102,10 @@ pub struct FunctionOutput {
 
 impl fmt::Display for FunctionOutput {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} {}", self.mode, self.type_)
+        match self.mode {
+            Mode::None => write!(f, "{}", self.type_.to_leo_string()),
+            _ => write!(f, "{} {}", self.mode, self.type_.to_leo_string()),
+        }
     }
 }
 
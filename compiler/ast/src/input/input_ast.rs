@@ -14,10 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{normalize_json_value, remove_key_from_json, Expression, Struct, Type};
+use crate::{
+    normalize_json_value,
+    remove_key_from_json,
+    Expression,
+    Mode,
+    Node,
+    Struct,
+    StructVariableInitializer,
+    Type,
+};
 
 use super::*;
-use leo_errors::{AstError, Result};
+use leo_errors::{AstError, InputError, Result};
 
 /// Input data which includes [`ProgramInput`].
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -41,34 +50,17 @@ pub struct InputAst {
 
 impl InputAst {
     /// Returns all values of the input AST for execution with `leo run`.
-    pub fn program_inputs(&self, program_name: &str, structs: IndexMap<Symbol, Struct>) -> Vec<String> {
+    pub fn program_inputs(&self, program_name: &str, structs: IndexMap<Symbol, Struct>) -> Result<Vec<String>> {
         self.sections
             .iter()
             .filter(|section| section.name() == program_name)
             .flat_map(|section| {
-                section.definitions.iter().map(|definition| match &definition.type_ {
-                    // Handle case where the input may be record.
-                    Type::Identifier(identifier) => {
-                        match structs.get(&identifier.name) {
-                            // TODO: Better error handling.
-                            None => panic!(
-                                "Input error: A struct or record declaration does not exist for {}.",
-                                identifier.name
-                            ),
-                            Some(struct_) => match struct_.is_record {
-                                false => definition.value.to_string(),
-                                true => match &definition.value {
-                                    // Print out the record interface with visibility.
-                                    Expression::Struct(struct_expression) => struct_expression.to_record_string(),
-                                    _ => panic!("Input error: Expected a struct expression."),
-                                },
-                            },
-                        }
-                    }
-                    _ => definition.value.to_string(),
-                })
+                section
+                    .definitions
+                    .iter()
+                    .map(|definition| aleo_value(&definition.value, &definition.type_, None, &structs))
             })
-            .collect::<Vec<_>>()
+            .collect()
     }
 
     /// Serializes the `Input` into a JSON Value.
@@ -106,3 +98,67 @@ impl InputAst {
             .map_err(|e| AstError::failed_to_write_ast_to_json_file(&path, &e))?)
     }
 }
+
+/// Prints `value`, of type `type_`, as an Aleo value. The literals of a record are suffixed with the visibility of the
+/// member they are in, and `visibility` is that of the member that `value` is in, if any.
+fn aleo_value(
+    value: &Expression,
+    type_: &Type,
+    visibility: Option<Mode>,
+    structs: &IndexMap<Symbol, Struct>,
+) -> Result<String> {
+    match (type_, value) {
+        (Type::Identifier(identifier), value) => {
+            let struct_ =
+                structs.get(&identifier.name).ok_or_else(|| InputError::unknown_struct(identifier, identifier.span))?;
+            let expression = match value {
+                Expression::Struct(expression) => expression,
+                value => return Err(InputError::unexpected_type(identifier, value, value.span()).into()),
+            };
+
+            // The owner of a record comes first, and its nonce last. Each member of a record has its own visibility.
+            let mut members = struct_
+                .members
+                .iter()
+                .map(|member| {
+                    let visibility = match (struct_.is_record, member.mode) {
+                        (true, Mode::None) => Some(Mode::Private),
+                        (true, mode) => Some(mode),
+                        (false, _) => visibility,
+                    };
+                    (member.identifier.name, &member.type_, visibility)
+                })
+                .collect::<Vec<_>>();
+            if struct_.is_record {
+                members.sort_by_key(|(name, ..)| *name != sym::owner);
+                members.push((sym::_nonce, &Type::Group, Some(Mode::Public)));
+            }
+
+            let members = members
+                .into_iter()
+                .map(|(name, type_, visibility)| {
+                    match expression.members.iter().find(|initializer| initializer.identifier.name == name) {
+                        Some(StructVariableInitializer { expression: Some(value), .. }) => {
+                            Ok(format!("{name}: {}", aleo_value(value, type_, visibility, structs)?))
+                        }
+                        Some(initializer) => Err(InputError::illegal_expression(initializer, initializer.span).into()),
+                        None => Err(InputError::missing_struct_member(identifier, name, expression.span).into()),
+                    }
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(format!("{{ {} }}", members.join(", ")))
+        }
+        (Type::Array(array_type), Expression::Array(array)) => {
+            let elements = array
+                .elements
+                .iter()
+                .map(|element| aleo_value(element, array_type.element_type(), visibility, structs))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(format!("[{}]", elements.join(", ")))
+        }
+        (_, value) => match visibility {
+            Some(visibility) => Ok(format!("{value}.{visibility}")),
+            None => Ok(value.to_string()),
+        },
+    }
+}
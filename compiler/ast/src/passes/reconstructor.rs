@@ -155,6 +155,7 @@ pub trait ExpressionReconstructor {
             Expression::Cast(CastExpression {
                 expression: Box::new(self.reconstruct_expression(*input.expression).0),
                 type_: input.type_,
+                kind: input.kind,
                 span: input.span,
                 id: input.id,
             }),
@@ -420,7 +421,11 @@ pub trait ProgramReconstructor: StatementReconstructor {
             program_scopes: input
                 .program_scopes
                 .into_iter()
-                .map(|(id, scope)| (id, self.reconstruct_program_scope(scope)))
+                .map(|(id, scope)| match scope.is_stub {
+                    // Stubs do not have any function bodies to reconstruct.
+                    true => (id, scope),
+                    false => (id, self.reconstruct_program_scope(scope)),
+                })
                 .collect(),
         }
     }
@@ -440,6 +445,7 @@ pub trait ProgramReconstructor: StatementReconstructor {
                 })
                 .collect(),
             span: input.span,
+            is_stub: input.is_stub,
         }
     }
 
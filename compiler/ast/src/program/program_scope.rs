@@ -16,7 +16,7 @@
 
 //! A Leo program scope consists of struct, function, and mapping definitions.
 
-use crate::{ConstDeclaration, Function, Mapping, ProgramId, Struct};
+use crate::{ConstDeclaration, Function, Indent, Mapping, ProgramId, Struct};
 
 use leo_span::{Span, Symbol};
 use serde::{Deserialize, Serialize};
@@ -37,20 +37,31 @@ pub struct ProgramScope {
     pub functions: Vec<(Symbol, Function)>,
     /// The span associated with the program scope.
     pub span: Span,
+    /// Whether the program scope is a stub for a program imported as Aleo instructions.
+    /// A stub only declares the interface of the program, so its functions have empty bodies.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub is_stub: bool,
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
 }
 
 impl fmt::Display for ProgramScope {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         writeln!(f, "program {} {{", self.program_id)?;
+        for (_, const_) in self.consts.iter() {
+            writeln!(f, "{}", Indent(const_))?;
+        }
         for (_, struct_) in self.structs.iter() {
-            writeln!(f, "    {struct_}")?;
+            writeln!(f, "{}", Indent(struct_))?;
         }
         for (_, mapping) in self.mappings.iter() {
-            writeln!(f, "    {mapping}")?;
+            writeln!(f, "{}", Indent(mapping))?;
         }
         for (_, function) in self.functions.iter() {
-            writeln!(f, "    {function}")?;
+            writeln!(f, "{}", Indent(function))?;
         }
-        Ok(())
+        write!(f, "}}")
     }
 }
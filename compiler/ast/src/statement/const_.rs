@@ -37,8 +37,8 @@ pub struct ConstDeclaration {
 
 impl fmt::Display for ConstDeclaration {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.place)?;
-        write!(f, ": {}", self.type_)?;
+        write!(f, "const {}", self.place)?;
+        write!(f, ": {}", self.type_.to_leo_string())?;
         write!(f, " = {};", self.value)
     }
 }
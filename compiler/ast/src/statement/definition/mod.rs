@@ -44,7 +44,7 @@ impl fmt::Display for DefinitionStatement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{} ", self.declaration_type)?;
         write!(f, "{}", self.place)?;
-        write!(f, ": {}", self.type_)?;
+        write!(f, ": {}", self.type_.to_leo_string())?;
         write!(f, " = {};", self.value)
     }
 }
@@ -52,7 +52,15 @@ pub struct IterationStatement {
 impl fmt::Display for IterationStatement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let eq = if self.inclusive { "=" } else { "" };
-        write!(f, "for {} in {}..{eq}{} {}", self.variable, self.start, self.stop, self.block)
+        write!(
+            f,
+            "for {}: {} in {}..{eq}{} {}",
+            self.variable,
+            self.type_.to_leo_string(),
+            self.start,
+            self.stop,
+            self.block
+        )
     }
 }
 
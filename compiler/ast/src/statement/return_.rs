@@ -35,7 +35,14 @@ pub struct ReturnStatement {
 
 impl fmt::Display for ReturnStatement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "return {}", self.expression)
+        match self.expression {
+            Expression::Unit(_) => write!(f, "return")?,
+            _ => write!(f, "return {}", self.expression)?,
+        }
+        if let Some(arguments) = &self.finalize_arguments {
+            write!(f, " then finalize({})", arguments.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", "))?;
+        }
+        write!(f, ";")
     }
 }
 
@@ -46,8 +46,8 @@ impl Member {
 impl fmt::Display for Member {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.mode {
-            Mode::None => write!(f, "{}: {}", self.identifier, self.type_),
-            _ => write!(f, "{} {} {}", self.mode, self.identifier, self.type_),
+            Mode::None => write!(f, "{}: {}", self.identifier, self.type_.to_leo_string()),
+            _ => write!(f, "{} {}: {}", self.mode, self.identifier, self.type_.to_leo_string()),
         }
     }
 }
@@ -17,7 +17,7 @@
 pub mod member;
 pub use member::*;
 
-use crate::{Identifier, Node, NodeID};
+use crate::{Identifier, Indent, Node, NodeID};
 use leo_span::{Span, Symbol};
 
 use serde::{Deserialize, Serialize};
@@ -68,9 +68,9 @@ impl fmt::Debug for Struct {
 impl fmt::Display for Struct {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str(if self.is_record { "record" } else { "struct" })?;
-        writeln!(f, " {} {{ ", self.identifier)?;
+        writeln!(f, " {} {{", self.identifier)?;
         for field in self.members.iter() {
-            writeln!(f, "    {field}")?;
+            writeln!(f, "{},", Indent(field))?;
         }
         write!(f, "}}")
     }
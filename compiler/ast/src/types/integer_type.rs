@@ -42,6 +42,45 @@ impl IntegerType {
         matches!(self, I8 | I16 | I32 | I64 | I128)
     }
 
+    /// Returns the number of bits of the integer type.
+    pub fn bits(&self) -> u32 {
+        match self {
+            Self::I8 | Self::U8 => 8,
+            Self::I16 | Self::U16 => 16,
+            Self::I32 | Self::U32 => 32,
+            Self::I64 | Self::U64 => 64,
+            Self::I128 | Self::U128 => 128,
+        }
+    }
+
+    /// Returns the minimum value of the integer type.
+    pub fn min_value(&self) -> i128 {
+        match self {
+            Self::I8 => i8::MIN as i128,
+            Self::I16 => i16::MIN as i128,
+            Self::I32 => i32::MIN as i128,
+            Self::I64 => i64::MIN as i128,
+            Self::I128 => i128::MIN,
+            Self::U8 | Self::U16 | Self::U32 | Self::U64 | Self::U128 => 0,
+        }
+    }
+
+    /// Returns the maximum value of the integer type.
+    pub fn max_value(&self) -> u128 {
+        match self {
+            Self::I8 => i8::MAX as u128,
+            Self::I16 => i16::MAX as u128,
+            Self::I32 => i32::MAX as u128,
+            Self::I64 => i64::MAX as u128,
+            Self::I128 => i128::MAX as u128,
+            Self::U8 => u8::MAX as u128,
+            Self::U16 => u16::MAX as u128,
+            Self::U32 => u32::MAX as u128,
+            Self::U64 => u64::MAX as u128,
+            Self::U128 => u128::MAX,
+        }
+    }
+
     /// Returns the symbol for the integer type.
     pub fn symbol(self) -> Symbol {
         match self {
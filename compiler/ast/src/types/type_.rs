@@ -86,6 +86,24 @@ impl Type {
             _ => false,
         }
     }
+
+    /// Returns the type as it is written in Leo code.
+    /// This differs from the `Display` of the type, which is used in diagnostics, in that booleans are written `bool`.
+    pub fn to_leo_string(&self) -> String {
+        match self {
+            Type::Boolean => "bool".to_string(),
+            Type::Array(array_type) => {
+                format!("[{}; {}]", array_type.element_type().to_leo_string(), array_type.length())
+            }
+            Type::Mapping(mapping_type) => {
+                format!("({} => {})", mapping_type.key.to_leo_string(), mapping_type.value.to_leo_string())
+            }
+            Type::Tuple(tuple_type) => {
+                format!("({})", tuple_type.elements().iter().map(|type_| type_.to_leo_string()).join(", "))
+            }
+            _ => self.to_string(),
+        }
+    }
 }
 
 impl fmt::Display for Type {
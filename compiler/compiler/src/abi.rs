@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The ABI of a program, which describes the interface that clients use to call its transitions.
+
+use leo_ast::{functions, Mode, Program, Struct, Variant};
+use leo_passes::SymbolTable;
+
+use serde::{Deserialize, Serialize};
+
+/// The interface of a program: its structs, records, mappings, and transitions.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Abi {
+    /// The program ID, e.g. `token.aleo`.
+    pub program: String,
+    /// The IDs of the imported programs.
+    pub imports: Vec<String>,
+    /// The structs, including those of the imported programs.
+    pub structs: Vec<StructAbi>,
+    /// The records, including those of the imported programs.
+    pub records: Vec<StructAbi>,
+    /// The mappings.
+    pub mappings: Vec<MappingAbi>,
+    /// The transitions, in the order in which they are declared.
+    pub transitions: Vec<TransitionAbi>,
+}
+
+/// A struct or record.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StructAbi {
+    pub name: String,
+    pub fields: Vec<FieldAbi>,
+}
+
+/// A field of a struct or record, or an input of a transition or finalize block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldAbi {
+    pub name: String,
+    /// The Leo type, e.g. `u64`, `[u8; 4]`, or `Token`. Records of other programs are written `credits.aleo/credits`.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// The visibility, i.e. `constant`, `private`, or `public`. Fields of structs have no visibility.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+}
+
+/// An output of a transition.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutputAbi {
+    /// The Leo type, e.g. `u64`, `[u8; 4]`, or `Token`. Records of other programs are written `credits.aleo/credits`.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// The visibility, i.e. `constant`, `private`, or `public`.
+    pub mode: String,
+}
+
+/// A mapping.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MappingAbi {
+    pub name: String,
+    pub key: String,
+    pub value: String,
+}
+
+/// A transition, along with the inputs of its finalize block, if it has one.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransitionAbi {
+    pub name: String,
+    pub inputs: Vec<FieldAbi>,
+    pub outputs: Vec<OutputAbi>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finalize: Option<Vec<FieldAbi>>,
+}
+
+impl Abi {
+    /// Describes the interface of `program`, whose structs are declared in `symbol_table`.
+    pub fn new(program: &Program, symbol_table: &SymbolTable) -> Self {
+        let mut abi =
+            Abi { imports: program.imports.keys().map(|name| format!("{name}.aleo")).collect(), ..Default::default() };
+
+        for struct_ in symbol_table.structs.values() {
+            match struct_.is_record {
+                true => abi.records.push(struct_abi(struct_, Some(Mode::Private))),
+                false => abi.structs.push(struct_abi(struct_, None)),
+            }
+        }
+
+        for program_scope in program.program_scopes.values() {
+            abi.program = program_scope.program_id.to_string();
+            abi.mappings.extend(program_scope.mappings.iter().map(|(_, mapping)| MappingAbi {
+                name: mapping.identifier.to_string(),
+                key: mapping.key_type.to_string(),
+                value: mapping.value_type.to_string(),
+            }));
+            abi.transitions.extend(
+                program_scope.functions.iter().filter(|(_, function)| function.variant == Variant::Transition).map(
+                    |(_, function)| TransitionAbi {
+                        name: function.identifier.to_string(),
+                        inputs: function.input.iter().map(|input| input_abi(input, Mode::Private)).collect(),
+                        outputs: function.output.iter().map(output_abi).collect(),
+                        finalize: function.finalize.as_ref().map(|finalize| {
+                            finalize.input.iter().map(|input| input_abi(input, Mode::Public)).collect()
+                        }),
+                    },
+                ),
+            );
+        }
+
+        abi
+    }
+}
+
+/// Returns the visibility of a value declared with `mode`, which is `default` if no mode is declared.
+fn visibility(mode: Mode, default: Mode) -> String {
+    match mode {
+        Mode::None => default.to_string(),
+        mode => mode.to_string(),
+    }
+}
+
+/// Describes a struct, whose fields have the visibility `default` unless they declare one.
+fn struct_abi(struct_: &Struct, default: Option<Mode>) -> StructAbi {
+    StructAbi {
+        name: struct_.identifier.to_string(),
+        fields: struct_
+            .members
+            .iter()
+            .map(|member| FieldAbi {
+                name: member.identifier.to_string(),
+                type_: member.type_.to_string(),
+                mode: default.map(|default| visibility(member.mode, default)),
+            })
+            .collect(),
+    }
+}
+
+/// Describes an input, which has the visibility `default` unless it declares one.
+fn input_abi(input: &functions::Input, default: Mode) -> FieldAbi {
+    match input {
+        functions::Input::Internal(input) => FieldAbi {
+            name: input.identifier.to_string(),
+            type_: input.type_.to_string(),
+            mode: Some(visibility(input.mode, default)),
+        },
+        functions::Input::External(input) => FieldAbi {
+            name: input.identifier.to_string(),
+            type_: format!("{}.aleo/{}", input.program_name, input.record),
+            mode: Some(Mode::Private.to_string()),
+        },
+    }
+}
+
+/// Describes an output, which is private unless it declares a visibility.
+fn output_abi(output: &functions::Output) -> OutputAbi {
+    match output {
+        functions::Output::Internal(output) => {
+            OutputAbi { type_: output.type_.to_string(), mode: visibility(output.mode, Mode::Private) }
+        }
+        functions::Output::External(output) => OutputAbi {
+            type_: format!("{}.aleo/{}", output.program_name, output.record),
+            mode: Mode::Private.to_string(),
+        },
+    }
+}
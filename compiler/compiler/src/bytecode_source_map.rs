@@ -0,0 +1,206 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A source map from the generated Aleo instructions back to the Leo source they are generated from.
+
+use leo_passes::aleo_ir;
+use leo_span::{symbol::with_session_globals, Span};
+
+use serde::{Deserialize, Serialize};
+
+/// A location in the Leo source.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceLocation {
+    /// The name of the Leo file.
+    pub file: String,
+    /// The line of the location, starting at 1.
+    pub line: usize,
+    /// The column of the location, starting at 1.
+    pub column: usize,
+    /// The contents of the line of the location.
+    pub source: String,
+}
+
+impl SourceLocation {
+    /// Returns the location of `span`, if it points into a known source file.
+    pub fn from_span(span: Span) -> Option<Self> {
+        if span.is_dummy() {
+            return None;
+        }
+        with_session_globals(|s| {
+            let location = s.source_map.span_to_location(span)?;
+            let line = s.source_map.line_contents_of_span(span)?;
+            Some(Self {
+                file: location.source_file.name.to_string(),
+                line: location.line_start,
+                column: location.col_start,
+                source: line.lines().next().unwrap_or_default().trim().to_string(),
+            })
+        })
+    }
+}
+
+impl std::fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}: `{}`", self.file, self.line, self.column, self.source)
+    }
+}
+
+/// An emitted Aleo instruction, or input declaration, and the Leo source it is generated from.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstructionMapping {
+    /// The line of the instruction in the `.aleo` file, starting at 1.
+    pub line: usize,
+    /// The instruction, as it is printed in the `.aleo` file.
+    pub instruction: String,
+    /// The location of the Leo source.
+    pub location: SourceLocation,
+}
+
+/// A register of an Aleo function, and the Leo source of the instruction that defines it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegisterMapping {
+    /// The register, e.g. `r3`.
+    pub register: String,
+    /// The location of the Leo source.
+    pub location: SourceLocation,
+}
+
+/// The source map of an Aleo function, closure, or finalize block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionSourceMap {
+    /// The name of the function.
+    pub name: String,
+    /// Whether the source map is for the finalize block of the function.
+    pub finalize: bool,
+    /// The input declarations and instructions of the function, in order.
+    pub instructions: Vec<InstructionMapping>,
+    /// The registers of the function, in the order in which they are defined.
+    pub registers: Vec<RegisterMapping>,
+}
+
+/// The source map of a generated `.aleo` file.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BytecodeSourceMap {
+    /// The name of the program, e.g. `hello.aleo`.
+    pub program: String,
+    /// The source maps of the functions, closures, and finalize blocks of the program.
+    pub functions: Vec<FunctionSourceMap>,
+}
+
+impl BytecodeSourceMap {
+    /// Constructs the source map of `program`, whose printed form is `printed`.
+    pub fn new(program: &aleo_ir::Program, printed: &aleo_ir::PrintedProgram) -> Self {
+        let mut functions = Vec::new();
+        for function in program.functions.iter() {
+            // Inline functions are not printed.
+            if let Some(lines) = printed.bodies.get(&(function.name.clone(), false)) {
+                functions.push(FunctionSourceMap::new(
+                    &function.name,
+                    false,
+                    &function.inputs,
+                    &function.instructions,
+                    lines,
+                ));
+            }
+            if let Some(finalize) = &function.finalize {
+                if let Some(lines) = printed.bodies.get(&(finalize.name.clone(), true)) {
+                    functions.push(FunctionSourceMap::new(
+                        &finalize.name,
+                        true,
+                        &finalize.inputs,
+                        &finalize.instructions,
+                        lines,
+                    ));
+                }
+            }
+        }
+        Self { program: program.id.clone(), functions }
+    }
+
+    /// Explains an error that snarkVM reports while running the function `function` in terms of the Leo source.
+    /// Note that the finalize block of the function is not considered, since it is not run along with the function.
+    /// Returns `None` if the error does not mention any instruction or register of the function.
+    pub fn explain(&self, function: &str, error: &str) -> Option<String> {
+        let body = self.functions.iter().find(|body| body.name == function && !body.finalize)?;
+
+        // Prefer the instructions quoted by the error, since they are more precise than the registers they mention.
+        let mut lines: Vec<String> = body
+            .instructions
+            .iter()
+            .filter(|mapping| error.contains(&mapping.instruction))
+            .map(|mapping| format!("`{}` is generated from {}", mapping.instruction, mapping.location))
+            .collect();
+        if lines.is_empty() {
+            let registers = registers_in(error);
+            lines = body
+                .registers
+                .iter()
+                .filter(|mapping| registers.contains(&mapping.register.as_str()))
+                .map(|mapping| format!("`{}` is defined at {}", mapping.register, mapping.location))
+                .collect();
+        }
+        lines.dedup();
+
+        (!lines.is_empty()).then(|| lines.iter().map(|line| format!("\n  {line}")).collect())
+    }
+}
+
+impl FunctionSourceMap {
+    /// Constructs the source map of a function, closure, or finalize block, whose lines are printed at `lines`.
+    fn new(
+        name: &str,
+        finalize: bool,
+        inputs: &[aleo_ir::Input],
+        instructions: &[aleo_ir::SpannedInstruction],
+        lines: &aleo_ir::BodyLines,
+    ) -> Self {
+        let mut source_map = Self { name: name.to_string(), finalize, instructions: Vec::new(), registers: Vec::new() };
+        for (input, line) in inputs.iter().zip(lines.inputs.iter()) {
+            source_map.add_line(*line, input.to_string(), vec![input.register.to_string()], input.span);
+        }
+        for (instruction, line) in instructions.iter().zip(lines.instructions.iter()) {
+            let destinations =
+                instruction.instruction.destinations().into_iter().map(|register| register.to_string()).collect();
+            source_map.add_line(*line, instruction.to_string(), destinations, instruction.span);
+        }
+        source_map
+    }
+
+    /// Maps the line `line`, which declares or defines `registers`, to the Leo source at `span`, if any.
+    fn add_line(&mut self, line: usize, instruction: String, registers: Vec<String>, span: Span) {
+        if let Some(location) = SourceLocation::from_span(span) {
+            self.registers
+                .extend(registers.into_iter().map(|register| RegisterMapping { register, location: location.clone() }));
+            self.instructions.push(InstructionMapping { line, instruction, location });
+        }
+    }
+}
+
+/// Returns the registers, e.g. `r3`, that occur in `text`.
+fn registers_in(text: &str) -> Vec<&str> {
+    let mut registers = Vec::new();
+    let bytes = text.as_bytes();
+    for (index, _) in text.match_indices('r') {
+        let is_start = index == 0 || !bytes[index - 1].is_ascii_alphanumeric();
+        let length = bytes[index + 1..].iter().take_while(|byte| byte.is_ascii_digit()).count();
+        let is_end = bytes.get(index + 1 + length).map_or(true, |byte| !byte.is_ascii_alphanumeric());
+        if is_start && length > 0 && is_end {
+            registers.push(&text[index..index + 1 + length]);
+        }
+    }
+    registers
+}
@@ -17,17 +17,29 @@
 //! The compiler for Leo programs.
 //!
 //! The [`Compiler`] type compiles Leo programs into R1CS circuits.
+use leo_ast::{normalize_json_value, remove_key_from_json, NodeBuilder, Program};
 pub use leo_ast::{Ast, InputAst};
-use leo_ast::{NodeBuilder, Program};
-use leo_errors::{emitter::Handler, CompilerError, Result};
-pub use leo_passes::SymbolTable;
+use leo_errors::{emitter::Handler, AstError, CompilerError, Result};
 use leo_passes::*;
-use leo_span::{source_map::FileName, symbol::with_session_globals};
-
+pub use leo_passes::{
+    input_ast_from_values,
+    FileSystemResolver,
+    ImportResolver,
+    InlinedCall,
+    InliningStrategy,
+    InputValidator,
+    Pass,
+    SymbolTable,
+    UnrolledLoop,
+    VirtualResolver,
+};
+use leo_span::{source_map::FileName, symbol::with_session_globals, Symbol};
+
+use indexmap::IndexMap;
 use sha2::{Digest, Sha256};
-use std::{fs, path::PathBuf};
+use std::{fs, path::PathBuf, rc::Rc, time::Instant};
 
-use crate::CompilerOptions;
+use crate::{Abi, BytecodeSourceMap, CompilerOptions, PassContext, PassManager, Stage, StageTiming};
 
 /// The primary entry point of the Leo compiler.
 #[derive(Clone)]
@@ -48,12 +60,33 @@ pub struct Compiler<'a> {
     pub input_ast: Option<InputAst>,
     /// Options configuring compilation.
     compiler_options: CompilerOptions,
+    /// The configuration of the compiler stages.
+    pub pass_manager: PassManager,
+    /// The resolver used to locate the source of imported programs.
+    import_resolver: Rc<dyn ImportResolver>,
     /// The `NodeCounter` used to generate sequentially increasing `NodeID`s.
     node_builder: NodeBuilder,
     /// The `Assigner` is used to construct (unique) assignment statements.
     assigner: Assigner,
     /// The type table.
     type_table: TypeTable,
+    /// The bytecode of the programs imported as Aleo instructions.
+    import_bytecode: IndexMap<Symbol, String>,
+    /// The bytecode of the programs that the main program depends on, in the order in which they must be deployed.
+    pub dependencies: IndexMap<Symbol, String>,
+    /// The statistics of each stage that has run, if stage timings are enabled.
+    pub timings: Vec<StageTiming>,
+    /// The calls to local functions, and whether or not the function inlining pass inlined them.
+    pub inlining_report: Vec<InlinedCall>,
+    /// The loops that the loop unrolling pass unrolled.
+    pub unroll_report: Vec<UnrolledLoop>,
+    /// The ABI of the main program, which is described after type checking.
+    pub abi: Abi,
+    /// The source map from the bytecode of the main program to the Leo source.
+    pub source_map: BytecodeSourceMap,
+    /// The source maps of the programs that the main program depends on.
+    /// Programs imported as Aleo instructions do not have a source map.
+    pub dependency_source_maps: IndexMap<Symbol, BytecodeSourceMap>,
 }
 
 impl<'a> Compiler<'a> {
@@ -65,6 +98,7 @@ impl<'a> Compiler<'a> {
         main_file_path: PathBuf,
         output_directory: PathBuf,
         compiler_options: Option<CompilerOptions>,
+        import_resolver: Rc<dyn ImportResolver>,
     ) -> Self {
         let node_builder = NodeBuilder::default();
         let assigner = Assigner::default();
@@ -78,9 +112,19 @@ impl<'a> Compiler<'a> {
             ast: Ast::new(Program::default()),
             input_ast: None,
             compiler_options: compiler_options.unwrap_or_default(),
+            pass_manager: PassManager::default(),
+            import_resolver,
             node_builder,
             assigner,
             type_table,
+            import_bytecode: IndexMap::new(),
+            dependencies: IndexMap::new(),
+            timings: Vec::new(),
+            inlining_report: Vec::new(),
+            unroll_report: Vec::new(),
+            abi: Abi::default(),
+            source_map: BytecodeSourceMap::default(),
+            dependency_source_maps: IndexMap::new(),
         }
     }
 
@@ -106,24 +150,42 @@ impl<'a> Compiler<'a> {
         // Use the parser to construct the abstract syntax tree (ast).
         self.ast = leo_parser::parse_ast(self.handler, &self.node_builder, &prg_sf.src, prg_sf.start_pos)?;
 
-        // If the program is imported, then check that the name of its program scope matches the file name.
-        // Note that parsing enforces that there is exactly one program scope in a file.
-        // TODO: Clean up check.
-        let program_scope = self.ast.ast.program_scopes.values().next().unwrap();
-        let program_scope_name = format!("{}", program_scope.program_id.name);
-        if program_scope_name != self.program_name {
-            return Err(CompilerError::program_scope_name_does_not_match(
-                program_scope_name,
-                self.program_name.clone(),
-                program_scope.program_id.name.span,
-            )
-            .into());
+        self.resolve_program()
+    }
+
+    /// Stores a pre-built AST, e.g. one constructed programmatically or read with `Ast::from_json_file`, as the program.
+    /// The symbols of the AST must be interned in the current session.
+    /// The AST is validated as a parsed AST would be: the nodes must have unique `NodeID`s,
+    /// and there must be a program scope whose name matches the program name.
+    pub fn load_ast(&mut self, ast: Ast) -> Result<()> {
+        // The nodes created by the later passes must not reuse the IDs of the given nodes.
+        let next_id = NodeIdValidator::do_pass((&ast, self.handler))?;
+        self.node_builder = NodeBuilder::new(next_id);
+        if ast.ast.program_scopes.is_empty() {
+            return Err(CompilerError::missing_program_scope(&self.program_name).into());
         }
+        self.ast = ast;
+
+        self.resolve_program()
+    }
+
+    /// Checks the program scopes of the AST and resolves its imports.
+    fn resolve_program(&mut self) -> Result<()> {
+        // Check that the file declares a program scope whose name matches the program name.
+        // Note that parsing enforces that there is at least one program scope in a file.
+        ImportCollector::check_program_scope_name(&self.ast.ast, Symbol::intern(&self.program_name))?;
+
+        // Resolve the imported programs and the program scopes declared alongside the main program scope.
+        self.import_resolution_pass()?;
 
         if self.compiler_options.output.initial_ast {
             self.write_ast_to_json("initial_ast.json")?;
         }
 
+        if self.compiler_options.output.initial_leo {
+            self.write_ast_to_leo("initial.leo")?;
+        }
+
         Ok(())
     }
 
@@ -146,9 +208,11 @@ impl<'a> Compiler<'a> {
             // Parse and serialize it.
             let input_ast =
                 leo_parser::parse_input(self.handler, &self.node_builder, &input_sf.src, input_sf.start_pos)?;
-            if self.compiler_options.output.initial_ast {
+            if self.compiler_options.output.initial_input_ast {
                 // Write the input AST snapshot post parsing.
-                if self.compiler_options.output.ast_spans_enabled {
+                if self.compiler_options.output.emit_to_stdout {
+                    print_snapshot(input_ast.to_json_value()?, self.compiler_options.output.ast_spans_enabled)?;
+                } else if self.compiler_options.output.ast_spans_enabled {
                     input_ast.to_json_file(
                         self.output_directory.clone(),
                         &format!("{}.initial_input_ast.json", self.program_name),
@@ -167,6 +231,19 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 
+    /// Runs the import resolution pass.
+    pub fn import_resolution_pass(&mut self) -> Result<()> {
+        let ast = core::mem::take(&mut self.ast);
+        (self.ast, self.import_bytecode) = ImportCollector::do_pass((
+            ast,
+            self.handler,
+            &self.node_builder,
+            &*self.import_resolver,
+            Symbol::intern(&self.program_name),
+        ))?;
+        Ok(())
+    }
+
     /// Runs the symbol table pass.
     pub fn symbol_table_pass(&self) -> Result<SymbolTable> {
         let symbol_table = SymbolTableCreator::do_pass((&self.ast, self.handler))?;
@@ -188,19 +265,25 @@ impl<'a> Compiler<'a> {
 
     /// Runs the loop unrolling pass.
     pub fn loop_unrolling_pass(&mut self, symbol_table: SymbolTable) -> Result<SymbolTable> {
-        let (ast, symbol_table) = Unroller::do_pass((
+        let (ast, symbol_table, unrolled_loops) = Unroller::do_pass((
             std::mem::take(&mut self.ast),
             self.handler,
             &self.node_builder,
             symbol_table,
             &self.type_table,
+            self.compiler_options.build.max_unrolled_iterations,
         ))?;
         self.ast = ast;
+        self.unroll_report = unrolled_loops;
 
         if self.compiler_options.output.unrolled_ast {
             self.write_ast_to_json("unrolled_ast.json")?;
         }
 
+        if self.compiler_options.output.unrolled_leo {
+            self.write_ast_to_leo("unrolled.leo")?;
+        }
+
         if self.compiler_options.output.unrolled_symbol_table {
             self.write_symbol_table_to_json("unrolled_symbol_table.json", &symbol_table)?;
         }
@@ -222,6 +305,10 @@ impl<'a> Compiler<'a> {
             self.write_ast_to_json("ssa_ast.json")?;
         }
 
+        if self.compiler_options.output.ssa_leo {
+            self.write_ast_to_leo("ssa.leo")?;
+        }
+
         Ok(())
     }
 
@@ -233,12 +320,17 @@ impl<'a> Compiler<'a> {
             &self.type_table,
             &self.node_builder,
             &self.assigner,
+            self.compiler_options.build.finalize_branching,
         ))?;
 
         if self.compiler_options.output.flattened_ast {
             self.write_ast_to_json("flattened_ast.json")?;
         }
 
+        if self.compiler_options.output.flattened_leo {
+            self.write_ast_to_leo("flattened.leo")?;
+        }
+
         Ok(())
     }
 
@@ -255,24 +347,55 @@ impl<'a> Compiler<'a> {
             self.write_ast_to_json("destructured_ast.json")?;
         }
 
+        if self.compiler_options.output.destructured_leo {
+            self.write_ast_to_leo("destructured.leo")?;
+        }
+
         Ok(())
     }
 
     /// Runs the function inlining pass.
     pub fn function_inlining_pass(&mut self, call_graph: &CallGraph) -> Result<()> {
-        let ast = FunctionInliner::do_pass((
+        let (ast, report) = FunctionInliner::do_pass((
             std::mem::take(&mut self.ast),
             &self.node_builder,
             call_graph,
             &self.assigner,
             &self.type_table,
+            self.compiler_options.build.inlining_strategy,
         ))?;
         self.ast = ast;
+        self.inlining_report = report;
 
         if self.compiler_options.output.inlined_ast {
             self.write_ast_to_json("inlined_ast.json")?;
         }
 
+        if self.compiler_options.output.inlined_leo {
+            self.write_ast_to_leo("inlined.leo")?;
+        }
+
+        Ok(())
+    }
+
+    /// Runs the common subexpression elimination pass.
+    pub fn common_subexpression_elimination_pass(&mut self) -> Result<()> {
+        if self.compiler_options.build.cse_enabled {
+            self.ast = CommonSubexpressionEliminator::do_pass((
+                std::mem::take(&mut self.ast),
+                &self.node_builder,
+                &self.type_table,
+            ))?;
+        }
+
+        if self.compiler_options.output.cse_ast {
+            self.write_ast_to_json("cse_ast.json")?;
+        }
+
+        if self.compiler_options.output.cse_leo {
+            self.write_ast_to_leo("cse.leo")?;
+        }
+
         Ok(())
     }
 
@@ -286,6 +409,10 @@ impl<'a> Compiler<'a> {
             self.write_ast_to_json("dce_ast.json")?;
         }
 
+        if self.compiler_options.output.dce_leo {
+            self.write_ast_to_leo("dce.leo")?;
+        }
+
         Ok(())
     }
 
@@ -295,27 +422,131 @@ impl<'a> Compiler<'a> {
         symbol_table: &SymbolTable,
         struct_graph: &StructGraph,
         call_graph: &CallGraph,
-    ) -> Result<String> {
+    ) -> Result<aleo_ir::Program> {
         CodeGenerator::do_pass((&self.ast, symbol_table, &self.type_table, struct_graph, call_graph, &self.ast.ast))
     }
 
-    /// Runs the compiler stages.
-    pub fn compiler_stages(&mut self) -> Result<(SymbolTable, StructGraph, CallGraph)> {
-        let st = self.symbol_table_pass()?;
-        let (st, struct_graph, call_graph) = self.type_checker_pass(st)?;
+    /// Runs the bytecode optimization pass, if the optimization level is at least 1.
+    pub fn bytecode_optimization_pass(&self, program: aleo_ir::Program) -> aleo_ir::Program {
+        match self.compiler_options.build.optimization_level {
+            0 => program,
+            _ => BytecodeOptimizer::do_pass(program),
+        }
+    }
 
-        // TODO: Make this pass optional.
-        let st = self.loop_unrolling_pass(st)?;
+    /// Prints the bytecode of `program`, annotated with the Leo source if source comments are enabled.
+    pub fn print_bytecode(&self, program: &aleo_ir::Program) -> aleo_ir::PrintedProgram {
+        program.print(self.compiler_options.build.source_comments)
+    }
 
+    /// Runs the code generation pass on each of the programs that the main program depends on.
+    /// Returns the bytecode of each program, in the order in which they must be deployed, along with its source map.
+    pub fn dependencies_code_generation_pass(
+        &self,
+        symbol_table: &SymbolTable,
+        struct_graph: &StructGraph,
+        call_graph: &CallGraph,
+    ) -> Result<IndexMap<Symbol, (String, Option<BytecodeSourceMap>)>> {
+        let imports = &self.ast.ast.imports;
+        imports
+            .iter()
+            .map(|(name, (program, _))| {
+                // Programs imported as Aleo instructions are deployed as is.
+                if let Some(bytecode) = self.import_bytecode.get(name) {
+                    return Ok((*name, (bytecode.clone(), None)));
+                }
+                // Substitute the resolved programs for the unresolved imports of the dependency.
+                let dependency = Ast::new(Program {
+                    imports: program
+                        .imports
+                        .keys()
+                        .map(|import_name| (*import_name, imports.get(import_name).unwrap().clone()))
+                        .collect(),
+                    program_scopes: program.program_scopes.clone(),
+                });
+                let bytecode = CodeGenerator::do_pass((
+                    &dependency,
+                    symbol_table,
+                    &self.type_table,
+                    struct_graph,
+                    call_graph,
+                    &self.ast.ast,
+                ))?;
+                let program = self.bytecode_optimization_pass(bytecode);
+                let printed = self.print_bytecode(&program);
+                let source_map = BytecodeSourceMap::new(&program, &printed);
+                Ok((*name, (printed.bytecode, Some(source_map))))
+            })
+            .collect()
+    }
+
+    /// Runs the custom passes inserted after `stage`.
+    fn custom_passes(&mut self, stage: Stage, symbol_table: &mut SymbolTable) -> Result<()> {
+        for pass in self.pass_manager.passes_after(stage) {
+            pass.borrow_mut().run(PassContext {
+                ast: &mut self.ast,
+                symbol_table,
+                type_table: &self.type_table,
+                node_builder: &self.node_builder,
+                handler: self.handler,
+            })?;
+            // Errors emitted by a custom pass also stop the compilation.
+            self.handler.last_err().map_err(|e| *e)?;
+        }
+        Ok(())
+    }
+
+    /// Runs the compiler stages, as configured by the pass manager.
+    pub fn compiler_stages(&mut self) -> Result<(SymbolTable, StructGraph, CallGraph)> {
+        let start = Instant::now();
+        let mut st = self.symbol_table_pass()?;
+        self.record_timing(Stage::SymbolTable.name(), start);
+        self.custom_passes(Stage::SymbolTable, &mut st)?;
+
+        let start = Instant::now();
+        let (mut st, struct_graph, call_graph) = self.type_checker_pass(st)?;
+        self.record_timing(Stage::TypeChecking.name(), start);
+        self.abi = Abi::new(&self.ast.ast, &st);
+        self.custom_passes(Stage::TypeChecking, &mut st)?;
+
+        let start = Instant::now();
+        st = self.loop_unrolling_pass(st)?;
+        self.record_timing(Stage::LoopUnrolling.name(), start);
+        self.custom_passes(Stage::LoopUnrolling, &mut st)?;
+
+        let start = Instant::now();
         self.static_single_assignment_pass(&st)?;
+        self.record_timing(Stage::StaticSingleAssignment.name(), start);
+        self.custom_passes(Stage::StaticSingleAssignment, &mut st)?;
 
+        let start = Instant::now();
         self.flattening_pass(&st)?;
+        self.record_timing(Stage::Flattening.name(), start);
+        self.custom_passes(Stage::Flattening, &mut st)?;
 
+        let start = Instant::now();
         self.destructuring_pass()?;
+        self.record_timing(Stage::Destructuring.name(), start);
+        self.custom_passes(Stage::Destructuring, &mut st)?;
 
+        let start = Instant::now();
         self.function_inlining_pass(&call_graph)?;
+        self.record_timing(Stage::FunctionInlining.name(), start);
+        self.custom_passes(Stage::FunctionInlining, &mut st)?;
 
-        self.dead_code_elimination_pass()?;
+        if self.pass_manager.is_enabled(Stage::CommonSubexpressionElimination) {
+            let start = Instant::now();
+            self.common_subexpression_elimination_pass()?;
+            self.record_timing(Stage::CommonSubexpressionElimination.name(), start);
+        }
+        self.custom_passes(Stage::CommonSubexpressionElimination, &mut st)?;
+
+        if self.pass_manager.is_enabled(Stage::DeadCodeElimination) {
+            let start = Instant::now();
+            self.dead_code_elimination_pass()?;
+            self.record_timing(Stage::DeadCodeElimination.name(), start);
+        }
+        self.custom_passes(Stage::DeadCodeElimination, &mut st)?;
 
         Ok((st, struct_graph, call_graph))
     }
@@ -323,18 +554,62 @@ impl<'a> Compiler<'a> {
     /// Returns a compiled Leo program.
     pub fn compile(&mut self) -> Result<(SymbolTable, String)> {
         // Parse the program.
+        let start = Instant::now();
         self.parse_program()?;
+        self.record_timing("parsing", start);
+        self.compile_loaded_program()
+    }
+
+    /// Returns a compiled Leo program from a pre-built AST.
+    /// The AST is validated with `load_ast`, after which the compiler stages run from symbol table creation onward.
+    pub fn compile_ast(&mut self, ast: Ast) -> Result<(SymbolTable, String)> {
+        let start = Instant::now();
+        self.load_ast(ast)?;
+        self.record_timing("validation", start);
+        self.compile_loaded_program()
+    }
+
+    /// Runs the compiler stages and code generation on the stored program.
+    fn compile_loaded_program(&mut self) -> Result<(SymbolTable, String)> {
         // Run the intermediate compiler stages.
         let (symbol_table, struct_graph, call_graph) = self.compiler_stages()?;
         // Run code generation.
-        let bytecode = self.code_generation_pass(&symbol_table, &struct_graph, &call_graph)?;
-        Ok((symbol_table, bytecode))
+        let start = Instant::now();
+        let program = self.code_generation_pass(&symbol_table, &struct_graph, &call_graph)?;
+        for (name, (bytecode, source_map)) in
+            self.dependencies_code_generation_pass(&symbol_table, &struct_graph, &call_graph)?
+        {
+            if let Some(source_map) = source_map {
+                self.dependency_source_maps.insert(name, source_map);
+            }
+            self.dependencies.insert(name, bytecode);
+        }
+        self.record_timing("code_generation", start);
+        // Optimize the generated bytecode.
+        let start = Instant::now();
+        let program = self.bytecode_optimization_pass(program);
+        let printed = self.print_bytecode(&program);
+        if self.compiler_options.build.optimization_level > 0 {
+            self.record_timing("bytecode_optimization", start);
+        }
+        self.source_map = BytecodeSourceMap::new(&program, &printed);
+        Ok((symbol_table, printed.bytecode))
+    }
+
+    /// Records the statistics of the stage `stage`, which started at `start`, if stage timings are enabled.
+    fn record_timing(&mut self, stage: &str, start: Instant) {
+        if self.compiler_options.output.stage_timings {
+            let duration = start.elapsed();
+            self.timings.push(StageTiming::new(stage, duration, &self.ast, &self.type_table));
+        }
     }
 
     /// Writes the AST to a JSON file.
     fn write_ast_to_json(&self, file_suffix: &str) -> Result<()> {
         // Remove `Span`s if they are not enabled.
-        if self.compiler_options.output.ast_spans_enabled {
+        if self.compiler_options.output.emit_to_stdout {
+            print_snapshot(self.ast.to_json_value()?, self.compiler_options.output.ast_spans_enabled)?;
+        } else if self.compiler_options.output.ast_spans_enabled {
             self.ast.to_json_file(self.output_directory.clone(), &format!("{}.{file_suffix}", self.program_name))?;
         } else {
             self.ast.to_json_file_without_keys(
@@ -346,10 +621,23 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 
+    /// Writes the AST as Leo code.
+    fn write_ast_to_leo(&self, file_suffix: &str) -> Result<()> {
+        if self.compiler_options.output.emit_to_stdout {
+            println!("{}", self.ast.ast);
+        } else {
+            let path = self.output_directory.join(format!("{}.{file_suffix}", self.program_name));
+            fs::write(&path, self.ast.ast.to_string()).map_err(|e| CompilerError::file_write_error(&path, e))?;
+        }
+        Ok(())
+    }
+
     /// Writes the Symbol Table to a JSON file.
     fn write_symbol_table_to_json(&self, file_suffix: &str, symbol_table: &SymbolTable) -> Result<()> {
         // Remove `Span`s if they are not enabled.
-        if self.compiler_options.output.symbol_table_spans_enabled {
+        if self.compiler_options.output.emit_to_stdout {
+            print_snapshot(symbol_table.to_json_value()?, self.compiler_options.output.symbol_table_spans_enabled)?;
+        } else if self.compiler_options.output.symbol_table_spans_enabled {
             symbol_table
                 .to_json_file(self.output_directory.clone(), &format!("{}.{file_suffix}", self.program_name))?;
         } else {
@@ -362,3 +650,245 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 }
+
+/// Writes a snapshot to stdout, removing `Span`s if they are not enabled.
+fn print_snapshot(value: serde_json::Value, spans_enabled: bool) -> Result<()> {
+    let value = match spans_enabled {
+        true => value,
+        false => normalize_json_value(remove_key_from_json(remove_key_from_json(value, "_span"), "span")),
+    };
+    let json = serde_json::to_string_pretty(&value).map_err(|e| AstError::failed_to_convert_ast_to_json_string(&e))?;
+    println!("{json}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_ast::IntegerType;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    const PROGRAM: &str = "program test.aleo {
+    transition main(a: u8, b: u8) -> u8 {
+        let c: u8 = a + b;
+        return c * 2u8;
+    }
+}";
+
+    fn new_compiler(handler: &Handler) -> Compiler<'_> {
+        Compiler::new(
+            "test".to_string(),
+            "aleo".to_string(),
+            handler,
+            PathBuf::from("test.leo"),
+            std::env::temp_dir(),
+            None,
+            Rc::new(VirtualResolver::default()),
+        )
+    }
+
+    /// Returns the AST of `PROGRAM`, as a user of the compiler would construct it.
+    fn program_ast() -> Ast {
+        let handler = Handler::default();
+        let mut compiler = new_compiler(&handler);
+        compiler.parse_program_from_string(PROGRAM, FileName::Custom("test".into())).unwrap();
+        Ast::from_json_string(&compiler.ast.to_json_string().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_compile_deserialized_ast() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let (_, bytecode) = new_compiler(&handler).compile_ast(program_ast()).unwrap();
+            assert!(bytecode.contains("function main:"));
+        });
+    }
+
+    #[test]
+    fn test_disable_optional_stages() {
+        const PROGRAM_WITH_LOOP: &str = "program test.aleo {
+    const ROUNDS: u8 = 4u8;
+
+    transition main(a: u8, b: u8) -> u8 {
+        let sum: u8 = 0u8;
+        for i: u8 in 0u8..ROUNDS {
+            if a > i {
+                sum += a * b;
+            }
+        }
+        return sum;
+    }
+}";
+        create_session_if_not_set_then(|_| {
+            for stage in Stage::ALL.into_iter().filter(|stage| stage.is_optional()) {
+                let handler = Handler::default();
+                let mut compiler = new_compiler(&handler);
+                compiler.pass_manager.disable(stage).unwrap();
+                compiler.parse_program_from_string(PROGRAM_WITH_LOOP, FileName::Custom("test".into())).unwrap();
+                let (_, bytecode) = compiler.compile_loaded_program().unwrap();
+                assert!(bytecode.contains("function main:"), "{stage}: {bytecode}");
+            }
+        });
+    }
+
+    #[test]
+    fn test_reject_duplicate_node_ids() {
+        create_session_if_not_set_then(|_| {
+            let mut ast = program_ast();
+            let scope = ast.ast.program_scopes.values_mut().next().unwrap();
+            let (_, function) = scope.functions[0].clone();
+            scope.functions.push((Symbol::intern("copy"), function));
+
+            let handler = Handler::default();
+            assert!(new_compiler(&handler).compile_ast(ast).is_err());
+        });
+    }
+
+    #[test]
+    fn test_transitive_imports() {
+        const IMPORTER: &str = "import a.leo;
+
+program test.aleo {
+    transition main(x: u8) -> u8 {
+        return a.leo/f(x);
+    }
+}";
+        const A: &str = "import b.leo;
+
+program a.aleo {
+    transition f(x: u8) -> u8 {
+        return b.leo/g(x);
+    }
+}";
+        const B: &str = "program b.aleo {
+    transition g(x: u8) -> u8 {
+        return x + 1u8;
+    }
+}";
+        create_session_if_not_set_then(|_| {
+            let mut resolver = VirtualResolver::default();
+            resolver.insert(Symbol::intern("a"), ImportSource::Leo(A.to_string()));
+            resolver.insert(Symbol::intern("b"), ImportSource::Leo(B.to_string()));
+
+            let handler = Handler::default();
+            let mut compiler = Compiler::new(
+                "test".to_string(),
+                "aleo".to_string(),
+                &handler,
+                PathBuf::from("test.leo"),
+                std::env::temp_dir(),
+                None,
+                Rc::new(resolver),
+            );
+            compiler.parse_program_from_string(IMPORTER, FileName::Custom("test".into())).unwrap();
+            let (_, bytecode) = compiler.compile_loaded_program().unwrap();
+
+            // The dependencies are generated in the order in which they must be deployed.
+            let names = compiler.dependencies.keys().map(|name| name.to_string()).collect::<Vec<_>>();
+            assert_eq!(names, ["b", "a"]);
+            assert!(compiler.dependencies[&Symbol::intern("a")].contains("call b.aleo/g r0 into r1;"));
+
+            // Each program is accepted by snarkVM once its dependencies are deployed.
+            let mut process = snarkvm::prelude::Process::<snarkvm::prelude::Testnet3>::load().unwrap();
+            for bytecode in compiler.dependencies.values().chain(std::iter::once(&bytecode)) {
+                process.add_program(&bytecode.parse().unwrap()).unwrap();
+            }
+        });
+    }
+
+    #[test]
+    fn test_aleo_imports() {
+        const IMPORTER: &str = "import token.aleo;
+
+program test.aleo {
+    transition main(a: u64) -> u64 {
+        return token.leo/split(a);
+    }
+}";
+        const TOKEN: &str = "program token.aleo;
+
+mapping balances:
+    key as address.public;
+    value as u64.public;
+
+function split:
+    input r0 as u64.public;
+    div r0 2u64 into r1;
+    output r1 as u64.public;
+
+function deposit:
+    input r0 as address.public;
+    input r1 as u64.public;
+    async deposit r0 r1 into r2;
+    output r2 as token.aleo/deposit.future;
+
+finalize deposit:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use balances[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into balances[r0];
+";
+        create_session_if_not_set_then(|_| {
+            let mut resolver = VirtualResolver::default();
+            resolver.insert(Symbol::intern("token"), ImportSource::Aleo(TOKEN.to_string()));
+
+            let handler = Handler::default();
+            let mut compiler = Compiler::new(
+                "test".to_string(),
+                "aleo".to_string(),
+                &handler,
+                PathBuf::from("test.leo"),
+                std::env::temp_dir(),
+                None,
+                Rc::new(resolver),
+            );
+            compiler.parse_program_from_string(IMPORTER, FileName::Custom("test".into())).unwrap();
+            let (_, bytecode) = compiler.compile_loaded_program().unwrap();
+
+            // The imported program is deployed as is.
+            assert_eq!(compiler.dependencies[&Symbol::intern("token")], TOKEN);
+            assert!(bytecode.contains("call token.aleo/split r0 into r1;"), "{bytecode}");
+
+            let mut process = snarkvm::prelude::Process::<snarkvm::prelude::Testnet3>::load().unwrap();
+            for bytecode in compiler.dependencies.values().chain(std::iter::once(&bytecode)) {
+                process.add_program(&bytecode.parse().unwrap()).unwrap();
+            }
+        });
+    }
+
+    #[test]
+    fn test_code_generation_produces_aleo_ir() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let mut compiler = new_compiler(&handler);
+            compiler.parse_program_from_string(PROGRAM, FileName::Custom("test".into())).unwrap();
+            let (symbol_table, struct_graph, call_graph) = compiler.compiler_stages().unwrap();
+            let program = compiler.code_generation_pass(&symbol_table, &struct_graph, &call_graph).unwrap();
+
+            assert_eq!(program.id, "test.aleo");
+            let main = &program.functions[0];
+            assert_eq!(main.kind, aleo_ir::FunctionKind::Function);
+            assert_eq!(main.inputs.len(), 2);
+            assert_eq!(main.inputs[0].type_, aleo_ir::ValueType {
+                type_: aleo_ir::Type::Integer(IntegerType::U8),
+                visibility: Some(aleo_ir::Visibility::Private),
+            });
+            let instructions = main.instructions.iter().map(|instruction| &instruction.instruction).collect::<Vec<_>>();
+            assert!(matches!(instructions.as_slice(), [
+                aleo_ir::Instruction::Operation { opcode: aleo_ir::Opcode::Add, .. },
+                aleo_ir::Instruction::Operation { opcode: aleo_ir::Opcode::Mul, operands, .. }
+            ] if operands[1] == aleo_ir::Operand::Literal(aleo_ir::Literal::Integer(IntegerType::U8, "2".to_string()))));
+            assert_eq!(main.outputs.len(), 1);
+            assert_eq!(main.outputs[0].operand, aleo_ir::Operand::Register(aleo_ir::Register(3)));
+
+            // The printer produces the bytecode of the program.
+            let bytecode = program.to_string();
+            assert!(bytecode.starts_with("program test.aleo;\n"));
+            assert!(bytecode.contains("\nfunction main:\n    input r0 as u8.private;\n"));
+            assert!(
+                bytecode.contains("    add r0 r1 into r2;\n    mul r2 2u8 into r3;\n    output r3 as u8.private;\n")
+            );
+        });
+    }
+}
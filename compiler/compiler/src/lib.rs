@@ -19,8 +19,20 @@
 #![allow(clippy::upper_case_acronyms)]
 #![doc = include_str!("../README.md")]
 
+mod abi;
+pub use abi::*;
+
+mod bytecode_source_map;
+pub use bytecode_source_map::*;
+
 mod compiler;
 pub use compiler::*;
 
 mod options;
 pub use options::*;
+
+mod pass_manager;
+pub use pass_manager::*;
+
+mod timings;
+pub use timings::*;
@@ -14,9 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use leo_passes::InliningStrategy;
+
 // NOTE: If compiler passes are made optional, pass preconditions and invariants may not necessarily hold true.
 
-#[derive(Clone, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct CompilerOptions {
     /// Build options.
     pub build: BuildOptions,
@@ -24,13 +26,25 @@ pub struct CompilerOptions {
     pub output: OutputOptions,
 }
 
-#[derive(Clone, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct BuildOptions {
     /// Whether to enable dead code elimination.
     pub dce_enabled: bool,
+    /// Whether to enable common subexpression elimination.
+    pub cse_enabled: bool,
+    /// Whether to compile the conditional statements in finalize blocks to branches, instead of flattening them.
+    pub finalize_branching: bool,
+    /// The optimization level. Level 1 and above run the bytecode optimizer after code generation.
+    pub optimization_level: u8,
+    /// The strategy that decides which `inline` functions are inlined.
+    pub inlining_strategy: InliningStrategy,
+    /// The maximum total number of loop iterations that loop unrolling may unroll, or `None` for no limit.
+    pub max_unrolled_iterations: Option<u64>,
+    /// Whether to annotate the generated Aleo instructions with the Leo source, as comments.
+    pub source_comments: bool,
 }
 
-#[derive(Clone, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct OutputOptions {
     //// Whether spans are enabled in the output symbol tables.
     pub symbol_table_spans_enabled: bool,
@@ -56,6 +70,28 @@ pub struct OutputOptions {
     pub destructured_ast: bool,
     /// If enabled writes the AST after inlining.
     pub inlined_ast: bool,
+    /// If enabled writes the AST after common subexpression elimination.
+    pub cse_ast: bool,
     /// If enabled writes the AST after dead code elimination.
     pub dce_ast: bool,
+    /// If enabled writes the AST after parsing as Leo code.
+    pub initial_leo: bool,
+    /// If enabled writes the AST after loop unrolling as Leo code.
+    pub unrolled_leo: bool,
+    /// If enabled writes the AST after static single assignment as Leo code.
+    pub ssa_leo: bool,
+    /// If enabled writes the AST after flattening as Leo code.
+    pub flattened_leo: bool,
+    /// If enabled writes the AST after destructuring as Leo code.
+    pub destructured_leo: bool,
+    /// If enabled writes the AST after inlining as Leo code.
+    pub inlined_leo: bool,
+    /// If enabled writes the AST after common subexpression elimination as Leo code.
+    pub cse_leo: bool,
+    /// If enabled writes the AST after dead code elimination as Leo code.
+    pub dce_leo: bool,
+    /// If enabled records the wall time of each stage, and the number of AST nodes and type table entries after it.
+    pub stage_timings: bool,
+    /// If enabled writes the snapshots to stdout instead of the output directory.
+    pub emit_to_stdout: bool,
 }
@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The configuration of the compiler pipeline.
+
+use leo_ast::{Ast, NodeBuilder};
+use leo_errors::{emitter::Handler, CompilerError, Result};
+use leo_passes::{SymbolTable, TypeTable};
+
+use indexmap::IndexSet;
+use std::{cell::RefCell, fmt, rc::Rc, str::FromStr};
+
+/// A stage of the compiler pipeline. The stages run in the order in which they are declared.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Stage {
+    SymbolTable,
+    TypeChecking,
+    LoopUnrolling,
+    StaticSingleAssignment,
+    Flattening,
+    Destructuring,
+    FunctionInlining,
+    CommonSubexpressionElimination,
+    DeadCodeElimination,
+}
+
+impl Stage {
+    /// All of the stages, in the order in which they run.
+    pub const ALL: [Stage; 9] = [
+        Stage::SymbolTable,
+        Stage::TypeChecking,
+        Stage::LoopUnrolling,
+        Stage::StaticSingleAssignment,
+        Stage::Flattening,
+        Stage::Destructuring,
+        Stage::FunctionInlining,
+        Stage::CommonSubexpressionElimination,
+        Stage::DeadCodeElimination,
+    ];
+
+    /// Returns the name of the stage.
+    pub fn name(self) -> &'static str {
+        match self {
+            Stage::SymbolTable => "symbol_table",
+            Stage::TypeChecking => "type_checking",
+            Stage::LoopUnrolling => "loop_unrolling",
+            Stage::StaticSingleAssignment => "static_single_assignment",
+            Stage::Flattening => "flattening",
+            Stage::Destructuring => "destructuring",
+            Stage::FunctionInlining => "function_inlining",
+            Stage::CommonSubexpressionElimination => "common_subexpression_elimination",
+            Stage::DeadCodeElimination => "dead_code_elimination",
+        }
+    }
+
+    /// Returns whether the stage may be disabled.
+    /// The other stages establish invariants that the later stages and code generation rely on,
+    /// e.g. loop unrolling removes loops and substitutes global constants, which the later stages assume.
+    pub fn is_optional(self) -> bool {
+        matches!(self, Stage::CommonSubexpressionElimination | Stage::DeadCodeElimination)
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Stage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Stage::ALL.into_iter().find(|stage| stage.name() == s).ok_or_else(|| format!("unknown compiler stage `{s}`"))
+    }
+}
+
+/// The state of the compilation that is visible to a custom pass.
+pub struct PassContext<'a> {
+    /// The program, as transformed by the stages that have run so far.
+    pub ast: &'a mut Ast,
+    /// The symbol table of the program.
+    pub symbol_table: &'a mut SymbolTable,
+    /// A mapping between expressions and their types.
+    pub type_table: &'a TypeTable,
+    /// The builder used to create nodes with unique IDs.
+    pub node_builder: &'a NodeBuilder,
+    /// The handler used to report errors and warnings.
+    pub handler: &'a Handler,
+}
+
+/// A pass supplied by a user of the compiler, e.g. an extra analysis that runs after type checking.
+/// A `leo_passes::Pass` can be run as a custom pass by constructing its input from the context.
+pub trait CustomPass {
+    /// Runs the pass. Errors stop the compilation.
+    fn run(&mut self, context: PassContext) -> Result<()>;
+}
+
+impl<F: FnMut(PassContext) -> Result<()>> CustomPass for F {
+    fn run(&mut self, context: PassContext) -> Result<()> {
+        self(context)
+    }
+}
+
+/// Configures the stages of the compiler pipeline: which optional stages run, and which custom passes run after each stage.
+#[derive(Clone, Default)]
+pub struct PassManager {
+    /// The optional stages that are disabled.
+    disabled: IndexSet<Stage>,
+    /// The custom passes, in the order in which they were inserted, along with the stage that each one follows.
+    custom_passes: Vec<(Stage, Rc<RefCell<dyn CustomPass>>)>,
+}
+
+impl PassManager {
+    /// Disables an optional stage.
+    pub fn disable(&mut self, stage: Stage) -> Result<&mut Self> {
+        if !stage.is_optional() {
+            return Err(CompilerError::required_pass_disabled(stage).into());
+        }
+        self.disabled.insert(stage);
+        Ok(self)
+    }
+
+    /// Returns whether `stage` runs.
+    pub fn is_enabled(&self, stage: Stage) -> bool {
+        !self.disabled.contains(&stage)
+    }
+
+    /// Runs `pass` after `stage`, and after the custom passes already inserted after `stage`.
+    /// The pass runs at this point of the pipeline even if `stage` is disabled.
+    pub fn insert_after(&mut self, stage: Stage, pass: impl CustomPass + 'static) -> &mut Self {
+        self.custom_passes.push((stage, Rc::new(RefCell::new(pass))));
+        self
+    }
+
+    /// Returns the custom passes inserted after `stage`, in order.
+    pub(crate) fn passes_after(&self, stage: Stage) -> impl Iterator<Item = &Rc<RefCell<dyn CustomPass>>> {
+        self.custom_passes.iter().filter(move |(after, _)| *after == stage).map(|(_, pass)| pass)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disable_stages() {
+        let mut pass_manager = PassManager::default();
+        assert!(pass_manager.disable(Stage::CommonSubexpressionElimination).is_ok());
+        assert!(pass_manager.disable(Stage::TypeChecking).is_err());
+        assert!(pass_manager.disable(Stage::LoopUnrolling).is_err());
+        assert!(!pass_manager.is_enabled(Stage::CommonSubexpressionElimination));
+        assert!(pass_manager.is_enabled(Stage::LoopUnrolling));
+        assert!(pass_manager.is_enabled(Stage::DeadCodeElimination));
+    }
+
+    #[test]
+    fn test_stage_names() {
+        for stage in Stage::ALL {
+            assert_eq!(stage.name().parse::<Stage>(), Ok(stage));
+        }
+        assert!("parsing".parse::<Stage>().is_err());
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Statistics about the program after each stage of the compiler.
+
+use leo_ast::{Ast, Expression, ExpressionVisitor, Function, ProgramVisitor, Statement, StatementVisitor};
+use leo_passes::TypeTable;
+
+use serde::Serialize;
+use std::time::Duration;
+
+/// The wall time of a compiler stage, along with the number of AST nodes and type table entries after the stage.
+/// Note that these are counts, not measurements of memory.
+#[derive(Clone, Debug, Serialize)]
+pub struct StageTiming {
+    /// The name of the stage.
+    pub stage: String,
+    /// The wall time of the stage, in milliseconds.
+    pub duration_ms: f64,
+    /// The number of functions, statements, and expressions in the AST after the stage.
+    pub ast_nodes: usize,
+    /// The number of entries in the type table after the stage.
+    pub type_table_entries: usize,
+}
+
+impl StageTiming {
+    /// Records the statistics of the stage `stage`, which took `duration`.
+    pub fn new(stage: &str, duration: Duration, ast: &Ast, type_table: &TypeTable) -> Self {
+        let mut counter = NodeCounter::default();
+        counter.visit_program(ast.as_repr());
+        Self {
+            stage: stage.to_string(),
+            duration_ms: duration.as_secs_f64() * 1000.0,
+            ast_nodes: counter.count,
+            type_table_entries: type_table.len(),
+        }
+    }
+}
+
+/// Counts the functions, statements, and expressions in a program.
+#[derive(Default)]
+struct NodeCounter {
+    count: usize,
+}
+
+impl<'a> ExpressionVisitor<'a> for NodeCounter {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_expression(&mut self, input: &'a Expression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.count += 1;
+        match input {
+            Expression::Access(access) => self.visit_access(access, additional),
+            Expression::Array(array) => self.visit_array(array, additional),
+            Expression::Binary(binary) => self.visit_binary(binary, additional),
+            Expression::Call(call) => self.visit_call(call, additional),
+            Expression::Cast(cast) => self.visit_cast(cast, additional),
+            Expression::Struct(struct_) => self.visit_struct_init(struct_, additional),
+            Expression::Err(_) => {}
+            Expression::Identifier(identifier) => self.visit_identifier(identifier, additional),
+            Expression::Literal(literal) => self.visit_literal(literal, additional),
+            Expression::Ternary(ternary) => self.visit_ternary(ternary, additional),
+            Expression::Tuple(tuple) => self.visit_tuple(tuple, additional),
+            Expression::Unary(unary) => self.visit_unary(unary, additional),
+            Expression::Unit(unit) => self.visit_unit(unit, additional),
+        }
+    }
+}
+
+impl<'a> StatementVisitor<'a> for NodeCounter {
+    fn visit_statement(&mut self, input: &'a Statement) {
+        self.count += 1;
+        match input {
+            Statement::Assert(stmt) => self.visit_assert(stmt),
+            Statement::Assign(stmt) => self.visit_assign(stmt),
+            Statement::Block(stmt) => self.visit_block(stmt),
+            Statement::Conditional(stmt) => self.visit_conditional(stmt),
+            Statement::Console(stmt) => self.visit_console(stmt),
+            Statement::Const(stmt) => self.visit_const(stmt),
+            Statement::Definition(stmt) => self.visit_definition(stmt),
+            Statement::Expression(stmt) => self.visit_expression_statement(stmt),
+            Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Return(stmt) => self.visit_return(stmt),
+        }
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for NodeCounter {
+    fn visit_function(&mut self, input: &'a Function) {
+        self.count += 1;
+        self.visit_block(&input.block);
+        if let Some(finalize) = &input.finalize {
+            self.visit_block(&finalize.block);
+        }
+    }
+}
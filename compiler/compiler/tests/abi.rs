@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::{
+    Abi,
+    BuildOptions,
+    Compiler,
+    CompilerOptions,
+    FieldAbi,
+    InliningStrategy,
+    OutputAbi,
+    VirtualResolver,
+};
+use leo_errors::emitter::Handler;
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+use std::{path::PathBuf, rc::Rc};
+
+const PROGRAM: &str = "program token.aleo {
+    struct Metadata {
+        supply: u64,
+        decimals: u8,
+    }
+
+    record Token {
+        owner: address,
+        amount: u64,
+        public tag: field,
+    }
+
+    mapping balances: address => u64;
+
+    transition mint(receiver: address, public amount: u64) -> (Token, public u64) {
+        return (Token { owner: receiver, amount, tag: 0field }, amount);
+    }
+
+    transition deposit(token: Token, metadata: Metadata) -> u64 {
+        return token.amount + metadata.supply then finalize(token.owner, token.amount);
+    } finalize deposit(owner: address, amount: u64) {
+        let balance: u64 = Mapping::get_or_use(balances, owner, 0u64);
+        Mapping::set(balances, owner, balance + amount);
+    }
+
+    function helper(x: u64) -> u64 {
+        return x;
+    }
+}";
+
+/// Runs the compiler stages on `PROGRAM`, returning its ABI.
+fn abi() -> Abi {
+    let handler = Handler::default();
+    let options = CompilerOptions {
+        build: BuildOptions {
+            dce_enabled: true,
+            cse_enabled: false,
+            finalize_branching: false,
+            optimization_level: 0,
+            inlining_strategy: InliningStrategy::Always,
+            max_unrolled_iterations: None,
+            source_comments: false,
+        },
+        output: Default::default(),
+    };
+    let mut compiler = Compiler::new(
+        "token".to_string(),
+        "aleo".to_string(),
+        &handler,
+        PathBuf::from("token.leo"),
+        std::env::temp_dir(),
+        Some(options),
+        Rc::new(VirtualResolver::default()),
+    );
+    compiler.parse_program_from_string(PROGRAM, FileName::Custom("token".into())).unwrap();
+    compiler.compiler_stages().unwrap();
+    compiler.abi
+}
+
+fn field(name: &str, type_: &str, mode: Option<&str>) -> FieldAbi {
+    FieldAbi { name: name.to_string(), type_: type_.to_string(), mode: mode.map(str::to_string) }
+}
+
+fn output(type_: &str, mode: &str) -> OutputAbi {
+    OutputAbi { type_: type_.to_string(), mode: mode.to_string() }
+}
+
+#[test]
+fn abi_describes_structs_records_and_mappings() {
+    create_session_if_not_set_then(|_| {
+        let abi = abi();
+        assert_eq!(abi.program, "token.aleo");
+        assert!(abi.imports.is_empty());
+
+        // Fields of structs have no visibility, and fields of records are private unless they declare one.
+        assert_eq!(abi.structs.len(), 1);
+        assert_eq!(abi.structs[0].name, "Metadata");
+        assert_eq!(abi.structs[0].fields, vec![field("supply", "u64", None), field("decimals", "u8", None)]);
+        assert_eq!(abi.records.len(), 1);
+        assert_eq!(abi.records[0].name, "Token");
+        assert_eq!(abi.records[0].fields, vec![
+            field("owner", "address", Some("private")),
+            field("amount", "u64", Some("private")),
+            field("tag", "field", Some("public")),
+        ]);
+
+        assert_eq!(abi.mappings.len(), 1);
+        assert_eq!((abi.mappings[0].name.as_str(), abi.mappings[0].key.as_str()), ("balances", "address"));
+        assert_eq!(abi.mappings[0].value, "u64");
+    });
+}
+
+#[test]
+fn abi_describes_transitions() {
+    create_session_if_not_set_then(|_| {
+        let abi = abi();
+
+        // Only transitions are part of the interface.
+        let names: Vec<&str> = abi.transitions.iter().map(|transition| transition.name.as_str()).collect();
+        assert_eq!(names, ["mint", "deposit"]);
+
+        // Inputs and outputs are private, and finalize inputs are public, unless they declare a visibility.
+        let mint = &abi.transitions[0];
+        assert_eq!(mint.inputs, vec![
+            field("receiver", "address", Some("private")),
+            field("amount", "u64", Some("public"))
+        ]);
+        assert_eq!(mint.outputs, vec![output("Token", "private"), output("u64", "public")]);
+        assert_eq!(mint.finalize, None);
+
+        let deposit = &abi.transitions[1];
+        assert_eq!(deposit.inputs, vec![
+            field("token", "Token", Some("private")),
+            field("metadata", "Metadata", Some("private"))
+        ]);
+        assert_eq!(
+            deposit.finalize,
+            Some(vec![field("owner", "address", Some("public")), field("amount", "u64", Some("public"))])
+        );
+    });
+}
+
+#[test]
+fn abi_round_trips_through_json() {
+    create_session_if_not_set_then(|_| {
+        let abi = abi();
+        let json = serde_json::to_value(&abi).unwrap();
+        assert_eq!(
+            json["transitions"][0]["inputs"][1],
+            serde_json::json!({"name": "amount", "type": "u64", "mode": "public"})
+        );
+        assert_eq!(json["structs"][0]["fields"][0], serde_json::json!({"name": "supply", "type": "u64"}));
+        assert!(json["transitions"][0].get("finalize").is_none());
+        assert_eq!(serde_json::from_value::<Abi>(json).unwrap(), abi);
+    });
+}
@@ -0,0 +1,261 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Checks that the bytecode optimizer preserves the behavior of programs.
+//! Each program is compiled, and its functions are evaluated before and after optimization on the same inputs.
+//! Wherever the original function succeeds, the optimized function must produce the same outputs.
+//! Note that the optimized function may succeed where the original halts, since unused operations are removed.
+
+use leo_ast::IntegerType;
+use leo_compiler::{Compiler, VirtualResolver};
+use leo_errors::emitter::Handler;
+use leo_passes::{
+    aleo_ir::{self, Instruction, Literal, Opcode, Operand, Register, Type},
+    BytecodeOptimizer,
+    Pass,
+};
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+use std::{collections::HashMap, path::PathBuf, rc::Rc};
+
+/// Programs over `u32`s and `boolean`s, which contain the patterns that the optimizer removes.
+const PROGRAMS: [&str; 4] = [
+    // Duplicated comparisons, casts to the same type, and ternaries with constant conditions or equal branches.
+    "program test.aleo {
+    transition main(a: u32, b: u32, flag: bool) -> (bool, u32) {
+        let x: bool = a == b;
+        let y: bool = (a as u32) == b;
+        let c: u32 = true ? a + b : b;
+        let d: u32 = flag ? c : c;
+        return (x && y, d.add_wrapped(a + b));
+    }
+}",
+    // Conditionals, whose guards are duplicated by flattening.
+    "program test.aleo {
+    transition main(a: u32, b: u32, flag: bool) -> u32 {
+        let result: u32 = a;
+        if flag && a < b {
+            result = a.mul_wrapped(b);
+        } else if flag && !(a < b) {
+            result = b.sub_wrapped(a);
+        }
+        if a < b {
+            result = result.add_wrapped(1u32);
+        }
+        return result;
+    }
+}",
+    // Closures and assertions.
+    "program test.aleo {
+    function helper(a: u32, b: u32) -> u32 {
+        let x: u32 = a.add_wrapped(b);
+        let y: u32 = a.add_wrapped(b);
+        return x.mul_wrapped(y);
+    }
+
+    transition main(a: u32, b: u32, flag: bool) -> u32 {
+        assert_eq(a == b, a == b);
+        let c: u32 = flag ? helper(a, b) : helper(a, b);
+        return c / (b - a);
+    }
+}",
+    // Checked arithmetic that halts on some inputs.
+    "program test.aleo {
+    transition main(a: u32, b: u32) -> u32 {
+        let x: u32 = a - b;
+        let y: u32 = (a - b) as u32;
+        return x * y;
+    }
+}",
+];
+
+/// The inputs on which each function is evaluated, for each type.
+const U32_INPUTS: [u32; 5] = [0, 1, 2, 7, u32::MAX];
+const BOOLEAN_INPUTS: [bool; 2] = [true, false];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Value {
+    Boolean(bool),
+    U32(u32),
+}
+
+/// Compiles `source` to Aleo instructions, without optimizing them.
+fn compile(source: &str) -> aleo_ir::Program {
+    let handler = Handler::default();
+    let mut compiler = Compiler::new(
+        "test".to_string(),
+        "aleo".to_string(),
+        &handler,
+        PathBuf::from("test.leo"),
+        std::env::temp_dir(),
+        None,
+        Rc::new(VirtualResolver::default()),
+    );
+    compiler.parse_program_from_string(source, FileName::Custom("test".into())).unwrap();
+    let (symbol_table, struct_graph, call_graph) = compiler.compiler_stages().unwrap();
+    compiler.code_generation_pass(&symbol_table, &struct_graph, &call_graph).unwrap()
+}
+
+/// Returns the number of instructions in the program.
+fn instruction_count(program: &aleo_ir::Program) -> usize {
+    program.functions.iter().map(|function| function.instructions.len()).sum()
+}
+
+/// Returns every combination of inputs for a function with the given input types.
+fn input_combinations(function: &aleo_ir::Function) -> Vec<Vec<Value>> {
+    function.inputs.iter().fold(vec![Vec::new()], |combinations, input| {
+        let values: Vec<Value> = match &input.type_.type_ {
+            Type::Integer(IntegerType::U32) => U32_INPUTS.into_iter().map(Value::U32).collect(),
+            Type::Boolean => BOOLEAN_INPUTS.into_iter().map(Value::Boolean).collect(),
+            type_ => panic!("unsupported input type `{type_}`"),
+        };
+        combinations
+            .iter()
+            .flat_map(|combination| {
+                values.iter().map(move |value| {
+                    let mut combination = combination.clone();
+                    combination.push(value.clone());
+                    combination
+                })
+            })
+            .collect()
+    })
+}
+
+fn read(registers: &HashMap<Register, Value>, operand: &Operand) -> Value {
+    match operand {
+        Operand::Register(register) => registers[register].clone(),
+        Operand::Literal(Literal::Boolean(boolean)) => Value::Boolean(*boolean),
+        Operand::Literal(Literal::Integer(IntegerType::U32, value)) => Value::U32(value.parse().unwrap()),
+        _ => panic!("unsupported operand `{operand}`"),
+    }
+}
+
+/// Applies an operation to its operands, or returns `None` if it halts.
+fn operate(opcode: Opcode, operands: &[Value]) -> Option<Value> {
+    use Value::*;
+    Some(match (opcode, operands) {
+        (Opcode::Add, [U32(a), U32(b)]) => U32(a.checked_add(*b)?),
+        (Opcode::Sub, [U32(a), U32(b)]) => U32(a.checked_sub(*b)?),
+        (Opcode::Mul, [U32(a), U32(b)]) => U32(a.checked_mul(*b)?),
+        (Opcode::Div, [U32(a), U32(b)]) => U32(a.checked_div(*b)?),
+        (Opcode::AddWrapped, [U32(a), U32(b)]) => U32(a.wrapping_add(*b)),
+        (Opcode::SubWrapped, [U32(a), U32(b)]) => U32(a.wrapping_sub(*b)),
+        (Opcode::MulWrapped, [U32(a), U32(b)]) => U32(a.wrapping_mul(*b)),
+        (Opcode::IsEq, [a, b]) => Boolean(a == b),
+        (Opcode::IsNeq, [a, b]) => Boolean(a != b),
+        (Opcode::Lt, [U32(a), U32(b)]) => Boolean(a < b),
+        (Opcode::Lte, [U32(a), U32(b)]) => Boolean(a <= b),
+        (Opcode::Gt, [U32(a), U32(b)]) => Boolean(a > b),
+        (Opcode::Gte, [U32(a), U32(b)]) => Boolean(a >= b),
+        (Opcode::And, [Boolean(a), Boolean(b)]) => Boolean(*a && *b),
+        (Opcode::Or, [Boolean(a), Boolean(b)]) => Boolean(*a || *b),
+        (Opcode::Xor, [Boolean(a), Boolean(b)]) => Boolean(a ^ b),
+        (Opcode::Not, [Boolean(a)]) => Boolean(!a),
+        (Opcode::Ternary, [Boolean(condition), if_true, if_false]) => match condition {
+            true => if_true.clone(),
+            false => if_false.clone(),
+        },
+        // The programs only cast values to their own type.
+        (Opcode::Cast, [value]) => value.clone(),
+        _ => panic!("unsupported operation `{opcode}` on {operands:?}"),
+    })
+}
+
+/// Evaluates the function `name` of `program`, or returns `None` if it halts.
+fn evaluate(program: &aleo_ir::Program, name: &str, arguments: &[Value]) -> Option<Vec<Value>> {
+    let function = program.functions.iter().find(|function| function.name == name).unwrap();
+    let mut registers: HashMap<Register, Value> =
+        function.inputs.iter().map(|input| input.register).zip(arguments.iter().cloned()).collect();
+
+    for instruction in function.instructions.iter() {
+        match &instruction.instruction {
+            Instruction::Operation { opcode, operands, destination, .. } => {
+                let operands: Vec<Value> = operands.iter().map(|operand| read(&registers, operand)).collect();
+                registers.insert(*destination, operate(*opcode, &operands)?);
+            }
+            Instruction::AssertEq(left, right) => {
+                if read(&registers, left) != read(&registers, right) {
+                    return None;
+                }
+            }
+            Instruction::AssertNeq(left, right) => {
+                if read(&registers, left) == read(&registers, right) {
+                    return None;
+                }
+            }
+            Instruction::Call { callee, arguments, destinations } => {
+                let arguments: Vec<Value> = arguments.iter().map(|argument| read(&registers, argument)).collect();
+                let outputs = evaluate(program, callee, &arguments)?;
+                assert_eq!(outputs.len(), destinations.len());
+                registers.extend(destinations.iter().copied().zip(outputs));
+            }
+            _ => panic!("unsupported instruction `{instruction}`"),
+        }
+    }
+
+    Some(function.outputs.iter().map(|output| read(&registers, &output.operand)).collect())
+}
+
+/// Checks that each register is defined once, in order, starting after the inputs.
+fn check_registers(function: &aleo_ir::Function) {
+    let inputs = function.inputs.iter().map(|input| input.register);
+    let destinations = function.instructions.iter().flat_map(|instruction| instruction.instruction.destinations());
+    for (index, register) in inputs.chain(destinations.copied()).enumerate() {
+        assert_eq!(register, Register(index as u64), "registers of `{}` are not consecutive", function.name);
+    }
+}
+
+#[test]
+fn optimized_programs_are_equivalent() {
+    create_session_if_not_set_then(|_| {
+        let mut original_count = 0;
+        let mut optimized_count = 0;
+        for source in PROGRAMS {
+            let original = compile(source);
+            let optimized = BytecodeOptimizer::do_pass(original.clone());
+
+            for function in optimized.functions.iter() {
+                check_registers(function);
+                for arguments in input_combinations(function) {
+                    if let Some(outputs) = evaluate(&original, &function.name, &arguments) {
+                        assert_eq!(
+                            evaluate(&optimized, &function.name, &arguments),
+                            Some(outputs),
+                            "`{}` differs on {arguments:?}\noriginal:\n{original}\noptimized:\n{optimized}",
+                            function.name,
+                        );
+                    }
+                }
+            }
+
+            assert!(instruction_count(&optimized) <= instruction_count(&original));
+            original_count += instruction_count(&original);
+            optimized_count += instruction_count(&optimized);
+        }
+        assert!(optimized_count < original_count);
+    });
+}
+
+#[test]
+fn optimization_is_idempotent() {
+    create_session_if_not_set_then(|_| {
+        for source in PROGRAMS {
+            let optimized = BytecodeOptimizer::do_pass(compile(source));
+            assert_eq!(BytecodeOptimizer::do_pass(optimized.clone()), optimized);
+        }
+    });
+}
@@ -0,0 +1,160 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Measures the instructions that common subexpression elimination removes from the generated bytecode.
+//! Run with `--nocapture` to print the reduction for each of the example programs.
+
+use leo_compiler::{
+    BuildOptions,
+    Compiler,
+    CompilerOptions,
+    FileSystemResolver,
+    ImportResolver,
+    InliningStrategy,
+    VirtualResolver,
+};
+use leo_errors::emitter::Handler;
+use leo_passes::aleo_ir;
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+use std::{fs, path::PathBuf, rc::Rc};
+
+const PROGRAM: &str = "program test.aleo {
+    struct Point {
+        x: field,
+        y: field,
+    }
+
+    transition main(a: Point, b: Point, flag: bool) -> field {
+        let h: field = BHP256::hash_to_field(a.x + b.x);
+        let s: field = flag ? a.x + b.x : BHP256::hash_to_field(a.x + b.x);
+        if flag && a.y == b.y {
+            s += h;
+        } else if flag {
+            s -= h;
+        }
+        return s + BHP256::hash_to_field(a.x + b.x);
+    }
+}";
+
+/// Returns the options of a build with dead code elimination, and with or without common subexpression elimination.
+fn options(cse_enabled: bool) -> CompilerOptions {
+    CompilerOptions {
+        build: BuildOptions {
+            dce_enabled: true,
+            cse_enabled,
+            finalize_branching: false,
+            optimization_level: 0,
+            inlining_strategy: InliningStrategy::Always,
+            max_unrolled_iterations: None,
+            source_comments: false,
+        },
+        output: Default::default(),
+    }
+}
+
+/// Compiles the program `name`, whose source is `source`, to Aleo instructions.
+fn compile(name: &str, source: &str, import_resolver: Rc<dyn ImportResolver>, cse_enabled: bool) -> aleo_ir::Program {
+    let handler = Handler::default();
+    let mut compiler = Compiler::new(
+        name.to_string(),
+        "aleo".to_string(),
+        &handler,
+        PathBuf::from(format!("{name}.leo")),
+        std::env::temp_dir(),
+        Some(options(cse_enabled)),
+        import_resolver,
+    );
+    compiler.parse_program_from_string(source, FileName::Custom(name.to_string())).unwrap();
+    let (symbol_table, struct_graph, call_graph) = compiler.compiler_stages().unwrap();
+    compiler.code_generation_pass(&symbol_table, &struct_graph, &call_graph).unwrap()
+}
+
+/// Returns the number of instructions in the functions and finalize blocks of the program.
+fn instruction_count(program: &aleo_ir::Program) -> usize {
+    program
+        .functions
+        .iter()
+        .map(|function| {
+            function.instructions.len() + function.finalize.as_ref().map_or(0, |finalize| finalize.instructions.len())
+        })
+        .sum()
+}
+
+/// Returns the number of operations with the given opcode in the program.
+fn opcode_count(program: &aleo_ir::Program, opcode: aleo_ir::Opcode) -> usize {
+    program
+        .functions
+        .iter()
+        .flat_map(|function| function.instructions.iter())
+        .filter(
+            |instruction| matches!(&instruction.instruction, aleo_ir::Instruction::Operation { opcode: op, .. } if *op == opcode),
+        )
+        .count()
+}
+
+#[test]
+fn duplicate_expressions_are_computed_once() {
+    create_session_if_not_set_then(|_| {
+        let resolver: Rc<dyn ImportResolver> = Rc::new(VirtualResolver::default());
+        let original = compile("test", PROGRAM, resolver.clone(), false);
+        let optimized = compile("test", PROGRAM, resolver, true);
+
+        let hash = aleo_ir::Opcode::Hash(aleo_ir::Algorithm::Bhp256);
+        assert_eq!(opcode_count(&original, hash), 3);
+        assert_eq!(opcode_count(&optimized, hash), 1);
+        assert!(instruction_count(&optimized) < instruction_count(&original));
+        // The signatures of the functions are unchanged.
+        assert_eq!(original.functions.len(), optimized.functions.len());
+        for (original, optimized) in original.functions.iter().zip(optimized.functions.iter()) {
+            assert_eq!(original.inputs, optimized.inputs);
+            assert_eq!(original.outputs.len(), optimized.outputs.len());
+        }
+    });
+}
+
+#[test]
+fn measure_examples() {
+    let examples = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../examples");
+    let mut packages: Vec<PathBuf> = fs::read_dir(&examples)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.join("src/main.leo").exists())
+        .collect();
+    packages.sort();
+
+    create_session_if_not_set_then(|_| {
+        let (mut total_original, mut total_optimized) = (0, 0);
+        println!("{:<16} {:>8} {:>8} {:>8}", "example", "before", "after", "removed");
+        for package in packages {
+            let manifest: serde_json::Value =
+                serde_json::from_str(&fs::read_to_string(package.join("program.json")).unwrap()).unwrap();
+            let program_id = manifest["program"].as_str().unwrap();
+            let name = program_id.strip_suffix(".aleo").unwrap_or(program_id);
+            let source = fs::read_to_string(package.join("src/main.leo")).unwrap();
+            let resolver: Rc<dyn ImportResolver> = Rc::new(FileSystemResolver::for_package(&package));
+
+            let original = instruction_count(&compile(name, &source, resolver.clone(), false));
+            let optimized = instruction_count(&compile(name, &source, resolver, true));
+            println!("{name:<16} {original:>8} {optimized:>8} {:>8}", original - optimized);
+            assert!(optimized <= original, "common subexpression elimination added instructions to `{name}`");
+
+            total_original += original;
+            total_optimized += optimized;
+        }
+        println!("{:<16} {total_original:>8} {total_optimized:>8} {:>8}", "total", total_original - total_optimized);
+    });
+}
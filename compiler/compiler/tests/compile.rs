@@ -98,7 +98,18 @@ fn run_test(test: Test, handler: &Handler, buf: &BufferEmitter) -> Result<Value,
                 flattened_ast: true,
                 destructured_ast: true,
                 inlined_ast: true,
+                cse_ast: false,
                 dce_ast: true,
+                initial_leo: false,
+                unrolled_leo: false,
+                ssa_leo: false,
+                flattened_leo: false,
+                destructured_leo: false,
+                inlined_leo: false,
+                cse_leo: false,
+                dce_leo: false,
+                stage_timings: false,
+                emit_to_stdout: false,
             },
         };
 
@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::{BuildOptions, Compiler, CompilerOptions, InliningStrategy, VirtualResolver};
+use leo_errors::emitter::Handler;
+use leo_passes::aleo_ir::{Instruction, Opcode, Program};
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+use std::{path::PathBuf, rc::Rc};
+
+const PROGRAM: &str = "program test.aleo {
+    mapping balances: address => u64;
+    mapping counts: u8 => u64;
+
+    transition transfer(receiver: address, amount: u64) {
+        return then finalize(self.caller, receiver, amount);
+    }
+
+    finalize transfer(sender: address, receiver: address, amount: u64) {
+        if amount > 0u64 {
+            let sender_balance: u64 = Mapping::get(balances, sender);
+            Mapping::set(balances, sender, sender_balance - amount);
+            let receiver_balance: u64 = Mapping::get_or_use(balances, receiver, 0u64);
+            Mapping::set(balances, receiver, receiver_balance + amount);
+        } else {
+            Mapping::remove(balances, sender);
+        }
+    }
+
+    transition accumulate(amount: u64) {
+        return then finalize(amount);
+    }
+
+    finalize accumulate(amount: u64) {
+        let total: u64 = Mapping::get_or_use(counts, 0u8, 0u64);
+        if amount > 10u64 {
+            total = total + amount;
+        }
+        Mapping::set(counts, 0u8, total);
+    }
+
+    transition nested(amount: u64) {
+        return then finalize(amount);
+    }
+
+    finalize nested(amount: u64) {
+        if amount > 0u64 {
+            let doubled: u64 = Mapping::get_or_use(counts, 1u8, amount);
+            if amount > 10u64 {
+                doubled = doubled * 2u64;
+            }
+            Mapping::set(counts, 1u8, doubled);
+        }
+    }
+
+    transition early_return(amount: u64) {
+        return then finalize(amount);
+    }
+
+    finalize early_return(amount: u64) {
+        if amount == 0u64 {
+            return;
+        }
+        Mapping::set(counts, 2u8, amount);
+    }
+}";
+
+/// Compiles `PROGRAM` to Aleo instructions, with or without finalize branching.
+fn compile(finalize_branching: bool) -> Program {
+    let handler = Handler::default();
+    let options = CompilerOptions {
+        build: BuildOptions {
+            dce_enabled: true,
+            cse_enabled: false,
+            finalize_branching,
+            optimization_level: 0,
+            inlining_strategy: InliningStrategy::Always,
+            max_unrolled_iterations: None,
+            source_comments: false,
+        },
+        output: Default::default(),
+    };
+    let mut compiler = Compiler::new(
+        "test".to_string(),
+        "aleo".to_string(),
+        &handler,
+        PathBuf::from("test.leo"),
+        std::env::temp_dir(),
+        Some(options),
+        Rc::new(VirtualResolver::default()),
+    );
+    compiler.parse_program_from_string(PROGRAM, FileName::Custom("test".into())).unwrap();
+    let (symbol_table, struct_graph, call_graph) = compiler.compiler_stages().unwrap();
+    compiler.code_generation_pass(&symbol_table, &struct_graph, &call_graph).unwrap()
+}
+
+/// Returns the instructions of the finalize block of the function `name`.
+fn finalize(program: &Program, name: &str) -> Vec<Instruction> {
+    let function = program.functions.iter().find(|function| function.name == name).unwrap();
+    function.finalize.as_ref().unwrap().instructions.iter().map(|instruction| instruction.instruction.clone()).collect()
+}
+
+/// Returns the number of branches and ternaries in the instructions.
+fn count(instructions: &[Instruction]) -> (usize, usize) {
+    let branches =
+        instructions.iter().filter(|instruction| matches!(instruction, Instruction::BranchEq { .. })).count();
+    let ternaries = instructions
+        .iter()
+        .filter(|instruction| matches!(instruction, Instruction::Operation { opcode: Opcode::Ternary, .. }))
+        .count();
+    (branches, ternaries)
+}
+
+#[test]
+fn conditionals_in_finalize_are_compiled_to_branches() {
+    create_session_if_not_set_then(|_| {
+        let program = compile(true);
+        let instructions = finalize(&program, "transfer");
+        let lines: Vec<String> = instructions.iter().map(|instruction| instruction.to_string()).collect();
+
+        // The `then` block is skipped if the condition is false, and the `otherwise` block is skipped after it.
+        assert!(lines[1].starts_with("branch.eq r3 false to end_then_0;"), "{lines:#?}");
+        let end_then = lines.iter().position(|line| line == "position end_then_0;").unwrap();
+        assert_eq!(lines[end_then - 1], "branch.eq true true to end_otherwise_0;");
+        assert_eq!(lines.last().unwrap(), "position end_otherwise_0;");
+
+        // The mapping is only read in the `then` block, and only removed from in the `otherwise` block.
+        let get = lines.iter().position(|line| line.starts_with("get balances[")).unwrap();
+        let remove = lines.iter().position(|line| line.starts_with("remove balances[")).unwrap();
+        assert!(get < end_then && end_then < remove);
+        assert_eq!(count(&instructions), (2, 0));
+
+        // Without finalize branching, the conditional is flattened.
+        let program = compile(false);
+        assert_eq!(count(&finalize(&program, "transfer")).0, 0);
+    });
+}
+
+#[test]
+fn conditionals_whose_variables_are_used_after_them_are_flattened() {
+    create_session_if_not_set_then(|_| {
+        let program = compile(true);
+        // The variable `total` is assigned in the conditional and used after it.
+        assert_eq!(count(&finalize(&program, "accumulate")), (0, 1));
+        // The outer conditional is a branch, and the inner one assigns a variable that is used in the outer block.
+        assert_eq!(count(&finalize(&program, "nested")), (1, 1));
+        // A conditional that returns is flattened, along with its return.
+        assert_eq!(count(&finalize(&program, "early_return")).0, 0);
+    });
+}
@@ -0,0 +1,218 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::{BuildOptions, Compiler, CompilerOptions, InlinedCall, InliningStrategy, VirtualResolver};
+use leo_errors::emitter::Handler;
+use leo_passes::aleo_ir::{FunctionKind, Instruction, Program, SpannedInstruction};
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+use std::{path::PathBuf, rc::Rc};
+
+const PROGRAM: &str = "program test.aleo {
+    mapping values: u8 => u64;
+
+    inline large(a: u64, b: u64) -> u64 {
+        let c: u64 = a * b + a;
+        let d: u64 = c * c - b;
+        let e: u64 = d / 2u64 + c;
+        return e * e + d;
+    }
+
+    inline small(a: u64) -> u64 {
+        return a + 1u64;
+    }
+
+    @inline(never)
+    inline kept(a: u64) -> u64 {
+        return a * 3u64;
+    }
+
+    @inline(always)
+    inline forced(a: u64, b: u64) -> u64 {
+        let c: u64 = a * b + a;
+        let d: u64 = c * c - b;
+        return d / 2u64 + c;
+    }
+
+    transition main(x: u64, y: u64) -> u64 {
+        let a: u64 = large(x, y);
+        let b: u64 = large(y, x);
+        let c: u64 = large(a, b);
+        let d: u64 = small(a) + small(b) + small(c);
+        let e: u64 = forced(d, x) + forced(d, y) + forced(d, a);
+        return kept(e);
+    }
+
+    transition store(x: u64) {
+        return then finalize(x);
+    }
+
+    finalize store(x: u64) {
+        Mapping::set(values, 0u8, kept(x));
+    }
+}";
+
+const NESTED_PROGRAM: &str = "program test.aleo {
+    mapping values: u8 => u64;
+
+    record token {
+        owner: address,
+        amount: u64,
+    }
+
+    @inline(never)
+    inline kept(a: u64) -> u64 {
+        return a * 3u64;
+    }
+
+    inline wrapper(a: u64) -> u64 {
+        return kept(a) + kept(a + 1u64);
+    }
+
+    @inline(never)
+    inline amount(t: token) -> u64 {
+        return t.amount;
+    }
+
+    transition main(x: u64, t: token) -> u64 {
+        return wrapper(x) + amount(t);
+    }
+
+    transition store(x: u64) {
+        return then finalize(x);
+    }
+
+    finalize store(x: u64) {
+        Mapping::set(values, 0u8, wrapper(x));
+    }
+}";
+
+/// Compiles `program` with the given inlining strategy, returning the Aleo instructions and the inlining report.
+fn compile(program: &str, inlining_strategy: InliningStrategy) -> (Program, Vec<InlinedCall>) {
+    let handler = Handler::default();
+    let options = CompilerOptions {
+        build: BuildOptions {
+            dce_enabled: true,
+            cse_enabled: false,
+            finalize_branching: false,
+            optimization_level: 0,
+            inlining_strategy,
+            max_unrolled_iterations: None,
+            source_comments: false,
+        },
+        output: Default::default(),
+    };
+    let mut compiler = Compiler::new(
+        "test".to_string(),
+        "aleo".to_string(),
+        &handler,
+        PathBuf::from("test.leo"),
+        std::env::temp_dir(),
+        Some(options),
+        Rc::new(VirtualResolver::default()),
+    );
+    compiler.parse_program_from_string(program, FileName::Custom("test".into())).unwrap();
+    let (symbol_table, struct_graph, call_graph) = compiler.compiler_stages().unwrap();
+    let program = compiler.code_generation_pass(&symbol_table, &struct_graph, &call_graph).unwrap();
+    (program, compiler.inlining_report)
+}
+
+/// Returns the kind of the function `name`.
+fn kind(program: &Program, name: &str) -> FunctionKind {
+    program.functions.iter().find(|function| function.name == name).unwrap().kind
+}
+
+/// Returns the number of calls to `callee` in the instructions.
+fn calls(instructions: &[SpannedInstruction], callee: &str) -> usize {
+    instructions
+        .iter()
+        .filter(
+            |instruction| matches!(&instruction.instruction, Instruction::Call { callee: name, .. } if name == callee),
+        )
+        .count()
+}
+
+/// Returns whether the report contains a call from `caller` to `callee` that was, or was not, inlined.
+fn reported(report: &[InlinedCall], caller: &str, callee: &str, inlined: bool) -> bool {
+    report
+        .iter()
+        .any(|call| call.caller.to_string() == caller && call.callee.to_string() == callee && call.inlined == inlined)
+}
+
+#[test]
+fn cost_strategy_emits_large_functions_as_closures() {
+    create_session_if_not_set_then(|_| {
+        let (program, report) = compile(PROGRAM, InliningStrategy::Cost);
+        let main = &program.functions.iter().find(|function| function.name == "main").unwrap().instructions;
+
+        // The large function is called three times, so it is cheaper to call it than to inline it.
+        assert_eq!(kind(&program, "large"), FunctionKind::Closure);
+        assert_eq!(calls(main, "large"), 3);
+        assert!(reported(&report, "main", "large", false));
+
+        // The small function is cheaper to inline, and `@inline(always)` takes precedence over the cost.
+        assert_eq!(kind(&program, "small"), FunctionKind::Inline);
+        assert_eq!(kind(&program, "forced"), FunctionKind::Inline);
+        assert_eq!(calls(main, "small") + calls(main, "forced"), 0);
+        assert!(reported(&report, "main", "small", true));
+
+        // The closure declarations precede the transitions that call them.
+        let position = |name: &str| program.functions.iter().position(|function| function.name == name).unwrap();
+        assert!(position("large") < position("main") && position("kept") < position("main"));
+        assert!(program.to_string().contains("closure large:"));
+    });
+}
+
+#[test]
+fn never_annotated_functions_are_only_inlined_into_finalize_blocks() {
+    create_session_if_not_set_then(|_| {
+        let (program, report) = compile(PROGRAM, InliningStrategy::Always);
+
+        // The default strategy inlines every function, except for the one annotated with `@inline(never)`.
+        assert_eq!(kind(&program, "large"), FunctionKind::Inline);
+        assert_eq!(kind(&program, "kept"), FunctionKind::Closure);
+        let main = &program.functions.iter().find(|function| function.name == "main").unwrap().instructions;
+        assert_eq!(calls(main, "kept"), 1);
+        assert!(reported(&report, "main", "kept", false));
+
+        // Finalize blocks cannot call closures, so the call in `store` is inlined.
+        let store = program.functions.iter().find(|function| function.name == "store").unwrap();
+        assert_eq!(calls(&store.finalize.as_ref().unwrap().instructions, "kept"), 0);
+        assert!(reported(&report, "store", "kept", true));
+    });
+}
+
+#[test]
+fn never_annotated_functions_called_from_inlined_functions_are_closures() {
+    create_session_if_not_set_then(|_| {
+        let (program, report) = compile(NESTED_PROGRAM, InliningStrategy::Always);
+
+        // The calls to `kept` from `wrapper` remain calls once `wrapper` is inlined into the body of `main`.
+        assert_eq!(kind(&program, "kept"), FunctionKind::Closure);
+        let main = &program.functions.iter().find(|function| function.name == "main").unwrap().instructions;
+        assert_eq!(calls(main, "kept"), 2);
+        assert!(reported(&report, "main", "wrapper", true));
+
+        // They are inlined once `wrapper` is inlined into a finalize block.
+        let store = program.functions.iter().find(|function| function.name == "store").unwrap();
+        assert_eq!(calls(&store.finalize.as_ref().unwrap().instructions, "kept"), 0);
+        assert!(reported(&report, "store", "kept", true));
+
+        // Closures cannot take records, so `amount` is inlined despite its annotation.
+        assert_eq!(kind(&program, "amount"), FunctionKind::Inline);
+        assert_eq!(calls(main, "amount"), 0);
+    });
+}
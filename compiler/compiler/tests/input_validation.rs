@@ -0,0 +1,284 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::NodeBuilder;
+use leo_compiler::{
+    input_ast_from_values,
+    BuildOptions,
+    Compiler,
+    CompilerOptions,
+    InliningStrategy,
+    InputAst,
+    InputValidator,
+    Pass,
+    SymbolTable,
+    VirtualResolver,
+};
+use leo_errors::{emitter::Handler, InputError};
+use leo_span::{
+    source_map::FileName,
+    symbol::{create_session_if_not_set_then, with_session_globals},
+};
+
+use indexmap::IndexMap;
+use std::{path::PathBuf, rc::Rc};
+
+const PROGRAM: &str = "program token.aleo {
+    struct Metadata {
+        supply: u64,
+        decimals: u8,
+    }
+
+    record Token {
+        owner: address,
+        amount: u64,
+        public tag: field,
+    }
+
+    transition mint(receiver: address, public amount: u64) -> Token {
+        return Token { owner: receiver, amount, tag: 0field };
+    }
+
+    transition burn(token: Token, metadata: Metadata) -> u64 {
+        return token.amount + metadata.supply;
+    }
+
+    transition batch(batches: [[Metadata; 2]; 1]) -> u64 {
+        return batches[0u32][1u32].supply;
+    }
+
+    function helper(x: u64) -> u64 {
+        return x;
+    }
+}";
+
+const ADDRESS: &str = "aleo1qnr4dkkvkgfqph0vzc3y6z2eu975wnpz2925ntjccd5cfqxtyu8s7pyjh9";
+
+/// Runs the compiler stages on `PROGRAM`, returning its symbol table.
+fn symbol_table(handler: &Handler) -> SymbolTable {
+    let options = CompilerOptions {
+        build: BuildOptions {
+            dce_enabled: true,
+            cse_enabled: false,
+            finalize_branching: false,
+            optimization_level: 0,
+            inlining_strategy: InliningStrategy::Always,
+            max_unrolled_iterations: None,
+            source_comments: false,
+        },
+        output: Default::default(),
+    };
+    let mut compiler = Compiler::new(
+        "token".to_string(),
+        "aleo".to_string(),
+        handler,
+        PathBuf::from("token.leo"),
+        std::env::temp_dir(),
+        Some(options),
+        Rc::new(VirtualResolver::default()),
+    );
+    compiler.parse_program_from_string(PROGRAM, FileName::Custom("token".into())).unwrap();
+    compiler.compiler_stages().unwrap().0
+}
+
+/// Parses the input file `input`.
+fn parse_input(handler: &Handler, input: &str) -> InputAst {
+    let input = input.replace("{address}", ADDRESS);
+    let input_sf = with_session_globals(|s| s.source_map.new_source(&input, FileName::Custom("token.in".into())));
+    leo_parser::parse_input(handler, &NodeBuilder::default(), &input_sf.src, input_sf.start_pos).unwrap()
+}
+
+/// Validates the input file `input` against the transitions of `PROGRAM`, returning the errors that it reports.
+fn validate(input: &str) -> Vec<String> {
+    let (handler, buffer) = Handler::new_with_buf();
+    let symbol_table = symbol_table(&handler);
+    let input_ast = parse_input(&handler, input);
+    let result = InputValidator::do_pass((&input_ast, &symbol_table, &handler));
+    let errors: Vec<String> = buffer.extract_errs().into_inner().into_iter().map(|error| error.to_string()).collect();
+    assert_eq!(result.is_ok(), errors.is_empty());
+    errors
+}
+
+#[test]
+fn accepts_inputs_that_match_the_transitions() {
+    create_session_if_not_set_then(|_| {
+        let errors = validate(
+            "[mint]
+            receiver: address = {address};
+            public amount: u64 = 5u64;
+
+            [burn]
+            token: Token = Token { owner: {address}, amount: 5u64, tag: 1field, _nonce: 0group };
+            metadata: Metadata = Metadata { supply: 10u64, decimals: 6u8 };",
+        );
+        assert!(errors.is_empty(), "{errors:?}");
+    });
+}
+
+#[test]
+fn rejects_sections_of_unknown_transitions() {
+    create_session_if_not_set_then(|_| {
+        let errors = validate("[helper]\nx: u64 = 1u64;");
+        assert_eq!(errors.len(), 1);
+        assert!(
+            errors[0].contains("unexpected section: expected 'mint', 'burn', 'batch' -- got 'helper'"),
+            "{errors:?}"
+        );
+    });
+}
+
+#[test]
+fn rejects_inputs_with_the_wrong_count_type_or_mode() {
+    create_session_if_not_set_then(|_| {
+        let errors = validate("[mint]\nreceiver: address = {address};");
+        assert!(errors[0].contains("The function `mint` has 2 input(s), but its section defines 1 value(s)."));
+
+        let errors = validate("[mint]\nreceiver: address = {address};\npublic amount: u32 = 5u32;");
+        assert!(errors[0].contains("unexpected type, expected: 'u64', received: 'u32'"), "{errors:?}");
+
+        let errors = validate("[mint]\nreceiver: address = {address};\namount: u64 = 5u64;");
+        assert!(errors[0].contains("The input `amount` is public, but its value in the input file is private."));
+
+        let errors = validate("[mint]\nreceiver: address = {address};\npublic amount: u64 = 5u32;");
+        assert!(errors[0].contains("unexpected type, expected: 'u64', received: 'u32'"), "{errors:?}");
+    });
+}
+
+#[test]
+fn rejects_records_with_missing_or_unknown_fields() {
+    create_session_if_not_set_then(|_| {
+        let errors = validate(
+            "[burn]
+            token: Token = Token { owner: {address}, amounts: 5u64, tag: 1field };
+            metadata: Metadata = Metadata { supply: 10u64, decimals: 6u8 };",
+        );
+        assert_eq!(errors.len(), 3, "{errors:?}");
+        assert!(errors[0].contains("The value of `Token` does not initialize its member `amount`."));
+        assert!(errors[1].contains("The value of `Token` does not initialize its member `_nonce`."));
+        assert!(errors[2].contains("`Token` does not have a member `amounts`."));
+    });
+}
+
+#[test]
+fn program_inputs_reports_unknown_structs() {
+    create_session_if_not_set_then(|_| {
+        let handler = Handler::default();
+        let input_ast =
+            parse_input(&handler, "[burn]\nmetadata: Metadata = Metadata { supply: 10u64, decimals: 6u8 };");
+        let error = input_ast.program_inputs("burn", IndexMap::new()).unwrap_err();
+        assert!(error.to_string().contains("A struct or record declaration does not exist for `Metadata`."));
+    });
+}
+
+/// Reads the values of a JSON or TOML input file, returning the inputs of `function` for execution with `leo run`.
+fn values_to_program_inputs(values: serde_json::Value, function: &str) -> Result<Vec<String>, String> {
+    let handler = Handler::default();
+    let symbol_table = symbol_table(&handler);
+    let input_ast =
+        input_ast_from_values(&values, &symbol_table, &NodeBuilder::default()).map_err(|error| error.to_string())?;
+    input_ast.program_inputs(function, symbol_table.structs).map_err(|error| error.to_string())
+}
+
+#[test]
+fn reads_values_as_program_inputs() {
+    create_session_if_not_set_then(|_| {
+        let values = serde_json::json!({
+            "mint": { "amount": "5u64", "receiver": ADDRESS },
+            "burn": {
+                "token": { "amount": 5, "tag": "1", "owner": ADDRESS, "_nonce": "0group" },
+                "metadata": { "supply": 100, "decimals": "6u8" },
+            },
+            "batch": { "batches": [[{ "supply": 1, "decimals": 2 }, { "supply": 3, "decimals": 4 }]] },
+        });
+
+        // Inputs are ordered by the signature, and the fields of records have their declared visibility.
+        assert_eq!(values_to_program_inputs(values.clone(), "mint").unwrap(), [ADDRESS, "5u64"]);
+        assert_eq!(values_to_program_inputs(values.clone(), "burn").unwrap(), [
+            format!("{{ owner: {ADDRESS}.private, amount: 5u64.private, tag: 1field.public, _nonce: 0group.public }}"),
+            "{ supply: 100u64, decimals: 6u8 }".to_string(),
+        ]);
+        assert_eq!(values_to_program_inputs(values, "batch").unwrap(), [
+            "[[{ supply: 1u64, decimals: 2u8 }, { supply: 3u64, decimals: 4u8 }]]"
+        ]);
+    });
+}
+
+#[test]
+fn prints_input_files_as_aleo_values() {
+    create_session_if_not_set_then(|_| {
+        let handler = Handler::default();
+        let symbol_table = symbol_table(&handler);
+        let input_ast = parse_input(
+            &handler,
+            "[burn]
+            token: Token = Token { _nonce: 0group, tag: 1field, amount: 5u64, owner: {address} };
+            metadata: Metadata = Metadata { supply: 10u64, decimals: 6u8 };",
+        );
+        assert_eq!(input_ast.program_inputs("burn", symbol_table.structs).unwrap(), [
+            format!("{{ owner: {ADDRESS}.private, amount: 5u64.private, tag: 1field.public, _nonce: 0group.public }}"),
+            "{ supply: 10u64, decimals: 6u8 }".to_string(),
+        ]);
+    });
+}
+
+#[test]
+fn rejects_invalid_values() {
+    create_session_if_not_set_then(|_| {
+        let error = |values: serde_json::Value| values_to_program_inputs(values, "burn").unwrap_err();
+
+        let token = serde_json::json!({ "amount": 5, "tag": 1, "owner": ADDRESS, "_nonce": 0 });
+        let metadata = serde_json::json!({ "supply": 100, "decimals": 256 });
+        assert!(error(serde_json::json!({ "burn": { "token": token, "metadata": metadata } }))
+            .contains("The value `256` of the input `burn.metadata.decimals` is not a valid `u8`."));
+
+        let token = serde_json::json!({ "amount": 5, "tag": 1, "owner": ADDRESS });
+        let metadata = serde_json::json!({ "supply": 100, "decimals": 6 });
+        assert!(error(serde_json::json!({ "burn": { "token": token, "metadata": metadata } }))
+            .contains("The input `burn.token._nonce` of type `group` has no value in the input file."));
+
+        assert!(
+            error(serde_json::json!({ "helper": { "x": 1 } })).contains("`helper` is not a transition of the program.")
+        );
+    });
+}
+
+#[test]
+fn validates_values_like_input_files() {
+    create_session_if_not_set_then(|_| {
+        let (handler, buffer) = Handler::new_with_buf();
+        let symbol_table = symbol_table(&handler);
+        let values = serde_json::json!({ "batch": { "batches": [[{ "supply": 1, "decimals": 2 }]] } });
+        let input_ast = input_ast_from_values(&values, &symbol_table, &NodeBuilder::default()).unwrap();
+        assert!(InputValidator::do_pass((&input_ast, &symbol_table, &handler)).is_err());
+        let errors = buffer.extract_errs().into_inner();
+        assert_eq!(errors.len(), 1);
+        assert!(
+            errors[0].to_string().contains("Expected an array of 2 element(s), but the value has 1."),
+            "{errors:?}"
+        );
+    });
+}
+
+#[test]
+fn only_errors_emitted_by_the_pass_fail_it() {
+    create_session_if_not_set_then(|_| {
+        let handler = Handler::default();
+        let symbol_table = symbol_table(&handler);
+        handler.emit_err(InputError::unknown_transition("helper"));
+        let input_ast = parse_input(&handler, "[mint]\nreceiver: address = {address};\npublic amount: u64 = 5u64;");
+        assert!(InputValidator::do_pass((&input_ast, &symbol_table, &handler)).is_ok());
+    });
+}
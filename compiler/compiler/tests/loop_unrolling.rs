@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::{BuildOptions, Compiler, CompilerOptions, InliningStrategy, UnrolledLoop, VirtualResolver};
+use leo_errors::emitter::{BufferEmitter, Handler};
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+use std::{path::PathBuf, rc::Rc};
+
+const PROGRAM: &str = "program test.aleo {
+    transition main(x: u32) -> u32 {
+        let sum: u32 = x;
+        for i: u32 in 0u32..4u32 {
+            sum += i;
+            for j: u32 in 0u32..3u32 {
+                sum += j;
+            }
+        }
+        return sum;
+    }
+}";
+
+/// Runs the compiler stages on `PROGRAM` with the given budget, returning the unrolled loops or the emitted errors.
+fn unroll(max_unrolled_iterations: Option<u64>) -> Result<Vec<UnrolledLoop>, String> {
+    let buf = BufferEmitter::new();
+    let handler = Handler::new(Box::new(buf.clone()));
+    let options = CompilerOptions {
+        build: BuildOptions {
+            dce_enabled: true,
+            cse_enabled: false,
+            finalize_branching: false,
+            optimization_level: 0,
+            inlining_strategy: InliningStrategy::Always,
+            max_unrolled_iterations,
+            source_comments: false,
+        },
+        output: Default::default(),
+    };
+    let mut compiler = Compiler::new(
+        "test".to_string(),
+        "aleo".to_string(),
+        &handler,
+        PathBuf::from("test.leo"),
+        std::env::temp_dir(),
+        Some(options),
+        Rc::new(VirtualResolver::default()),
+    );
+    let result = compiler.parse_program_from_string(PROGRAM, FileName::Custom("test".into()));
+    match result.and_then(|_| compiler.compiler_stages()) {
+        Ok(_) => Ok(compiler.unroll_report),
+        Err(_) => Err(buf.extract_errs().to_string()),
+    }
+}
+
+/// Returns the unrolled loop whose variable is `variable`.
+fn find<'a>(report: &'a [UnrolledLoop], variable: &str) -> &'a UnrolledLoop {
+    report.iter().find(|unrolled_loop| unrolled_loop.variable.to_string() == variable).unwrap()
+}
+
+#[test]
+fn unroll_report_counts_iterations_and_statements() {
+    create_session_if_not_set_then(|_| {
+        let report = unroll(None).unwrap();
+        assert_eq!(report.len(), 2);
+
+        // The inner loop is unrolled once for each of the four iterations of the outer loop.
+        let inner = find(&report, "j");
+        assert_eq!((inner.trip_count, inner.statements), (12, 12));
+        let outer = find(&report, "i");
+        assert_eq!((outer.trip_count, outer.statements), (4, 16));
+    });
+}
+
+#[test]
+fn unrolling_stops_at_the_budget() {
+    create_session_if_not_set_then(|_| {
+        // The loops unroll 16 iterations in total, which are the iterations in the report.
+        let report = unroll(Some(16)).unwrap();
+        assert_eq!(report.iter().map(|unrolled_loop| unrolled_loop.trip_count).sum::<u128>(), 16);
+
+        // The third time that the inner loop is unrolled exceeds the budget.
+        let error = unroll(Some(10)).unwrap_err();
+        assert!(error.contains("Unrolling this loop exceeds the budget of 10 unrolled iterations."), "{error}");
+        assert!(error.contains("for j: u32 in 0u32..3u32"), "{error}");
+    });
+}
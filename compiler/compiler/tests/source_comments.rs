@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::{BuildOptions, BytecodeSourceMap, Compiler, CompilerOptions, InliningStrategy, VirtualResolver};
+use leo_errors::emitter::Handler;
+use leo_span::symbol::create_session_if_not_set_then;
+
+use snarkvm::prelude::{Program, Testnet3};
+use std::{rc::Rc, str::FromStr};
+
+const PROGRAM: &str = "program test.aleo {
+    mapping totals: u32 => u32;
+
+    transition main(x: u32) -> u32 {
+        let y: u32 = x + 1u32;
+        if x > 2u32 {
+            y = y * 2u32;
+        }
+        return y then finalize(y);
+    }
+
+    finalize main(y: u32) {
+        let total: u32 = Mapping::get_or_use(totals, 0u32, 0u32);
+        Mapping::set(totals, 0u32, total + y);
+    }
+}";
+
+/// Compiles `PROGRAM`, returning its bytecode and source map.
+fn compile(source_comments: bool) -> (String, BytecodeSourceMap) {
+    let directory = tempfile::tempdir().unwrap();
+    let main_file_path = directory.path().join("main.leo");
+    std::fs::write(&main_file_path, PROGRAM).unwrap();
+
+    let handler = Handler::default();
+    let options = CompilerOptions {
+        build: BuildOptions {
+            dce_enabled: true,
+            cse_enabled: false,
+            finalize_branching: false,
+            optimization_level: 1,
+            inlining_strategy: InliningStrategy::Always,
+            max_unrolled_iterations: None,
+            source_comments,
+        },
+        output: Default::default(),
+    };
+    let mut compiler = Compiler::new(
+        "test".to_string(),
+        "aleo".to_string(),
+        &handler,
+        main_file_path,
+        directory.path().to_path_buf(),
+        Some(options),
+        Rc::new(VirtualResolver::default()),
+    );
+    let (_, bytecode) = compiler.compile().unwrap();
+    (bytecode, compiler.source_map)
+}
+
+/// Returns the index of the first line of `lines` that starts with `prefix`, ignoring indentation.
+fn position(lines: &[&str], prefix: &str) -> usize {
+    lines
+        .iter()
+        .position(|line| line.trim().starts_with(prefix))
+        .unwrap_or_else(|| panic!("No line starts with `{prefix}`"))
+}
+
+/// Returns the last comment that precedes the line at `index`, ignoring indentation.
+fn comment_before<'a>(lines: &[&'a str], index: usize) -> &'a str {
+    lines[..index].iter().rev().map(|line| line.trim()).find(|line| line.starts_with("//")).unwrap()
+}
+
+#[test]
+fn source_comments_are_disabled_by_default() {
+    create_session_if_not_set_then(|_| {
+        let (bytecode, _) = compile(false);
+        assert!(!bytecode.contains("//"), "{bytecode}");
+    });
+}
+
+#[test]
+fn source_comments_are_parsed_by_snarkvm() {
+    create_session_if_not_set_then(|_| {
+        let (annotated, _) = compile(true);
+        let (bytecode, _) = compile(false);
+        assert_ne!(annotated, bytecode);
+        // The comments are skipped, so the annotated program is the same program.
+        let annotated = Program::<Testnet3>::from_str(&annotated).unwrap();
+        assert!(annotated == Program::<Testnet3>::from_str(&bytecode).unwrap());
+    });
+}
+
+#[test]
+fn source_comments_precede_functions_and_statements() {
+    create_session_if_not_set_then(|_| {
+        let (bytecode, _) = compile(true);
+        let lines: Vec<&str> = bytecode.lines().collect();
+
+        // The header of each function and finalize block is preceded by its Leo source.
+        let function = position(&lines, "function main:");
+        assert_eq!(lines[function - 1], "// transition main(x: u32) -> u32 {");
+        let finalize = position(&lines, "finalize main:");
+        assert_eq!(lines[finalize - 1], "// finalize main(y: u32) {");
+
+        // The instructions of each line of Leo are preceded by that line.
+        assert_eq!(comment_before(&lines, position(&lines, "add ")), "// let y: u32 = x + 1u32;");
+        assert_eq!(comment_before(&lines, position(&lines, "set ")), "// Mapping::set(totals, 0u32, total + y);");
+    });
+}
+
+#[test]
+fn source_comments_name_the_registers() {
+    create_session_if_not_set_then(|_| {
+        let (bytecode, _) = compile(true);
+        let lines: Vec<&str> = bytecode.lines().collect();
+
+        // The inputs keep their Leo names, and the names introduced by the compiler are traced back to them.
+        let function = position(&lines, "function main:");
+        assert_eq!(lines[function + 1].trim(), "// r0: x");
+        let add = lines[position(&lines, "add ")].trim();
+        let destination = add.trim_end_matches(';').rsplit(' ').next().unwrap();
+        let name =
+            lines[function..].iter().find(|line| line.trim().starts_with(&format!("// {destination}: "))).unwrap();
+        assert!(name.trim().starts_with(&format!("// {destination}: y$")) && name.ends_with(" (y)"), "{name}");
+    });
+}
+
+#[test]
+fn source_map_skips_source_comments() {
+    create_session_if_not_set_then(|_| {
+        let (bytecode, source_map) = compile(true);
+        let lines: Vec<&str> = bytecode.lines().collect();
+        assert!(source_map.functions.iter().any(|function| function.finalize));
+        for mapping in source_map.functions.iter().flat_map(|function| function.instructions.iter()) {
+            assert_eq!(lines[mapping.line - 1].trim(), mapping.instruction);
+        }
+    });
+}
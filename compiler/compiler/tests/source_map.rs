@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::{BuildOptions, BytecodeSourceMap, Compiler, CompilerOptions, InliningStrategy, VirtualResolver};
+use leo_errors::emitter::Handler;
+use leo_span::symbol::create_session_if_not_set_then;
+
+use std::rc::Rc;
+
+const PROGRAM: &str = "program test.aleo {
+    transition main(x: u32) -> u32 {
+        let y: u32 = x + 1u32;
+        let z: u32 = y * 2u32;
+        return z then finalize(z);
+    }
+
+    finalize main(z: u32) {
+        assert_eq(z, 2u32);
+    }
+}";
+
+/// Compiles `PROGRAM`, returning its bytecode and source map.
+fn compile() -> (String, BytecodeSourceMap) {
+    let directory = tempfile::tempdir().unwrap();
+    let main_file_path = directory.path().join("main.leo");
+    std::fs::write(&main_file_path, PROGRAM).unwrap();
+
+    let handler = Handler::default();
+    let options = CompilerOptions {
+        build: BuildOptions {
+            dce_enabled: true,
+            cse_enabled: false,
+            finalize_branching: false,
+            optimization_level: 1,
+            inlining_strategy: InliningStrategy::Always,
+            max_unrolled_iterations: None,
+            source_comments: false,
+        },
+        output: Default::default(),
+    };
+    let mut compiler = Compiler::new(
+        "test".to_string(),
+        "aleo".to_string(),
+        &handler,
+        main_file_path,
+        directory.path().to_path_buf(),
+        Some(options),
+        Rc::new(VirtualResolver::default()),
+    );
+    let (_, bytecode) = compiler.compile().unwrap();
+    (bytecode, compiler.source_map)
+}
+
+#[test]
+fn source_map_links_instructions_to_leo_lines() {
+    create_session_if_not_set_then(|_| {
+        let (bytecode, source_map) = compile();
+        assert_eq!(source_map.program, "test.aleo");
+        let main = source_map.functions.iter().find(|function| function.name == "main" && !function.finalize).unwrap();
+
+        // Each mapped line of the bytecode is the instruction that the mapping records.
+        let lines: Vec<&str> = bytecode.lines().collect();
+        for mapping in main.instructions.iter() {
+            assert_eq!(lines[mapping.line - 1].trim(), mapping.instruction);
+        }
+
+        let input = &main.instructions[0];
+        assert_eq!((input.location.line, input.location.source.as_str()), (2, "transition main(x: u32) -> u32 {"));
+        let add = main.instructions.iter().find(|mapping| mapping.instruction.starts_with("add ")).unwrap();
+        assert_eq!((add.location.line, add.location.source.as_str()), (3, "let y: u32 = x + 1u32;"));
+        let mul = main.instructions.iter().find(|mapping| mapping.instruction.starts_with("mul ")).unwrap();
+        assert_eq!((mul.location.line, mul.location.source.as_str()), (4, "let z: u32 = y * 2u32;"));
+        assert!(mul.location.file.ends_with("main.leo"), "{}", mul.location.file);
+    });
+}
+
+#[test]
+fn source_map_explains_errors() {
+    create_session_if_not_set_then(|_| {
+        let (_, source_map) = compile();
+        let main = source_map.functions.iter().find(|function| function.name == "main" && !function.finalize).unwrap();
+        let mul = main.instructions.iter().find(|mapping| mapping.instruction.starts_with("mul ")).unwrap();
+
+        // Errors that quote an instruction are explained by the source of that instruction.
+        let error = format!("Failed to evaluate instruction ({})", mul.instruction);
+        let explanation = source_map.explain("main", &error).unwrap();
+        assert!(explanation.contains("let z: u32 = y * 2u32;"), "{explanation}");
+
+        // Otherwise, errors are explained by the source of the registers that they mention.
+        let explanation = source_map.explain("main", "Register 'r0' is not a u64").unwrap();
+        assert!(explanation.contains("`r0` is defined at"), "{explanation}");
+        assert!(explanation.contains("transition main(x: u32) -> u32 {"), "{explanation}");
+        // The finalize block also defines `r0`, but it is not run along with the function.
+        assert!(!explanation.contains("finalize main(z: u32) {"), "{explanation}");
+
+        assert_eq!(source_map.explain("main", "Failed to parse the inputs"), None);
+        assert_eq!(source_map.explain("other", "Register 'r0' is not a u64"), None);
+    });
+}
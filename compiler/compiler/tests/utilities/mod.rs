@@ -14,10 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-mod check_unique_node_ids;
-use check_unique_node_ids::*;
-
-use leo_compiler::{BuildOptions, Compiler, CompilerOptions};
+use leo_compiler::{
+    BuildOptions,
+    Compiler,
+    CompilerOptions,
+    FileSystemResolver,
+    ImportResolver,
+    InliningStrategy,
+    VirtualResolver,
+};
 use leo_errors::{
     emitter::{Buffer, Emitter, Handler},
     LeoError,
@@ -29,7 +34,8 @@ use leo_test_framework::{test::TestConfig, Test};
 
 use snarkvm::prelude::*;
 
-use leo_ast::ProgramVisitor;
+use leo_ast::Ast;
+use leo_passes::{NodeIdValidator, Pass};
 use snarkvm::{file::Manifest, package::Package};
 use std::{
     cell::RefCell,
@@ -96,11 +102,25 @@ pub fn get_build_options(test_config: &TestConfig) -> Vec<BuildOptions> {
                             .expect("Expected key `dce_enabled`")
                             .as_bool()
                             .expect("Expected value to be a boolean."),
+                        cse_enabled: false,
+                        finalize_branching: false,
+                        optimization_level: 0,
+                        inlining_strategy: InliningStrategy::Always,
+                        max_unrolled_iterations: None,
+                        source_comments: false,
                     }
                 })
                 .collect()
         }
-        None => vec![BuildOptions { dce_enabled: true }],
+        None => vec![BuildOptions {
+            dce_enabled: true,
+            cse_enabled: false,
+            finalize_branching: false,
+            optimization_level: 0,
+            inlining_strategy: InliningStrategy::Always,
+            max_unrolled_iterations: None,
+            source_comments: false,
+        }],
     }
 }
 
@@ -138,11 +158,20 @@ pub fn new_compiler(
     handler: &Handler,
     main_file_path: PathBuf,
     compiler_options: Option<CompilerOptions>,
+    import_resolver: Rc<dyn ImportResolver>,
 ) -> Compiler<'_> {
     let output_dir = PathBuf::from("/tmp/output/");
     fs::create_dir_all(output_dir.clone()).unwrap();
 
-    Compiler::new(String::from("test"), String::from("aleo"), handler, main_file_path, output_dir, compiler_options)
+    Compiler::new(
+        String::from("test"),
+        String::from("aleo"),
+        handler,
+        main_file_path,
+        output_dir,
+        compiler_options,
+        import_resolver,
+    )
 }
 
 pub fn parse_program<'a>(
@@ -151,15 +180,28 @@ pub fn parse_program<'a>(
     cwd: Option<PathBuf>,
     compiler_options: Option<CompilerOptions>,
 ) -> Result<Compiler<'a>, LeoError> {
-    let mut compiler = new_compiler(handler, cwd.clone().unwrap_or_else(|| "compiler-test".into()), compiler_options);
+    // Imports are resolved from the `imports` directory of the working directory, if one is given.
+    let import_resolver: Rc<dyn ImportResolver> = match &cwd {
+        Some(cwd) => Rc::new(FileSystemResolver::for_package(cwd)),
+        None => Rc::new(VirtualResolver::default()),
+    };
+    let mut compiler =
+        new_compiler(handler, cwd.clone().unwrap_or_else(|| "compiler-test".into()), compiler_options, import_resolver);
     let name = cwd.map_or_else(|| FileName::Custom("compiler-test".into()), FileName::Real);
     compiler.parse_program_from_string(program_string, name)?;
 
-    CheckUniqueNodeIds::new().visit_program(&compiler.ast.ast);
+    check_unique_node_ids(&compiler.ast);
 
     Ok(compiler)
 }
 
+/// Panics if two nodes of the AST share a `NodeID`.
+pub fn check_unique_node_ids(ast: &Ast) {
+    if let Err(err) = NodeIdValidator::do_pass((ast, &Handler::default())) {
+        panic!("{err}");
+    }
+}
+
 pub fn hash_content(content: &str) -> String {
     use sha2::{Digest, Sha256};
     let mut hasher = Sha256::new();
@@ -224,11 +266,11 @@ pub fn temp_dir() -> PathBuf {
 pub fn compile_and_process<'a>(parsed: &'a mut Compiler<'a>) -> Result<String, LeoError> {
     let st = parsed.symbol_table_pass()?;
 
-    CheckUniqueNodeIds::new().visit_program(&parsed.ast.ast);
+    check_unique_node_ids(&parsed.ast);
 
     let (st, struct_graph, call_graph) = parsed.type_checker_pass(st)?;
 
-    CheckUniqueNodeIds::new().visit_program(&parsed.ast.ast);
+    check_unique_node_ids(&parsed.ast);
 
     let st = parsed.loop_unrolling_pass(st)?;
 
@@ -240,12 +282,14 @@ pub fn compile_and_process<'a>(parsed: &'a mut Compiler<'a>) -> Result<String, L
 
     parsed.function_inlining_pass(&call_graph)?;
 
+    parsed.common_subexpression_elimination_pass()?;
+
     parsed.dead_code_elimination_pass()?;
 
     // Compile Leo program to bytecode.
-    let bytecode = parsed.code_generation_pass(&st, &struct_graph, &call_graph)?;
+    let program = parsed.code_generation_pass(&st, &struct_graph, &call_graph)?;
 
-    Ok(bytecode)
+    Ok(program.to_string())
 }
 
 /// Returns the private key from the .env file specified in the directory.
@@ -234,12 +234,19 @@ impl ParserContext<'_> {
     /// Otherwise, tries to parse the next token using [`parse_unary_expression`].
     fn parse_cast_expression(&mut self) -> Result<Expression> {
         let mut expr = self.parse_unary_expression()?;
-        if self.eat(&Token::As) {
+        if self.eat_any(&[Token::As, Token::AsLossy, Token::AsSaturating]) {
+            let kind = match self.prev_token.token {
+                Token::As => CastKind::Checked,
+                Token::AsLossy => CastKind::Lossy,
+                Token::AsSaturating => CastKind::Saturating,
+                _ => unreachable!("parse_cast_expression_ shouldn't produce this"),
+            };
             let (type_, end_span) = self.parse_primitive_type()?;
             let span = expr.span() + end_span;
             expr = Expression::Cast(CastExpression {
                 expression: Box::new(expr),
                 type_,
+                kind,
                 span,
                 id: self.node_builder.next_id(),
             });
@@ -15,11 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use crate::parse_ast;
-use leo_errors::{CompilerError, ParserError, Result};
-use leo_span::{source_map::FileName, symbol::with_session_globals};
-
-use std::fs;
+use leo_errors::{ParserError, Result};
 
 impl ParserContext<'_> {
     /// Returns a [`Program`] AST if all tokens can be consumed and represent a valid Leo program.
@@ -27,9 +23,6 @@ impl ParserContext<'_> {
         let mut imports = IndexMap::new();
         let mut program_scopes = IndexMap::new();
 
-        // TODO: Remove restrictions on multiple program scopes
-        let mut parsed_program_scope = false;
-
         while self.has_next() {
             match &self.token.token {
                 Token::Import => {
@@ -37,22 +30,20 @@ impl ParserContext<'_> {
                     imports.insert(id, import);
                 }
                 Token::Program => {
-                    match parsed_program_scope {
-                        // Only one program scope is allowed per file.
-                        true => return Err(ParserError::only_one_program_scope_is_allowed(self.token.span).into()),
-                        false => {
-                            parsed_program_scope = true;
-                            let program_scope = self.parse_program_scope()?;
-                            program_scopes.insert(program_scope.program_id.name.name, program_scope);
-                        }
+                    let program_scope = self.parse_program_scope()?;
+                    let name = program_scope.program_id.name;
+                    // Each program scope in a file must have a unique name.
+                    if program_scopes.contains_key(&name.name) {
+                        return Err(ParserError::duplicate_program_scope(name, name.span).into());
                     }
+                    program_scopes.insert(name.name, program_scope);
                 }
                 _ => return Err(Self::unexpected_item(&self.token, &[Token::Import, Token::Program]).into()),
             }
         }
 
         // Requires that at least one program scope is present.
-        if !parsed_program_scope {
+        if program_scopes.is_empty() {
             return Err(ParserError::missing_program_scope(self.token.span).into());
         }
 
@@ -67,8 +58,8 @@ impl ParserContext<'_> {
         )
     }
 
-    // TODO: remove import resolution from parser.
-    /// Parses an import statement `import foo.leo;`.
+    /// Parses an import statement `import foo.leo;` or `import foo.aleo;`.
+    /// Note that the imported program is resolved by the compiler, so an empty program is returned in its place.
     pub(super) fn parse_import(&mut self) -> Result<(Symbol, (Program, Span))> {
         // Parse `import`.
         let start = self.expect(&Token::Import)?;
@@ -76,42 +67,16 @@ impl ParserContext<'_> {
         // Parse `foo`.
         let import_name = self.expect_identifier()?;
 
-        // Parse `.leo`.
+        // Parse `.leo` or `.aleo`.
         self.expect(&Token::Dot)?;
-        if !self.eat(&Token::Leo) {
-            // Throw error for non-leo files.
+        if !self.eat(&Token::Leo) && !self.eat(&Token::Identifier(sym::aleo)) {
+            // Throw error for imports of other files.
             return Err(ParserError::leo_imports_only(self.token.span).into());
         }
 
         let end = self.expect(&Token::Semicolon)?;
 
-        // Tokenize and parse import file.
-        // Todo: move this to a different module.
-        let mut import_file_path =
-            std::env::current_dir().map_err(|err| CompilerError::cannot_open_cwd(err, self.token.span))?;
-        import_file_path.push("imports");
-        import_file_path.push(format!("{}.leo", import_name.name));
-
-        // Throw an error if the import file doesn't exist.
-        if !import_file_path.exists() {
-            return Err(CompilerError::import_not_found(import_file_path.display(), self.prev_token.span).into());
-        }
-
-        // Read the import file into string.
-        // Todo: protect against cyclic imports.
-        let program_string =
-            fs::read_to_string(&import_file_path).map_err(|e| CompilerError::file_read_error(&import_file_path, e))?;
-
-        // Create import file name.
-        let name: FileName = FileName::Real(import_file_path);
-
-        // Register the source (`program_string`) in the source map.
-        let prg_sf = with_session_globals(|s| s.source_map.new_source(&program_string, name));
-
-        // Use the parser to construct the imported abstract syntax tree (ast).
-        let program_ast = parse_ast(self.handler, self.node_builder, &prg_sf.src, prg_sf.start_pos)?;
-
-        Ok((import_name.name, (program_ast.into_repr(), start + end)))
+        Ok((import_name.name, (Program::default(), start + end)))
     }
 
     /// Parsers a program scope `program foo.aleo { ... }`.
@@ -180,7 +145,7 @@ impl ParserContext<'_> {
         // Parse `}`.
         let end = self.expect(&Token::RightCurly)?;
 
-        Ok(ProgramScope { program_id, consts, functions, structs, mappings, span: start + end })
+        Ok(ProgramScope { program_id, consts, functions, structs, mappings, span: start + end, is_stub: false })
     }
 
     /// Returns a [`Vec<Member>`] AST node if the next tokens represent a struct member.
@@ -386,16 +351,29 @@ impl ParserContext<'_> {
             Token::Program => {
                 Identifier { name: sym::program, span: self.expect(&Token::Program)?, id: self.node_builder.next_id() }
             }
+            Token::Inline => {
+                Identifier { name: sym::inline, span: self.expect(&Token::Inline)?, id: self.node_builder.next_id() }
+            }
             _ => self.expect_identifier()?,
         };
-        let span = start + identifier.span;
+        let mut span = start + identifier.span;
 
         // TODO: Verify that this check is sound.
         // Check that there is no whitespace in between the `@` symbol and identifier.
-        match identifier.span.hi.0 - start.lo.0 > 1 + identifier.name.to_string().len() as u32 {
-            true => Err(ParserError::space_in_annotation(span).into()),
-            false => Ok(Annotation { identifier, span, id: self.node_builder.next_id() }),
+        if identifier.span.hi.0 - start.lo.0 > 1 + identifier.name.to_string().len() as u32 {
+            return Err(ParserError::space_in_annotation(span).into());
         }
+
+        // Parse the arguments of the annotation, e.g. `@inline(never)`.
+        // Note that only the `inline` annotation takes arguments.
+        let mut arguments = Vec::new();
+        if identifier.name == sym::inline && self.peek_is_left_par() {
+            let (list, _, list_span) = self.parse_paren_comma_list(|p| p.expect_identifier().map(Some))?;
+            arguments = list;
+            span = span + list_span;
+        }
+
+        Ok(Annotation { identifier, arguments, span, id: self.node_builder.next_id() })
     }
 
     /// Returns an [`(Identifier, Function)`] AST node if the next tokens represent a function name
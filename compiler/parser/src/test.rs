@@ -16,7 +16,7 @@
 
 use crate::{tokenizer, ParserContext, SpannedToken};
 
-use leo_ast::{NodeBuilder, NodeID, Statement};
+use leo_ast::{Ast, NodeBuilder, NodeID, Program, Statement};
 use leo_errors::{emitter::Handler, LeoError};
 use leo_span::{
     source_map::FileName,
@@ -158,6 +158,24 @@ fn remove_key_from_json(value: &mut serde_json::Value, key: &str) {
     }
 }
 
+/// Removes the spans from AST JSON, including those of the identifiers, which are serialized as JSON strings,
+/// and those of the imports, which are not under a `span` key.
+fn remove_spans(value: serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+    match value {
+        Value::Object(map) if map.len() == 2 && map.contains_key("lo") && map.contains_key("hi") => Value::Null,
+        Value::Object(map) => {
+            Value::Object(map.into_iter().filter(|(key, _)| key != "span").map(|(k, v)| (k, remove_spans(v))).collect())
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(remove_spans).collect()),
+        Value::String(string) => match serde_json::from_str(&string) {
+            Ok(value @ (Value::Object(_) | Value::String(_))) => remove_spans(value),
+            _ => Value::String(string),
+        },
+        value => value,
+    }
+}
+
 // Helper function to normalize AST
 // Redeclaring here because we don't want to make this public
 fn normalize_json_value(value: serde_json::Value) -> serde_json::Value {
@@ -234,3 +252,56 @@ impl Runner for TestRunner {
 pub fn parser_tests() {
     leo_test_framework::run_tests(&TestRunner, "parser");
 }
+
+#[test]
+pub fn printed_programs_parse_to_the_same_program() {
+    let source = r#"
+import token.leo;
+program test.aleo {
+    const SCALE: u64 = 10u64;
+    struct Point {
+        x: u64,
+        y: u64,
+    }
+    record Token {
+        owner: address,
+        amount: u64,
+    }
+    mapping balances: address => u64;
+
+    @program
+    transition transfer(t: Token, to: address, public amount: u64) -> (Token, Token) {
+        let p: Point = Point { x: amount, y: SCALE };
+        let (a, b): (u64, bool) = (p.x.add_wrapped(p.y) * 2u64, !(amount > 0u64));
+        for i: u8 in 0u8..4u8 {
+            if (b && i == 1u8) {
+                assert_eq(-(1i8 + 1i8), (2i8).neg());
+            } else {
+                assert(a as u8 <= 255u8 ? true : false);
+                assert_eq((a + 1u64) as u8, -(i as i8) as u8);
+            }
+        }
+        return (Token { owner: to, amount }, Token { owner: t.owner, amount: t.amount - amount });
+    }
+
+    transition deposit(public amount: u64) {
+        return then finalize(self.caller, amount);
+    } finalize deposit(public owner: address, public amount: u64) {
+        let balance: u64 = Mapping::get_or_use(balances, owner, 0u64);
+        Mapping::set(balances, owner, balance + amount);
+    }
+}
+"#;
+    create_session_if_not_set_then(|s| {
+        let parse = |source: &str| {
+            let sf = s.source_map.new_source(source, FileName::Custom("test".into()));
+            crate::parse(&Handler::default(), &NodeBuilder::default(), &sf.src, sf.start_pos)
+                .unwrap_or_else(|e| panic!("failed to parse:\n{source}\n{e}"))
+        };
+        // Compares the programs without their spans, which differ between the source and the printed program.
+        let without_spans = |program: Program| remove_spans(Ast::new(program).to_json_value().unwrap());
+        let program = parse(source);
+        let printed = program.to_string();
+        assert_eq!(without_spans(parse(&printed)), without_spans(program), "{printed}");
+    });
+}
@@ -380,6 +380,8 @@ impl Token {
                     x if x.starts_with("aleo1") => Token::AddressLit(identifier),
                     "address" => Token::Address,
                     "as" => Token::As,
+                    "as_lossy" => Token::AsLossy,
+                    "as_saturating" => Token::AsSaturating,
                     "assert" => Token::Assert,
                     "assert_eq" => Token::AssertEq,
                     "assert_neq" => Token::AssertNeq,
@@ -110,6 +110,8 @@ pub enum Token {
 
     // Regular Keywords
     As,
+    AsLossy,
+    AsSaturating,
     Assert,
     AssertEq,
     AssertNeq,
@@ -150,6 +152,8 @@ pub enum Token {
 pub const KEYWORD_TOKENS: &[Token] = &[
     Token::Address,
     Token::As,
+    Token::AsLossy,
+    Token::AsSaturating,
     Token::Assert,
     Token::AssertEq,
     Token::AssertNeq,
@@ -206,6 +210,8 @@ impl Token {
         Some(match self {
             Token::Address => sym::address,
             Token::As => sym::As,
+            Token::AsLossy => sym::as_lossy,
+            Token::AsSaturating => sym::as_saturating,
             Token::Assert => sym::assert,
             Token::AssertEq => sym::assert_eq,
             Token::AssertNeq => sym::assert_neq,
@@ -342,6 +348,8 @@ impl fmt::Display for Token {
             Record => write!(f, "record"),
 
             As => write!(f, "as"),
+            AsLossy => write!(f, "as_lossy"),
+            AsSaturating => write!(f, "as_saturating"),
             Assert => write!(f, "assert"),
             AssertEq => write!(f, "assert_eq"),
             AssertNeq => write!(f, "assert_neq"),
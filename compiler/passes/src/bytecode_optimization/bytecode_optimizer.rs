@@ -0,0 +1,183 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::aleo_ir::{Input, Instruction, Literal, Opcode, Operand, Output, Register, SpannedInstruction, Type};
+
+use indexmap::{IndexMap, IndexSet};
+
+/// An operation, identified by its opcode, operands, and type.
+type OperationKey = (Opcode, Vec<Operand>, Option<Type>);
+
+#[derive(Default)]
+pub struct BytecodeOptimizer {
+    /// A mapping from the registers that hold a copy of an operand to that operand.
+    copies: IndexMap<Register, Operand>,
+    /// A mapping from each operation to the register that holds its result.
+    operations: IndexMap<OperationKey, Register>,
+    /// The types of the registers, without their visibility.
+    types: IndexMap<Register, Type>,
+}
+
+impl BytecodeOptimizer {
+    /// Optimizes the body of a function or finalize block, keeping the names of the remaining registers.
+    pub(crate) fn optimize_body(
+        &mut self,
+        inputs: &mut [Input],
+        instructions: &mut Vec<SpannedInstruction>,
+        outputs: &mut [Output],
+        names: &mut IndexMap<Register, String>,
+    ) {
+        loop {
+            let length = instructions.len();
+            self.propagate_copies(inputs, instructions, outputs);
+            Self::remove_dead_registers(instructions, outputs);
+            if instructions.len() == length {
+                break;
+            }
+        }
+        Self::renumber_registers(inputs, instructions, outputs, names);
+    }
+
+    /// Finds the operations that copy an operand, and replaces every read of their destination with that operand.
+    fn propagate_copies(&mut self, inputs: &[Input], instructions: &mut [SpannedInstruction], outputs: &mut [Output]) {
+        self.copies.clear();
+        self.operations.clear();
+        self.types.clear();
+        for input in inputs {
+            self.types.insert(input.register, input.type_.type_.clone());
+        }
+
+        for SpannedInstruction { instruction, .. } in instructions.iter_mut() {
+            for operand in instruction.operands_mut() {
+                self.replace_copies(operand);
+            }
+
+            // A branch to a position may skip the operations before it, so their results are not reused after it.
+            if let Instruction::Position(_) = instruction {
+                self.operations.clear();
+            }
+
+            if let Instruction::Operation { opcode, operands, destination, type_ } = instruction {
+                if let Some(type_) = type_ {
+                    self.types.insert(*destination, type_.register_type());
+                }
+                if let Some(operand) = self.simplify(*opcode, operands, type_.as_ref()) {
+                    self.copies.insert(*destination, operand);
+                } else if is_deterministic(*opcode, type_.as_ref()) {
+                    let key = (*opcode, operands.clone(), type_.clone());
+                    match self.operations.get(&key).copied() {
+                        Some(register) => {
+                            self.copies.insert(*destination, Operand::Register(register));
+                        }
+                        None => {
+                            self.operations.insert(key, *destination);
+                        }
+                    }
+                }
+            }
+        }
+
+        for output in outputs.iter_mut() {
+            self.replace_copies(&mut output.operand);
+        }
+    }
+
+    /// Replaces the registers in `operand` that hold a copy with the operand they copy.
+    fn replace_copies(&self, operand: &mut Operand) {
+        operand.replace_registers(&|register| self.copies.get(&register).cloned());
+    }
+
+    /// Returns the operand that the result of an operation is equal to, if the operation is a copy.
+    fn simplify(&self, opcode: Opcode, operands: &[Operand], type_: Option<&Type>) -> Option<Operand> {
+        match (opcode, operands, type_) {
+            (Opcode::Ternary, [condition, if_true, if_false], _) => match condition {
+                Operand::Literal(Literal::Boolean(true)) => Some(if_true.clone()),
+                Operand::Literal(Literal::Boolean(false)) => Some(if_false.clone()),
+                _ if if_true == if_false => Some(if_true.clone()),
+                _ => None,
+            },
+            (Opcode::Cast | Opcode::CastLossy, [operand], Some(type_)) if !type_.is_record() => {
+                let operand_type = match operand {
+                    Operand::Register(register) => self.types.get(register).cloned(),
+                    Operand::Literal(literal) => Some(literal.type_()),
+                    _ => None,
+                };
+                (operand_type.as_ref() == Some(type_)).then(|| operand.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Removes the operations whose destination is never read.
+    fn remove_dead_registers(instructions: &mut Vec<SpannedInstruction>, outputs: &[Output]) {
+        let mut used: IndexSet<Register> = outputs.iter().flat_map(|output| output.operand.registers()).collect();
+        let mut is_live = vec![true; instructions.len()];
+        for (index, SpannedInstruction { instruction, .. }) in instructions.iter().enumerate().rev() {
+            if let Instruction::Operation { destination, .. } = instruction {
+                if !used.contains(destination) {
+                    is_live[index] = false;
+                    continue;
+                }
+            }
+            used.extend(instruction.operands().into_iter().flat_map(Operand::registers));
+        }
+
+        let mut liveness = is_live.into_iter();
+        instructions.retain(|_| liveness.next().unwrap());
+    }
+
+    /// Renumbers the registers consecutively, in the order in which they are defined.
+    fn renumber_registers(
+        inputs: &mut [Input],
+        instructions: &mut [SpannedInstruction],
+        outputs: &mut [Output],
+        names: &mut IndexMap<Register, String>,
+    ) {
+        fn define(register: &mut Register, registers: &mut IndexMap<Register, Register>) {
+            let renamed = Register(registers.len() as u64);
+            registers.insert(*register, renamed);
+            *register = renamed;
+        }
+        fn rename(operand: &mut Operand, registers: &IndexMap<Register, Register>) {
+            operand.replace_registers(&|register| registers.get(&register).copied().map(Operand::Register))
+        }
+
+        let mut registers = IndexMap::new();
+        for input in inputs.iter_mut() {
+            define(&mut input.register, &mut registers);
+        }
+        for SpannedInstruction { instruction, .. } in instructions.iter_mut() {
+            for operand in instruction.operands_mut() {
+                rename(operand, &registers);
+            }
+            for destination in instruction.destinations_mut() {
+                define(destination, &mut registers);
+            }
+        }
+        for output in outputs.iter_mut() {
+            rename(&mut output.operand, &registers);
+        }
+        // The registers that are no longer defined lose their names.
+        *names =
+            registers.iter().filter_map(|(register, renamed)| Some((*renamed, names.get(register)?.clone()))).collect();
+    }
+}
+
+/// Returns whether an operation always produces the same result for the same operands.
+/// Records are excluded, since each record that a function outputs must be created separately.
+fn is_deterministic(opcode: Opcode, type_: Option<&Type>) -> bool {
+    opcode.is_deterministic() && !type_.map_or(false, Type::is_record)
+}
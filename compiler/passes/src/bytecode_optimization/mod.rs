@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Bytecode Optimization pass rewrites the Aleo instructions produced by code generation.
+//! The pass is run after the Code Generation pass, when the optimization level is at least 1.
+//!
+//! Within each function and finalize block, the pass
+//! - simplifies `ternary`s whose condition is a literal or whose branches are the same operand,
+//! - removes `cast`s of an operand to its own type,
+//! - eliminates common subexpressions, i.e. repeated operations on the same operands,
+//! - propagates the copies that the previous steps produce to the instructions and outputs that read them,
+//! - removes the operations whose results are never read, and
+//! - renumbers the remaining registers in the order in which they are defined.
+//!
+//! Consider the following Aleo function.
+//! ```text
+//! function main:
+//!     input r0 as u8.private;
+//!     input r1 as u8.private;
+//!     is.eq r0 r1 into r2;
+//!     cast r0 into r3 as u8;
+//!     is.eq r3 r1 into r4;
+//!     ternary true r2 r4 into r5;
+//!     and r5 r4 into r6;
+//!     output r6 as boolean.private;
+//! ```
+//!
+//! The bytecode optimization pass produces the following function.
+//! ```text
+//! function main:
+//!     input r0 as u8.private;
+//!     input r1 as u8.private;
+//!     is.eq r0 r1 into r2;
+//!     and r2 r2 into r3;
+//!     output r3 as boolean.private;
+//! ```
+//! Note that operations that may halt, e.g. a checked `add` that overflows, are removed when their result is unused.
+//! This matches the behavior of the Dead Code Elimination pass.
+
+pub mod bytecode_optimizer;
+pub use bytecode_optimizer::*;
+
+use crate::{aleo_ir, Pass};
+
+impl Pass for BytecodeOptimizer {
+    type Input = aleo_ir::Program;
+    type Output = aleo_ir::Program;
+
+    fn do_pass(mut program: Self::Input) -> Self::Output {
+        let mut optimizer = BytecodeOptimizer::default();
+        for function in program.functions.iter_mut() {
+            optimizer.optimize_body(
+                &mut function.inputs,
+                &mut function.instructions,
+                &mut function.outputs,
+                &mut function.names,
+            );
+            if let Some(finalize) = function.finalize.as_mut() {
+                optimizer.optimize_body(
+                    &mut finalize.inputs,
+                    &mut finalize.instructions,
+                    &mut finalize.outputs,
+                    &mut finalize.names,
+                );
+            }
+        }
+
+        program
+    }
+}
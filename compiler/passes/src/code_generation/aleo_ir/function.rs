@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::aleo_ir::{Operand, Register, SpannedInstruction, ValueType};
+
+use leo_span::Span;
+
+use indexmap::IndexMap;
+use std::fmt;
+
+/// The kind of an Aleo function.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FunctionKind {
+    /// An Aleo `function`, generated from a transition.
+    Function,
+    /// An Aleo `closure`, generated from a standard function.
+    Closure,
+    /// An inline function. Inline functions are not printed, but keep their place in the layout of the program.
+    Inline,
+}
+
+/// An input declaration, e.g. `input r0 as u8.private`.
+/// Note that the span is ignored when comparing inputs.
+#[derive(Clone, Debug)]
+pub struct Input {
+    pub register: Register,
+    pub type_: ValueType,
+    /// The span of the Leo input that the input is generated from.
+    pub span: Span,
+}
+
+impl PartialEq for Input {
+    fn eq(&self, other: &Self) -> bool {
+        self.register == other.register && self.type_ == other.type_
+    }
+}
+
+impl Eq for Input {}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "input {} as {};", self.register, self.type_)
+    }
+}
+
+/// An output declaration, e.g. `output r2 as u8.private`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Output {
+    pub operand: Operand,
+    pub type_: ValueType,
+}
+
+impl fmt::Display for Output {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "output {} as {};", self.operand, self.type_)
+    }
+}
+
+/// An Aleo function or closure.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Function {
+    pub name: String,
+    pub kind: FunctionKind,
+    pub inputs: Vec<Input>,
+    pub instructions: Vec<SpannedInstruction>,
+    pub outputs: Vec<Output>,
+    pub finalize: Option<Finalize>,
+    /// The span of the Leo function.
+    pub span: Span,
+    /// The names of the Leo variables that the registers hold, as they are named after the earlier passes.
+    pub names: IndexMap<Register, String>,
+}
+
+/// The finalize block of an Aleo function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    pub name: String,
+    pub inputs: Vec<Input>,
+    pub instructions: Vec<SpannedInstruction>,
+    pub outputs: Vec<Output>,
+    /// The span of the Leo finalize block.
+    pub span: Span,
+    /// The names of the Leo variables that the registers hold, as they are named after the earlier passes.
+    pub names: IndexMap<Register, String>,
+}
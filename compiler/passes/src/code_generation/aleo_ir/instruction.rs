@@ -0,0 +1,216 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::aleo_ir::{Opcode, Operand, Register, Type};
+
+use leo_span::Span;
+
+use std::fmt;
+
+/// An Aleo instruction.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    /// An instruction that computes a value from its operands, e.g. `add r0 r1 into r2` or `cast r0 into r1 as u8`.
+    Operation { opcode: Opcode, operands: Vec<Operand>, destination: Register, type_: Option<Type> },
+    /// `assert.eq left right`.
+    AssertEq(Operand, Operand),
+    /// `assert.neq left right`.
+    AssertNeq(Operand, Operand),
+    /// A call to a closure or to a function of an imported program, e.g. `call foo.aleo/bar r0 into r1 r2`.
+    Call { callee: String, arguments: Vec<Operand>, destinations: Vec<Register> },
+    /// `async function arguments into destination`, which creates the future of a function's finalize block.
+    Async { function: String, arguments: Vec<Operand>, destination: Register },
+    /// `await future`.
+    Await(Operand),
+    /// `get mapping[key] into destination`.
+    Get { mapping: Operand, key: Operand, destination: Register },
+    /// `get.or_use mapping[key] default into destination`.
+    GetOrUse { mapping: Operand, key: Operand, default: Operand, destination: Register },
+    /// `contains mapping[key] into destination`.
+    Contains { mapping: Operand, key: Operand, destination: Register },
+    /// `set value into mapping[key]`.
+    Set { mapping: Operand, key: Operand, value: Operand },
+    /// `remove mapping[key]`.
+    Remove { mapping: Operand, key: Operand },
+    /// `branch.eq first second to position`, which skips forward to `position` if the operands are equal.
+    /// Branches are only valid in finalize blocks.
+    BranchEq { first: Operand, second: Operand, position: String },
+    /// `branch.neq first second to position`, which skips forward to `position` if the operands are not equal.
+    BranchNeq { first: Operand, second: Operand, position: String },
+    /// `position label`, which marks the target of a branch.
+    Position(String),
+}
+
+impl Instruction {
+    /// Returns the operands that the instruction reads.
+    pub fn operands(&self) -> Vec<&Operand> {
+        match self {
+            Instruction::Operation { operands, .. } => operands.iter().collect(),
+            Instruction::AssertEq(left, right) | Instruction::AssertNeq(left, right) => vec![left, right],
+            Instruction::Call { arguments, .. } | Instruction::Async { arguments, .. } => arguments.iter().collect(),
+            Instruction::Await(future) => vec![future],
+            Instruction::Get { mapping, key, .. }
+            | Instruction::Contains { mapping, key, .. }
+            | Instruction::Remove { mapping, key } => vec![mapping, key],
+            Instruction::GetOrUse { mapping, key, default, .. } => vec![mapping, key, default],
+            Instruction::Set { mapping, key, value } => vec![mapping, key, value],
+            Instruction::BranchEq { first, second, .. } | Instruction::BranchNeq { first, second, .. } => {
+                vec![first, second]
+            }
+            Instruction::Position(_) => Vec::new(),
+        }
+    }
+
+    /// Returns mutable references to the operands that the instruction reads.
+    pub fn operands_mut(&mut self) -> Vec<&mut Operand> {
+        match self {
+            Instruction::Operation { operands, .. } => operands.iter_mut().collect(),
+            Instruction::AssertEq(left, right) | Instruction::AssertNeq(left, right) => vec![left, right],
+            Instruction::Call { arguments, .. } | Instruction::Async { arguments, .. } => {
+                arguments.iter_mut().collect()
+            }
+            Instruction::Await(future) => vec![future],
+            Instruction::Get { mapping, key, .. }
+            | Instruction::Contains { mapping, key, .. }
+            | Instruction::Remove { mapping, key } => vec![mapping, key],
+            Instruction::GetOrUse { mapping, key, default, .. } => vec![mapping, key, default],
+            Instruction::Set { mapping, key, value } => vec![mapping, key, value],
+            Instruction::BranchEq { first, second, .. } | Instruction::BranchNeq { first, second, .. } => {
+                vec![first, second]
+            }
+            Instruction::Position(_) => Vec::new(),
+        }
+    }
+
+    /// Returns the registers that the instruction writes.
+    pub fn destinations(&self) -> Vec<&Register> {
+        match self {
+            Instruction::Operation { destination, .. }
+            | Instruction::Async { destination, .. }
+            | Instruction::Get { destination, .. }
+            | Instruction::GetOrUse { destination, .. }
+            | Instruction::Contains { destination, .. } => vec![destination],
+            Instruction::Call { destinations, .. } => destinations.iter().collect(),
+            Instruction::AssertEq(..)
+            | Instruction::AssertNeq(..)
+            | Instruction::Await(_)
+            | Instruction::Set { .. }
+            | Instruction::Remove { .. }
+            | Instruction::BranchEq { .. }
+            | Instruction::BranchNeq { .. }
+            | Instruction::Position(_) => Vec::new(),
+        }
+    }
+
+    /// Returns mutable references to the registers that the instruction writes.
+    pub fn destinations_mut(&mut self) -> Vec<&mut Register> {
+        match self {
+            Instruction::Operation { destination, .. }
+            | Instruction::Async { destination, .. }
+            | Instruction::Get { destination, .. }
+            | Instruction::GetOrUse { destination, .. }
+            | Instruction::Contains { destination, .. } => vec![destination],
+            Instruction::Call { destinations, .. } => destinations.iter_mut().collect(),
+            Instruction::AssertEq(..)
+            | Instruction::AssertNeq(..)
+            | Instruction::Await(_)
+            | Instruction::Set { .. }
+            | Instruction::Remove { .. }
+            | Instruction::BranchEq { .. }
+            | Instruction::BranchNeq { .. }
+            | Instruction::Position(_) => Vec::new(),
+        }
+    }
+}
+
+/// Writes each operand, preceded by a space.
+fn write_operands(f: &mut fmt::Formatter, operands: &[Operand]) -> fmt::Result {
+    operands.iter().try_for_each(|operand| write!(f, " {operand}"))
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Instruction::Operation { opcode, operands, destination, type_ } => {
+                write!(f, "{opcode}")?;
+                write_operands(f, operands)?;
+                write!(f, " into {destination}")?;
+                if let Some(type_) = type_ {
+                    write!(f, " as {type_}")?;
+                }
+            }
+            Instruction::AssertEq(left, right) => write!(f, "assert.eq {left} {right}")?,
+            Instruction::AssertNeq(left, right) => write!(f, "assert.neq {left} {right}")?,
+            Instruction::Call { callee, arguments, destinations } => {
+                write!(f, "call {callee}")?;
+                write_operands(f, arguments)?;
+                if !destinations.is_empty() {
+                    write!(f, " into")?;
+                    destinations.iter().try_for_each(|destination| write!(f, " {destination}"))?;
+                }
+            }
+            Instruction::Async { function, arguments, destination } => {
+                write!(f, "async {function}")?;
+                write_operands(f, arguments)?;
+                write!(f, " into {destination}")?;
+            }
+            Instruction::Await(future) => write!(f, "await {future}")?,
+            Instruction::Get { mapping, key, destination } => write!(f, "get {mapping}[{key}] into {destination}")?,
+            Instruction::GetOrUse { mapping, key, default, destination } => {
+                write!(f, "get.or_use {mapping}[{key}] {default} into {destination}")?
+            }
+            Instruction::Contains { mapping, key, destination } => {
+                write!(f, "contains {mapping}[{key}] into {destination}")?
+            }
+            Instruction::Set { mapping, key, value } => write!(f, "set {value} into {mapping}[{key}]")?,
+            Instruction::Remove { mapping, key } => write!(f, "remove {mapping}[{key}]")?,
+            Instruction::BranchEq { first, second, position } => write!(f, "branch.eq {first} {second} to {position}")?,
+            Instruction::BranchNeq { first, second, position } => {
+                write!(f, "branch.neq {first} {second} to {position}")?
+            }
+            Instruction::Position(label) => write!(f, "position {label}")?,
+        }
+        write!(f, ";")
+    }
+}
+
+/// An instruction, along with the span of the Leo statement that it is generated from.
+/// Note that the span is ignored when comparing instructions.
+#[derive(Clone, Debug)]
+pub struct SpannedInstruction {
+    pub instruction: Instruction,
+    pub span: Span,
+}
+
+impl SpannedInstruction {
+    pub fn new(instruction: Instruction, span: Span) -> Self {
+        Self { instruction, span }
+    }
+}
+
+impl PartialEq for SpannedInstruction {
+    fn eq(&self, other: &Self) -> bool {
+        self.instruction == other.instruction
+    }
+}
+
+impl Eq for SpannedInstruction {}
+
+impl fmt::Display for SpannedInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.instruction)
+    }
+}
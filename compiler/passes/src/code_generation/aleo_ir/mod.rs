@@ -0,0 +1,39 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! An in-memory representation of a program in Aleo instructions.
+//! The code generator produces an `aleo_ir::Program`, which `Program::print` prints as bytecode.
+
+pub mod function;
+pub use function::*;
+
+pub mod instruction;
+pub use instruction::*;
+
+pub mod opcode;
+pub use opcode::*;
+
+pub mod operand;
+pub use operand::*;
+
+pub mod printer;
+pub use printer::*;
+
+pub mod program;
+pub use program::*;
+
+pub mod types;
+pub use types::*;
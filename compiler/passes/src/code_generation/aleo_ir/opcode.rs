@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fmt;
+
+/// A hash or commitment algorithm, e.g. `bhp256` in `hash.bhp256`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Algorithm {
+    Bhp256,
+    Bhp512,
+    Bhp768,
+    Bhp1024,
+    Keccak256,
+    Keccak384,
+    Keccak512,
+    Ped64,
+    Ped128,
+    Psd2,
+    Psd4,
+    Psd8,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+}
+
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Algorithm::Bhp256 => "bhp256",
+            Algorithm::Bhp512 => "bhp512",
+            Algorithm::Bhp768 => "bhp768",
+            Algorithm::Bhp1024 => "bhp1024",
+            Algorithm::Keccak256 => "keccak256",
+            Algorithm::Keccak384 => "keccak384",
+            Algorithm::Keccak512 => "keccak512",
+            Algorithm::Ped64 => "ped64",
+            Algorithm::Ped128 => "ped128",
+            Algorithm::Psd2 => "psd2",
+            Algorithm::Psd4 => "psd4",
+            Algorithm::Psd8 => "psd8",
+            Algorithm::Sha3_256 => "sha3_256",
+            Algorithm::Sha3_384 => "sha3_384",
+            Algorithm::Sha3_512 => "sha3_512",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// The opcode of an operation, e.g. `add` or `hash.bhp256`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Opcode {
+    Abs,
+    AbsWrapped,
+    Add,
+    AddWrapped,
+    And,
+    Cast,
+    CastLossy,
+    Commit(Algorithm),
+    Div,
+    DivWrapped,
+    Double,
+    Gt,
+    Gte,
+    Hash(Algorithm),
+    Inv,
+    IsEq,
+    IsNeq,
+    Lt,
+    Lte,
+    Mod,
+    Mul,
+    MulWrapped,
+    Nand,
+    Neg,
+    Nor,
+    Not,
+    Or,
+    Pow,
+    PowWrapped,
+    RandChaCha,
+    Rem,
+    RemWrapped,
+    Shl,
+    ShlWrapped,
+    Shr,
+    ShrWrapped,
+    SignVerify,
+    Sqrt,
+    Square,
+    Sub,
+    SubWrapped,
+    Ternary,
+    Xor,
+}
+
+impl Opcode {
+    /// Returns whether the operation always produces the same result for the same operands.
+    pub fn is_deterministic(&self) -> bool {
+        *self != Opcode::RandChaCha
+    }
+}
+
+impl fmt::Display for Opcode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Opcode::Commit(algorithm) => return write!(f, "commit.{algorithm}"),
+            Opcode::Hash(algorithm) => return write!(f, "hash.{algorithm}"),
+            Opcode::Abs => "abs",
+            Opcode::AbsWrapped => "abs.w",
+            Opcode::Add => "add",
+            Opcode::AddWrapped => "add.w",
+            Opcode::And => "and",
+            Opcode::Cast => "cast",
+            Opcode::CastLossy => "cast.lossy",
+            Opcode::Div => "div",
+            Opcode::DivWrapped => "div.w",
+            Opcode::Double => "double",
+            Opcode::Gt => "gt",
+            Opcode::Gte => "gte",
+            Opcode::Inv => "inv",
+            Opcode::IsEq => "is.eq",
+            Opcode::IsNeq => "is.neq",
+            Opcode::Lt => "lt",
+            Opcode::Lte => "lte",
+            Opcode::Mod => "mod",
+            Opcode::Mul => "mul",
+            Opcode::MulWrapped => "mul.w",
+            Opcode::Nand => "nand",
+            Opcode::Neg => "neg",
+            Opcode::Nor => "nor",
+            Opcode::Not => "not",
+            Opcode::Or => "or",
+            Opcode::Pow => "pow",
+            Opcode::PowWrapped => "pow.w",
+            Opcode::RandChaCha => "rand.chacha",
+            Opcode::Rem => "rem",
+            Opcode::RemWrapped => "rem.w",
+            Opcode::Shl => "shl",
+            Opcode::ShlWrapped => "shl.w",
+            Opcode::Shr => "shr",
+            Opcode::ShrWrapped => "shr.w",
+            Opcode::SignVerify => "sign.verify",
+            Opcode::Sqrt => "sqrt",
+            Opcode::Square => "square",
+            Opcode::Sub => "sub",
+            Opcode::SubWrapped => "sub.w",
+            Opcode::Ternary => "ternary",
+            Opcode::Xor => "xor",
+        };
+        write!(f, "{name}")
+    }
+}
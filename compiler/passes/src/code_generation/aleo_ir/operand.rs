@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::aleo_ir::Type;
+
+use leo_ast::IntegerType;
+
+use std::fmt;
+
+/// A register, printed as `r{index}`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Register(pub u64);
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "r{}", self.0)
+    }
+}
+
+/// A literal, e.g. `1u8`, `true`, or `group::GEN`.
+/// Numbers are stored without their type suffix.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Literal {
+    Address(String),
+    Boolean(bool),
+    Field(String),
+    Group(String),
+    /// The generator of the group, `group::GEN`.
+    GroupGenerator,
+    Integer(IntegerType, String),
+    Scalar(String),
+    String(String),
+}
+
+impl Literal {
+    /// Returns the type of the literal.
+    pub fn type_(&self) -> Type {
+        match self {
+            Literal::Address(_) => Type::Address,
+            Literal::Boolean(_) => Type::Boolean,
+            Literal::Field(_) => Type::Field,
+            Literal::Group(_) | Literal::GroupGenerator => Type::Group,
+            Literal::Integer(type_, _) => Type::Integer(*type_),
+            Literal::Scalar(_) => Type::Scalar,
+            Literal::String(_) => Type::String,
+        }
+    }
+}
+
+impl From<&leo_ast::Literal> for Literal {
+    fn from(literal: &leo_ast::Literal) -> Self {
+        match literal {
+            leo_ast::Literal::Address(address, ..) => Literal::Address(address.clone()),
+            leo_ast::Literal::Boolean(boolean, ..) => Literal::Boolean(*boolean),
+            leo_ast::Literal::Field(field, ..) => Literal::Field(field.clone()),
+            leo_ast::Literal::Group(group) => Literal::Group(group.to_string()),
+            leo_ast::Literal::Integer(type_, value, ..) => Literal::Integer(*type_, value.clone()),
+            leo_ast::Literal::Scalar(scalar, ..) => Literal::Scalar(scalar.clone()),
+            leo_ast::Literal::String(string, ..) => Literal::String(string.clone()),
+        }
+    }
+}
+
+impl fmt::Display for Literal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Literal::Address(address) => write!(f, "{address}"),
+            Literal::Boolean(boolean) => write!(f, "{boolean}"),
+            Literal::Field(field) => write!(f, "{field}field"),
+            Literal::Group(group) => write!(f, "{group}group"),
+            Literal::GroupGenerator => write!(f, "group::GEN"),
+            Literal::Integer(type_, value) => write!(f, "{value}{type_}"),
+            Literal::Scalar(scalar) => write!(f, "{scalar}scalar"),
+            Literal::String(string) => write!(f, "\"{string}\""),
+        }
+    }
+}
+
+/// An operand of an Aleo instruction.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Operand {
+    /// A register, e.g. `r0`.
+    Register(Register),
+    /// A literal, e.g. `1u8`, `true`, or `group::GEN`.
+    Literal(Literal),
+    /// A name that does not refer to a register, e.g. `self`, `block`, or a mapping.
+    Identifier(String),
+    /// An access to a member of an operand, e.g. `r0.owner` or `self.caller`.
+    Member(Box<Operand>, String),
+    /// An access to an element of an operand, e.g. `r0[1u32]`.
+    Index(Box<Operand>, Box<Operand>),
+}
+
+impl Operand {
+    /// Returns the registers that the operand reads, e.g. `r0` for `r0.owner`.
+    pub fn registers(&self) -> Vec<Register> {
+        match self {
+            Operand::Register(register) => vec![*register],
+            Operand::Literal(_) | Operand::Identifier(_) => Vec::new(),
+            Operand::Member(inner, _) => inner.registers(),
+            Operand::Index(inner, index) => inner.registers().into_iter().chain(index.registers()).collect(),
+        }
+    }
+
+    /// Replaces each register `r` that the operand reads with `replace(r)`, if it returns an operand.
+    pub fn replace_registers(&mut self, replace: &impl Fn(Register) -> Option<Operand>) {
+        match self {
+            Operand::Register(register) => {
+                if let Some(operand) = replace(*register) {
+                    *self = operand;
+                }
+            }
+            Operand::Literal(_) | Operand::Identifier(_) => {}
+            Operand::Member(inner, _) => inner.replace_registers(replace),
+            Operand::Index(inner, index) => {
+                inner.replace_registers(replace);
+                index.replace_registers(replace);
+            }
+        }
+    }
+}
+
+impl From<Register> for Operand {
+    fn from(register: Register) -> Self {
+        Operand::Register(register)
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operand::Register(register) => write!(f, "{register}"),
+            Operand::Literal(literal) => write!(f, "{literal}"),
+            Operand::Identifier(identifier) => write!(f, "{identifier}"),
+            Operand::Member(inner, name) => write!(f, "{inner}.{name}"),
+            Operand::Index(inner, index) => write!(f, "{inner}[{index}]"),
+        }
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::aleo_ir::{FunctionKind, Input, Output, Program, Register, SpannedInstruction};
+
+use leo_span::{symbol::with_session_globals, Span};
+
+use indexmap::IndexMap;
+use itertools::Itertools;
+
+/// The lines, starting at 1, at which the inputs and instructions of a function, closure, or finalize block are printed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BodyLines {
+    pub inputs: Vec<usize>,
+    pub instructions: Vec<usize>,
+}
+
+/// A program printed as Aleo instructions.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PrintedProgram {
+    pub bytecode: String,
+    /// The lines of each function, closure, and finalize block, indexed by its name and whether it is a finalize block.
+    pub bodies: IndexMap<(String, bool), BodyLines>,
+}
+
+/// Prints a program, keeping track of the line being printed.
+struct Printer {
+    printed: PrintedProgram,
+    /// The number of lines printed so far.
+    lines: usize,
+    /// Whether to annotate the bodies with their Leo source, as comments.
+    comments: bool,
+}
+
+impl Program {
+    /// Prints the program as Aleo instructions, recording the line of each input and instruction.
+    /// If `comments` is set, the bodies are annotated with their Leo source, as comments.
+    pub fn print(&self, comments: bool) -> PrintedProgram {
+        let mut printer = Printer { printed: PrintedProgram::default(), lines: 0, comments };
+        if !self.imports.is_empty() {
+            printer
+                .push(&format!("{}\n", self.imports.iter().map(|import| format!("import {import}.aleo;")).join("\n")));
+        }
+        printer.push(&format!("program {};\n\n", self.id));
+        printer.push(&format!("{}\n", self.composites.iter().join("\n")));
+        printer.push(&self.mappings.iter().join("\n"));
+        for (index, function) in self.functions.iter().enumerate() {
+            if index > 0 {
+                printer.push("\n");
+            }
+            let keyword = match function.kind {
+                FunctionKind::Function => "function",
+                FunctionKind::Closure => "closure",
+                // Inline functions are not printed, but keep their place in the layout of the program.
+                FunctionKind::Inline => {
+                    printer.push("\n");
+                    continue;
+                }
+            };
+            printer.header(&format!("{keyword} {}:", function.name), function.span, &function.names);
+            let lines = printer.body(&function.inputs, &function.instructions, &function.outputs);
+            printer.printed.bodies.insert((function.name.clone(), false), lines);
+            if let Some(finalize) = &function.finalize {
+                printer.header(&format!("finalize {}:", finalize.name), finalize.span, &finalize.names);
+                let lines = printer.body(&finalize.inputs, &finalize.instructions, &finalize.outputs);
+                printer.printed.bodies.insert((finalize.name.clone(), true), lines);
+            }
+        }
+        printer.printed
+    }
+}
+
+impl Printer {
+    /// Appends `text` to the bytecode.
+    fn push(&mut self, text: &str) {
+        self.lines += text.matches('\n').count();
+        self.printed.bytecode.push_str(text);
+    }
+
+    /// Appends `text` as a line of its own, and returns the number of the line.
+    fn push_line(&mut self, text: &str) -> usize {
+        self.push(&format!("{text}\n"));
+        self.lines
+    }
+
+    /// Prints the header of a function or finalize block.
+    /// With comments, the header is preceded by its Leo source, and followed by the Leo names of its registers.
+    fn header(&mut self, header: &str, span: Span, names: &IndexMap<Register, String>) {
+        self.push("\n");
+        if !self.comments {
+            self.push_line(header);
+            return;
+        }
+        if let Some(source) = source_line(span) {
+            self.push_line(&format!("// {source}"));
+        }
+        self.push_line(header);
+        for (register, name) in names {
+            // Names introduced by the compiler contain a `$`, and start with the Leo name they are derived from, if any.
+            let line = match name.split_once('$') {
+                None => format!("    // {register}: {name}"),
+                Some(("", _)) => format!("    // {register}: {name} (temporary)"),
+                Some((leo_name, _)) => format!("    // {register}: {name} ({leo_name})"),
+            };
+            self.push_line(&line);
+        }
+    }
+
+    /// Prints the inputs, instructions, and outputs of a function or finalize block, one per line.
+    /// With comments, each run of instructions from the same line of Leo is preceded by that line.
+    fn body(&mut self, inputs: &[Input], instructions: &[SpannedInstruction], outputs: &[Output]) -> BodyLines {
+        let mut lines = BodyLines::default();
+        for input in inputs {
+            lines.inputs.push(self.push_line(&format!("    {input}")));
+        }
+        let mut previous = None;
+        for instruction in instructions {
+            if let Some(source) = source_line(instruction.span).filter(|_| self.comments) {
+                if previous.as_ref() != Some(&source) {
+                    self.push_line(&format!("    // {source}"));
+                    previous = Some(source);
+                }
+            }
+            lines.instructions.push(self.push_line(&format!("    {}", instruction.instruction)));
+        }
+        for output in outputs {
+            self.push_line(&format!("    {output}"));
+        }
+        lines
+    }
+}
+
+/// Returns the first line of the Leo source that `span` is within, if any.
+fn source_line(span: Span) -> Option<String> {
+    if span.is_dummy() {
+        return None;
+    }
+    let lines = with_session_globals(|s| s.source_map.line_contents_of_span(span))?;
+    lines.lines().next().map(|line| line.trim().to_string())
+}
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::aleo_ir::{Function, ValueType};
+
+use std::fmt;
+
+/// The kind of an Aleo composite type.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CompositeKind {
+    Struct,
+    Record,
+    /// A struct declared in an imported program. It is not printed, but keeps its place in the dependency order.
+    Imported,
+}
+
+/// A member of a struct or record, e.g. `owner as address.private`.
+/// The members of structs are declared without a visibility.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Member {
+    pub name: String,
+    pub type_: ValueType,
+}
+
+/// An Aleo struct or record.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Composite {
+    pub name: String,
+    pub kind: CompositeKind,
+    pub members: Vec<Member>,
+}
+
+impl fmt::Display for Composite {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            CompositeKind::Struct => writeln!(f, "struct {}:", self.name)?,
+            CompositeKind::Record => writeln!(f, "record {}:", self.name)?,
+            CompositeKind::Imported => return Ok(()),
+        }
+        self.members.iter().try_for_each(|member| writeln!(f, "    {} as {};", member.name, member.type_))
+    }
+}
+
+/// An Aleo mapping.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mapping {
+    pub name: String,
+    pub key_type: ValueType,
+    pub value_type: ValueType,
+}
+
+impl fmt::Display for Mapping {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "\nmapping {}:", self.name)?;
+        writeln!(f, "\tkey as {};", self.key_type)?;
+        writeln!(f, "\tvalue as {};", self.value_type)
+    }
+}
+
+/// An Aleo program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Program {
+    /// The names of the imported programs, without the `.aleo` suffix.
+    pub imports: Vec<String>,
+    /// The program ID, e.g. `token.aleo`.
+    pub id: String,
+    /// The structs and records, in post-order of the struct dependency graph.
+    pub composites: Vec<Composite>,
+    pub mappings: Vec<Mapping>,
+    /// The functions and closures, in which each callee precedes its callers.
+    pub functions: Vec<Function>,
+}
+
+/// Prints the program without comments. Use `Program::print` to annotate it with its Leo source.
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.print(false).bytecode)
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::IntegerType;
+
+use std::fmt;
+
+/// A type of Aleo instructions, e.g. `u8`, `[u8; 2u32]`, or `token.record`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Type {
+    Address,
+    Boolean,
+    Field,
+    Group,
+    Scalar,
+    Signature,
+    String,
+    Integer(IntegerType),
+    /// The x-coordinate of a group element, `group.x`, which is only the target type of a cast.
+    GroupX,
+    /// The y-coordinate of a group element, `group.y`, which is only the target type of a cast.
+    GroupY,
+    /// A struct, e.g. `point`.
+    Struct(String),
+    /// An array, e.g. `[u8; 2u32]`.
+    Array(Box<Type>, u32),
+    /// A record of the program, e.g. `token.record`.
+    Record(String),
+    /// A record of an imported program, e.g. `credits.aleo/credits.record`.
+    ExternalRecord {
+        program: String,
+        record: String,
+    },
+    /// The future of a function's finalize block, e.g. `credits.aleo/transfer_public.future`.
+    Future {
+        program: String,
+        function: String,
+    },
+}
+
+impl Type {
+    /// Returns whether the type is a record, of the program or of an imported program.
+    pub fn is_record(&self) -> bool {
+        matches!(self, Type::Record(_) | Type::ExternalRecord { .. })
+    }
+
+    /// Returns the type of the registers that a cast to this type produces, e.g. `field` for `group.x`.
+    pub fn register_type(&self) -> Type {
+        match self {
+            Type::GroupX | Type::GroupY => Type::Field,
+            type_ => type_.clone(),
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Type::Address => write!(f, "address"),
+            Type::Boolean => write!(f, "boolean"),
+            Type::Field => write!(f, "field"),
+            Type::Group => write!(f, "group"),
+            Type::Scalar => write!(f, "scalar"),
+            Type::Signature => write!(f, "signature"),
+            Type::String => write!(f, "string"),
+            Type::Integer(type_) => write!(f, "{type_}"),
+            Type::GroupX => write!(f, "group.x"),
+            Type::GroupY => write!(f, "group.y"),
+            Type::Struct(name) => write!(f, "{name}"),
+            Type::Array(element, length) => write!(f, "[{element}; {length}u32]"),
+            Type::Record(name) => write!(f, "{name}.record"),
+            Type::ExternalRecord { program, record } => write!(f, "{program}.aleo/{record}.record"),
+            Type::Future { program, function } => write!(f, "{program}.aleo/{function}.future"),
+        }
+    }
+}
+
+/// The visibility of an input, an output, or a member of a record.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Visibility {
+    Constant,
+    Public,
+    Private,
+}
+
+impl fmt::Display for Visibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Visibility::Constant => write!(f, "constant"),
+            Visibility::Public => write!(f, "public"),
+            Visibility::Private => write!(f, "private"),
+        }
+    }
+}
+
+/// A type along with its visibility, e.g. `u8.private`.
+/// Records, futures, and the members of structs are declared without a visibility.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ValueType {
+    pub type_: Type,
+    pub visibility: Option<Visibility>,
+}
+
+impl fmt::Display for ValueType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.visibility {
+            Some(visibility) => write!(f, "{}.{visibility}", self.type_),
+            None => write!(f, "{}", self.type_),
+        }
+    }
+}
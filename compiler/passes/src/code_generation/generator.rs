@@ -14,7 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CallGraph, StructGraph, SymbolTable, TypeTable};
+use crate::{
+    aleo_ir::{Operand, Output, Register, Type},
+    CallGraph,
+    StructGraph,
+    SymbolTable,
+    TypeTable,
+};
 
 use leo_ast::{Function, Program, ProgramId};
 use leo_span::Symbol;
@@ -35,20 +41,26 @@ pub struct CodeGenerator<'a> {
     /// Reference to the current function.
     pub(crate) current_function: Option<&'a Function>,
     /// Mapping of variables to registers.
-    pub(crate) variable_mapping: IndexMap<&'a Symbol, String>,
+    pub(crate) variable_mapping: IndexMap<&'a Symbol, Operand>,
     /// Mapping of composite names to a tuple containing metadata associated with the name.
     /// The first element of the tuple indicate whether the composite is a record or not.
     /// The second element of the tuple is a string modifier used for code generation.
     pub(crate) composite_mapping: IndexMap<&'a Symbol, (bool, String)>,
     /// Mapping of global identifiers to their associated names.
-    pub(crate) global_mapping: IndexMap<&'a Symbol, String>,
+    pub(crate) global_mapping: IndexMap<&'a Symbol, Operand>,
     /// Are we traversing a transition function?
     pub(crate) is_transition_function: bool,
     /// Are we traversing a finalize block?
     pub(crate) in_finalize: bool,
+    /// A counter to generate unique position labels for the branches in the current finalize block.
+    pub(crate) next_label: u64,
     // TODO (@d0cd): There are a temporary solution to be compatible with futures introduced in Aleo instructions.
-    // The registers containing futures produced in the current transition.
-    pub(crate) futures: Vec<(String, String)>,
+    // The registers containing futures produced in the current transition, along with their types.
+    pub(crate) futures: Vec<(Register, Type)>,
+    /// The outputs declared by the return statement of the current function or finalize block.
+    pub(crate) outputs: Vec<Output>,
+    /// The names of the variables held by the registers of the current function or finalize block.
+    pub(crate) names: IndexMap<Register, String>,
     // A reference to program. This is needed to look up external programs.
     pub(crate) program: &'a Program,
     // The program ID of the current program.
@@ -77,9 +89,29 @@ impl<'a> CodeGenerator<'a> {
             global_mapping: IndexMap::new(),
             is_transition_function: false,
             in_finalize: false,
+            next_label: 0,
             futures: Vec::new(),
+            outputs: Vec::new(),
+            names: IndexMap::new(),
             program,
             program_id: None,
         }
     }
+
+    /// Returns the next available register.
+    pub(crate) fn allocate_register(&mut self) -> Register {
+        let register = Register(self.next_register);
+        self.next_register += 1;
+        register
+    }
+
+    /// Records `name` as the name of the variable held by `operand`, if it is a register.
+    /// The first name of a register is kept, unless it is a temporary introduced by the compiler, e.g. `$var$0`.
+    pub(crate) fn record_name(&mut self, operand: &Operand, name: Symbol) {
+        if let Operand::Register(register) = operand {
+            if !self.names.get(register).map_or(false, |existing| !existing.starts_with('$')) {
+                self.names.insert(*register, name.to_string());
+            }
+        }
+    }
 }
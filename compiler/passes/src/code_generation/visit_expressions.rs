@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::CodeGenerator;
+use crate::{
+    aleo_ir::{self, Algorithm, Instruction, Opcode, Operand},
+    CodeGenerator,
+};
 use leo_ast::{
     AccessExpression,
     ArrayAccess,
@@ -25,9 +28,12 @@ use leo_ast::{
     BinaryOperation,
     CallExpression,
     CastExpression,
+    CastKind,
+    CoreConstant,
     ErrExpression,
     Expression,
     Identifier,
+    IntegerType,
     Literal,
     MemberAccess,
     StructExpression,
@@ -41,477 +47,481 @@ use leo_ast::{
 use leo_span::sym;
 use std::borrow::Borrow;
 
-use std::fmt::Write as _;
-
 /// Implement the necessary methods to visit nodes in the AST.
 // Note: We opt for this option instead of using `Visitor` and `Director` because this pass requires
 // a post-order traversal of the AST. This is sufficient since this implementation is intended to be
 // a prototype. The production implementation will require a redesign of `Director`.
 impl<'a> CodeGenerator<'a> {
-    pub(crate) fn visit_expression(&mut self, input: &'a Expression) -> (String, String) {
+    /// Returns the operands produced by `input`, along with the instructions that compute them.
+    /// Calls and tuples may produce any number of operands, every other expression produces exactly one.
+    pub(crate) fn visit_expression(&mut self, input: &'a Expression) -> (Vec<Operand>, Vec<Instruction>) {
+        fn single((operand, instructions): (Operand, Vec<Instruction>)) -> (Vec<Operand>, Vec<Instruction>) {
+            (vec![operand], instructions)
+        }
+
         match input {
             Expression::Access(expr) => self.visit_access(expr),
-            Expression::Array(expr) => self.visit_array(expr),
-            Expression::Binary(expr) => self.visit_binary(expr),
+            Expression::Array(expr) => single(self.visit_array(expr)),
+            Expression::Binary(expr) => single(self.visit_binary(expr)),
             Expression::Call(expr) => self.visit_call(expr),
-            Expression::Cast(expr) => self.visit_cast(expr),
-            Expression::Struct(expr) => self.visit_struct_init(expr),
-            Expression::Err(expr) => self.visit_err(expr),
-            Expression::Identifier(expr) => self.visit_identifier(expr),
-            Expression::Literal(expr) => self.visit_value(expr),
-            Expression::Ternary(expr) => self.visit_ternary(expr),
+            Expression::Cast(expr) => single(self.visit_cast(expr)),
+            Expression::Struct(expr) => single(self.visit_struct_init(expr)),
+            Expression::Err(expr) => single(self.visit_err(expr)),
+            Expression::Identifier(expr) => single(self.visit_identifier(expr)),
+            Expression::Literal(expr) => single(self.visit_value(expr)),
+            Expression::Ternary(expr) => single(self.visit_ternary(expr)),
             Expression::Tuple(expr) => self.visit_tuple(expr),
-            Expression::Unary(expr) => self.visit_unary(expr),
+            Expression::Unary(expr) => single(self.visit_unary(expr)),
             Expression::Unit(expr) => self.visit_unit(expr),
         }
     }
 
-    fn visit_identifier(&mut self, input: &'a Identifier) -> (String, String) {
+    /// Returns the single operand produced by `input`, along with the instructions that compute it.
+    pub(crate) fn visit_operand(&mut self, input: &'a Expression) -> (Operand, Vec<Instruction>) {
+        let (mut operands, instructions) = self.visit_expression(input);
+        match (operands.pop(), operands.is_empty()) {
+            (Some(operand), true) => (operand, instructions),
+            _ => unreachable!("Type checking guarantees that operands of an instruction are single values."),
+        }
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier) -> (Operand, Vec<Instruction>) {
         (
             self.variable_mapping.get(&input.name).or_else(|| self.global_mapping.get(&input.name)).unwrap().clone(),
-            String::new(),
+            Vec::new(),
         )
     }
 
-    fn visit_err(&mut self, _input: &'a ErrExpression) -> (String, String) {
+    fn visit_err(&mut self, _input: &'a ErrExpression) -> (Operand, Vec<Instruction>) {
         unreachable!("`ErrExpression`s should not be in the AST at this phase of compilation.")
     }
 
-    fn visit_value(&mut self, input: &'a Literal) -> (String, String) {
-        (format!("{input}"), String::new())
+    fn visit_value(&mut self, input: &'a Literal) -> (Operand, Vec<Instruction>) {
+        (Operand::Literal(input.into()), Vec::new())
     }
 
-    fn visit_binary(&mut self, input: &'a BinaryExpression) -> (String, String) {
-        let (left_operand, left_instructions) = self.visit_expression(&input.left);
-        let (right_operand, right_instructions) = self.visit_expression(&input.right);
+    fn visit_binary(&mut self, input: &'a BinaryExpression) -> (Operand, Vec<Instruction>) {
+        let (left_operand, left_instructions) = self.visit_operand(&input.left);
+        let (right_operand, right_instructions) = self.visit_operand(&input.right);
 
         let opcode = match input.op {
-            BinaryOperation::Add => String::from("add"),
-            BinaryOperation::AddWrapped => String::from("add.w"),
-            BinaryOperation::And => String::from("and"),
-            BinaryOperation::BitwiseAnd => String::from("and"),
-            BinaryOperation::Div => String::from("div"),
-            BinaryOperation::DivWrapped => String::from("div.w"),
-            BinaryOperation::Eq => String::from("is.eq"),
-            BinaryOperation::Gte => String::from("gte"),
-            BinaryOperation::Gt => String::from("gt"),
-            BinaryOperation::Lte => String::from("lte"),
-            BinaryOperation::Lt => String::from("lt"),
-            BinaryOperation::Mod => String::from("mod"),
-            BinaryOperation::Mul => String::from("mul"),
-            BinaryOperation::MulWrapped => String::from("mul.w"),
-            BinaryOperation::Nand => String::from("nand"),
-            BinaryOperation::Neq => String::from("is.neq"),
-            BinaryOperation::Nor => String::from("nor"),
-            BinaryOperation::Or => String::from("or"),
-            BinaryOperation::BitwiseOr => String::from("or"),
-            BinaryOperation::Pow => String::from("pow"),
-            BinaryOperation::PowWrapped => String::from("pow.w"),
-            BinaryOperation::Rem => String::from("rem"),
-            BinaryOperation::RemWrapped => String::from("rem.w"),
-            BinaryOperation::Shl => String::from("shl"),
-            BinaryOperation::ShlWrapped => String::from("shl.w"),
-            BinaryOperation::Shr => String::from("shr"),
-            BinaryOperation::ShrWrapped => String::from("shr.w"),
-            BinaryOperation::Sub => String::from("sub"),
-            BinaryOperation::SubWrapped => String::from("sub.w"),
-            BinaryOperation::Xor => String::from("xor"),
+            BinaryOperation::Add => Opcode::Add,
+            BinaryOperation::AddWrapped => Opcode::AddWrapped,
+            BinaryOperation::And => Opcode::And,
+            BinaryOperation::BitwiseAnd => Opcode::And,
+            BinaryOperation::Div => Opcode::Div,
+            BinaryOperation::DivWrapped => Opcode::DivWrapped,
+            BinaryOperation::Eq => Opcode::IsEq,
+            BinaryOperation::Gte => Opcode::Gte,
+            BinaryOperation::Gt => Opcode::Gt,
+            BinaryOperation::Lte => Opcode::Lte,
+            BinaryOperation::Lt => Opcode::Lt,
+            BinaryOperation::Mod => Opcode::Mod,
+            BinaryOperation::Mul => Opcode::Mul,
+            BinaryOperation::MulWrapped => Opcode::MulWrapped,
+            BinaryOperation::Nand => Opcode::Nand,
+            BinaryOperation::Neq => Opcode::IsNeq,
+            BinaryOperation::Nor => Opcode::Nor,
+            BinaryOperation::Or => Opcode::Or,
+            BinaryOperation::BitwiseOr => Opcode::Or,
+            BinaryOperation::Pow => Opcode::Pow,
+            BinaryOperation::PowWrapped => Opcode::PowWrapped,
+            BinaryOperation::Rem => Opcode::Rem,
+            BinaryOperation::RemWrapped => Opcode::RemWrapped,
+            BinaryOperation::Shl => Opcode::Shl,
+            BinaryOperation::ShlWrapped => Opcode::ShlWrapped,
+            BinaryOperation::Shr => Opcode::Shr,
+            BinaryOperation::ShrWrapped => Opcode::ShrWrapped,
+            BinaryOperation::Sub => Opcode::Sub,
+            BinaryOperation::SubWrapped => Opcode::SubWrapped,
+            BinaryOperation::Xor => Opcode::Xor,
         };
 
-        let destination_register = format!("r{}", self.next_register);
-        let binary_instruction = format!("    {opcode} {left_operand} {right_operand} into {destination_register};\n",);
-
-        // Increment the register counter.
-        self.next_register += 1;
+        let destination = self.allocate_register();
+        let binary_instruction =
+            Instruction::Operation { opcode, operands: vec![left_operand, right_operand], destination, type_: None };
 
         // Concatenate the instructions.
         let mut instructions = left_instructions;
-        instructions.push_str(&right_instructions);
-        instructions.push_str(&binary_instruction);
+        instructions.extend(right_instructions);
+        instructions.push(binary_instruction);
 
-        (destination_register, instructions)
+        (destination.into(), instructions)
     }
 
-    fn visit_cast(&mut self, input: &'a CastExpression) -> (String, String) {
-        let (expression_operand, mut instructions) = self.visit_expression(&input.expression);
+    fn visit_cast(&mut self, input: &'a CastExpression) -> (Operand, Vec<Instruction>) {
+        let (expression_operand, mut instructions) = self.visit_operand(&input.expression);
 
         // Construct the destination register.
-        let destination_register = format!("r{}", self.next_register);
-        // Increment the register counter.
-        self.next_register += 1;
+        let destination = self.allocate_register();
 
-        let cast_instruction =
-            format!("    cast {expression_operand} into {destination_register} as {};\n", input.type_);
+        let opcode = match input.kind {
+            CastKind::Checked => Opcode::Cast,
+            CastKind::Lossy => Opcode::CastLossy,
+            CastKind::Saturating => unreachable!("Saturating casts are lowered during static single assignment."),
+        };
+
+        let cast_instruction = Instruction::Operation {
+            opcode,
+            operands: vec![expression_operand],
+            destination,
+            type_: Some(Self::visit_type(&input.type_)),
+        };
 
         // Concatenate the instructions.
-        instructions.push_str(&cast_instruction);
+        instructions.push(cast_instruction);
 
-        (destination_register, instructions)
+        (destination.into(), instructions)
     }
 
-    fn visit_array(&mut self, input: &'a ArrayExpression) -> (String, String) {
-        let (expression_operands, mut instructions) =
-            input.elements.iter().map(|expr| self.visit_expression(expr)).fold(
-                (String::new(), String::new()),
-                |(mut operands, mut instructions), (operand, operand_instructions)| {
-                    operands.push_str(&format!(" {operand}"));
-                    instructions.push_str(&operand_instructions);
-                    (operands, instructions)
-                },
-            );
+    fn visit_array(&mut self, input: &'a ArrayExpression) -> (Operand, Vec<Instruction>) {
+        let (expression_operands, mut instructions) = input.elements.iter().map(|expr| self.visit_operand(expr)).fold(
+            (Vec::new(), Vec::new()),
+            |(mut operands, mut instructions), (operand, operand_instructions)| {
+                operands.push(operand);
+                instructions.extend(operand_instructions);
+                (operands, instructions)
+            },
+        );
 
         // Construct the destination register.
-        let destination_register = format!("r{}", self.next_register);
-        // Increment the register counter.
-        self.next_register += 1;
+        let destination = self.allocate_register();
 
         // Get the array type.
         let array_type = match self.type_table.get(&input.id) {
             Some(Type::Array(array_type)) => Type::Array(array_type),
             _ => unreachable!("All types should be known at this phase of compilation"),
         };
-        let array_type: String = Self::visit_type(&array_type);
+        let array_type = Self::visit_type(&array_type);
 
-        let array_instruction =
-            format!("    cast {expression_operands} into {destination_register} as {};\n", array_type);
+        let array_instruction = Instruction::Operation {
+            opcode: Opcode::Cast,
+            operands: expression_operands,
+            destination,
+            type_: Some(array_type),
+        };
 
         // Concatenate the instructions.
-        instructions.push_str(&array_instruction);
+        instructions.push(array_instruction);
 
-        (destination_register, instructions)
+        (destination.into(), instructions)
     }
 
-    fn visit_unary(&mut self, input: &'a UnaryExpression) -> (String, String) {
-        let (expression_operand, expression_instructions) = self.visit_expression(&input.receiver);
-
-        // Note that non-empty suffixes must be preceded by a space.
-        let (opcode, suffix) = match input.op {
-            UnaryOperation::Abs => ("abs", ""),
-            UnaryOperation::AbsWrapped => ("abs.w", ""),
-            UnaryOperation::Double => ("double", ""),
-            UnaryOperation::Inverse => ("inv", ""),
-            UnaryOperation::Not => ("not", ""),
-            UnaryOperation::Negate => ("neg", ""),
-            UnaryOperation::Square => ("square", ""),
-            UnaryOperation::SquareRoot => ("sqrt", ""),
-            UnaryOperation::ToXCoordinate => ("cast", " as group.x"),
-            UnaryOperation::ToYCoordinate => ("cast", " as group.y"),
+    fn visit_unary(&mut self, input: &'a UnaryExpression) -> (Operand, Vec<Instruction>) {
+        let (expression_operand, expression_instructions) = self.visit_operand(&input.receiver);
+
+        let (opcode, type_) = match input.op {
+            UnaryOperation::Abs => (Opcode::Abs, None),
+            UnaryOperation::AbsWrapped => (Opcode::AbsWrapped, None),
+            UnaryOperation::Double => (Opcode::Double, None),
+            UnaryOperation::Inverse => (Opcode::Inv, None),
+            UnaryOperation::Not => (Opcode::Not, None),
+            UnaryOperation::Negate => (Opcode::Neg, None),
+            UnaryOperation::Square => (Opcode::Square, None),
+            UnaryOperation::SquareRoot => (Opcode::Sqrt, None),
+            UnaryOperation::ToXCoordinate => (Opcode::Cast, Some(aleo_ir::Type::GroupX)),
+            UnaryOperation::ToYCoordinate => (Opcode::Cast, Some(aleo_ir::Type::GroupY)),
         };
 
-        let destination_register = format!("r{}", self.next_register);
-        let unary_instruction = format!("    {opcode} {expression_operand} into {destination_register}{suffix};\n");
-
-        // Increment the register counter.
-        self.next_register += 1;
+        let destination = self.allocate_register();
+        let unary_instruction =
+            Instruction::Operation { opcode, operands: vec![expression_operand], destination, type_ };
 
         // Concatenate the instructions.
         let mut instructions = expression_instructions;
-        instructions.push_str(&unary_instruction);
+        instructions.push(unary_instruction);
 
-        (destination_register, instructions)
+        (destination.into(), instructions)
     }
 
-    fn visit_ternary(&mut self, input: &'a TernaryExpression) -> (String, String) {
-        let (condition_operand, condition_instructions) = self.visit_expression(&input.condition);
-        let (if_true_operand, if_true_instructions) = self.visit_expression(&input.if_true);
-        let (if_false_operand, if_false_instructions) = self.visit_expression(&input.if_false);
-
-        let destination_register = format!("r{}", self.next_register);
-        let ternary_instruction = format!(
-            "    ternary {condition_operand} {if_true_operand} {if_false_operand} into {destination_register};\n",
-        );
-
-        // Increment the register counter.
-        self.next_register += 1;
+    fn visit_ternary(&mut self, input: &'a TernaryExpression) -> (Operand, Vec<Instruction>) {
+        let (condition_operand, condition_instructions) = self.visit_operand(&input.condition);
+        let (if_true_operand, if_true_instructions) = self.visit_operand(&input.if_true);
+        let (if_false_operand, if_false_instructions) = self.visit_operand(&input.if_false);
+
+        let destination = self.allocate_register();
+        let ternary_instruction = Instruction::Operation {
+            opcode: Opcode::Ternary,
+            operands: vec![condition_operand, if_true_operand, if_false_operand],
+            destination,
+            type_: None,
+        };
 
         // Concatenate the instructions.
         let mut instructions = condition_instructions;
-        instructions.push_str(&if_true_instructions);
-        instructions.push_str(&if_false_instructions);
-        instructions.push_str(&ternary_instruction);
+        instructions.extend(if_true_instructions);
+        instructions.extend(if_false_instructions);
+        instructions.push(ternary_instruction);
 
-        (destination_register, instructions)
+        (destination.into(), instructions)
     }
 
-    fn visit_struct_init(&mut self, input: &'a StructExpression) -> (String, String) {
+    fn visit_struct_init(&mut self, input: &'a StructExpression) -> (Operand, Vec<Instruction>) {
         // Lookup struct or record.
-        let name = if let Some((is_record, type_)) = self.composite_mapping.get(&input.name.name) {
+        let type_ = if let Some((is_record, _)) = self.composite_mapping.get(&input.name.name) {
             if *is_record {
-                // record.private;
-                format!("{}.{type_}", input.name)
+                // foo.record;
+                aleo_ir::Type::Record(input.name.to_string())
             } else {
                 // foo; // no visibility for structs
-                input.name.to_string()
+                aleo_ir::Type::Struct(input.name.to_string())
             }
         } else {
             unreachable!("All composite types should be known at this phase of compilation")
         };
 
-        // Initialize instruction builder strings.
-        let mut instructions = String::new();
-        let mut struct_init_instruction = String::from("    cast ");
+        // Initialize storage for the instructions and operands.
+        let mut instructions = Vec::new();
+        let mut operands = Vec::with_capacity(input.members.len());
 
         // Visit each struct member and accumulate instructions from expressions.
         for member in input.members.iter() {
             let operand = if let Some(expr) = member.expression.as_ref() {
                 // Visit variable expression.
-                let (variable_operand, variable_instructions) = self.visit_expression(expr);
-                instructions.push_str(&variable_instructions);
+                let (variable_operand, variable_instructions) = self.visit_operand(expr);
+                instructions.extend(variable_instructions);
 
                 variable_operand
             } else {
                 // Push operand identifier.
                 let (ident_operand, ident_instructions) = self.visit_identifier(&member.identifier);
-                instructions.push_str(&ident_instructions);
+                instructions.extend(ident_instructions);
 
                 ident_operand
             };
 
-            // Push operand name to struct init instruction.
-            write!(struct_init_instruction, "{operand} ").expect("failed to write to string");
+            operands.push(operand);
         }
 
-        // Push destination register to struct init instruction.
-        let destination_register = format!("r{}", self.next_register);
-        writeln!(struct_init_instruction, "into {destination_register} as {name};",)
-            .expect("failed to write to string");
+        let destination = self.allocate_register();
+        instructions.push(Instruction::Operation { opcode: Opcode::Cast, operands, destination, type_: Some(type_) });
 
-        instructions.push_str(&struct_init_instruction);
-
-        // Increment the register counter.
-        self.next_register += 1;
-
-        (destination_register, instructions)
+        (destination.into(), instructions)
     }
 
-    fn visit_array_access(&mut self, input: &'a ArrayAccess) -> (String, String) {
-        let (array_operand, _) = self.visit_expression(&input.array);
+    fn visit_array_access(&mut self, input: &'a ArrayAccess) -> (Operand, Vec<Instruction>) {
+        let (array_operand, _) = self.visit_operand(&input.array);
         let index_operand = match input.index.as_ref() {
-            Expression::Literal(Literal::Integer(_, string, _, _)) => format!("{}u32", string),
+            Expression::Literal(Literal::Integer(_, string, _, _)) => {
+                Operand::Literal(aleo_ir::Literal::Integer(IntegerType::U32, string.clone()))
+            }
             _ => unreachable!("Array indices must be integer literals"),
         };
-        let array_access = format!("{}[{}]", array_operand, index_operand);
+        let array_access = Operand::Index(Box::new(array_operand), Box::new(index_operand));
 
-        (array_access, String::new())
+        (array_access, Vec::new())
     }
 
-    fn visit_member_access(&mut self, input: &'a MemberAccess) -> (String, String) {
-        let (inner_struct, _) = self.visit_expression(&input.inner);
-        let member_access = format!("{inner_struct}.{}", input.name);
+    fn visit_member_access(&mut self, input: &'a MemberAccess) -> (Operand, Vec<Instruction>) {
+        let (inner_struct, _) = self.visit_operand(&input.inner);
+        let member_access = Operand::Member(Box::new(inner_struct), input.name.to_string());
 
-        (member_access, String::new())
+        (member_access, Vec::new())
     }
 
     // group::GEN -> group::GEN
-    fn visit_associated_constant(&mut self, input: &'a AssociatedConstant) -> (String, String) {
-        (format!("{input}"), String::new())
+    fn visit_associated_constant(&mut self, input: &'a AssociatedConstant) -> (Operand, Vec<Instruction>) {
+        let constant = match &input.ty {
+            Type::Identifier(identifier) => CoreConstant::from_symbols(identifier.name, input.name.name),
+            _ => None,
+        };
+        match constant {
+            Some(CoreConstant::GroupGenerator) => (Operand::Literal(aleo_ir::Literal::GroupGenerator), Vec::new()),
+            None => unreachable!("Type checking guarantees that associated constants are core constants."),
+        }
     }
 
     // Pedersen64::hash() -> hash.ped64
-    fn visit_associated_function(&mut self, input: &'a AssociatedFunction) -> (String, String) {
-        let mut instructions = String::new();
+    // Returns no operand if the function does not produce a value, e.g. `Mapping::set`.
+    fn visit_associated_function(&mut self, input: &'a AssociatedFunction) -> (Option<Operand>, Vec<Instruction>) {
+        let mut instructions = Vec::new();
 
         // Visit each function argument and accumulate instructions from expressions.
         let arguments = input
             .arguments
             .iter()
             .map(|argument| {
-                let (arg_string, arg_instructions) = self.visit_expression(argument);
-                instructions.push_str(&arg_instructions);
-                arg_string
+                let (argument, argument_instructions) = self.visit_operand(argument);
+                instructions.extend(argument_instructions);
+                argument
             })
             .collect::<Vec<_>>();
 
+        // The type of the value that the function returns, if any.
+        let return_type = self.type_table.get(&input.id);
+
         // Helper function to get a destination register for a function call.
-        let mut get_destination_register = || {
-            let destination_register = format!("r{}", self.next_register);
-            self.next_register += 1;
-            destination_register
-        };
+        let mut get_destination_register = || self.allocate_register();
 
         // Helper function to construct the instruction associated with a simple function call.
         // This assumes that the function call has one output.
-        let mut construct_simple_function_call = |function: &Identifier, variant: &str, arguments: Vec<String>| {
-            // Split function into [opcode, return type] e.g. hash_to_field -> [hash, field]
-            let function_name = function.name.to_string();
-            let mut names = function_name.split("_to_");
-            let opcode = names.next().expect("failed to get opcode");
-            let return_type = names.next().expect("failed to get type");
-
-            let mut instruction = format!("    {opcode}.{variant}");
-            for argument in arguments {
-                write!(instruction, " {argument}").expect("failed to write to string");
-            }
-            let destination_register = get_destination_register();
-            writeln!(instruction, " into {destination_register} as {return_type};").expect("failed to write to string");
-            (destination_register, instruction)
-        };
+        let mut construct_simple_function_call =
+            |function: &Identifier, algorithm: Algorithm, arguments: Vec<Operand>| {
+                // Determine the opcode from the function, e.g. `hash.bhp256` for `hash_to_field`.
+                let opcode = match function.to_string().split("_to_").next() {
+                    Some("commit") => Opcode::Commit(algorithm),
+                    Some("hash") => Opcode::Hash(algorithm),
+                    _ => {
+                        unreachable!("The only associated methods of hash functions are `commit_to_*` and `hash_to_*`")
+                    }
+                };
+                let return_type = match &return_type {
+                    Some(type_) => Self::visit_type(type_),
+                    None => unreachable!("All types should be known at this phase of compilation"),
+                };
+
+                let destination = get_destination_register();
+                let instruction =
+                    Instruction::Operation { opcode, operands: arguments, destination, type_: Some(return_type) };
+                (Some(destination.into()), instruction)
+            };
 
         // Construct the instruction.
         let (destination, instruction) = match &input.ty {
             Type::Identifier(Identifier { name: sym::BHP256, .. }) => {
-                construct_simple_function_call(&input.name, "bhp256", arguments)
+                construct_simple_function_call(&input.name, Algorithm::Bhp256, arguments)
             }
             Type::Identifier(Identifier { name: sym::BHP512, .. }) => {
-                construct_simple_function_call(&input.name, "bhp512", arguments)
+                construct_simple_function_call(&input.name, Algorithm::Bhp512, arguments)
             }
             Type::Identifier(Identifier { name: sym::BHP768, .. }) => {
-                construct_simple_function_call(&input.name, "bhp768", arguments)
+                construct_simple_function_call(&input.name, Algorithm::Bhp768, arguments)
             }
             Type::Identifier(Identifier { name: sym::BHP1024, .. }) => {
-                construct_simple_function_call(&input.name, "bhp1024", arguments)
+                construct_simple_function_call(&input.name, Algorithm::Bhp1024, arguments)
             }
             Type::Identifier(Identifier { name: sym::Keccak256, .. }) => {
-                construct_simple_function_call(&input.name, "keccak256", arguments)
+                construct_simple_function_call(&input.name, Algorithm::Keccak256, arguments)
             }
             Type::Identifier(Identifier { name: sym::Keccak384, .. }) => {
-                construct_simple_function_call(&input.name, "keccak384", arguments)
+                construct_simple_function_call(&input.name, Algorithm::Keccak384, arguments)
             }
             Type::Identifier(Identifier { name: sym::Keccak512, .. }) => {
-                construct_simple_function_call(&input.name, "keccak512", arguments)
+                construct_simple_function_call(&input.name, Algorithm::Keccak512, arguments)
             }
             Type::Identifier(Identifier { name: sym::Pedersen64, .. }) => {
-                construct_simple_function_call(&input.name, "ped64", arguments)
+                construct_simple_function_call(&input.name, Algorithm::Ped64, arguments)
             }
             Type::Identifier(Identifier { name: sym::Pedersen128, .. }) => {
-                construct_simple_function_call(&input.name, "ped128", arguments)
+                construct_simple_function_call(&input.name, Algorithm::Ped128, arguments)
             }
             Type::Identifier(Identifier { name: sym::Poseidon2, .. }) => {
-                construct_simple_function_call(&input.name, "psd2", arguments)
+                construct_simple_function_call(&input.name, Algorithm::Psd2, arguments)
             }
             Type::Identifier(Identifier { name: sym::Poseidon4, .. }) => {
-                construct_simple_function_call(&input.name, "psd4", arguments)
+                construct_simple_function_call(&input.name, Algorithm::Psd4, arguments)
             }
             Type::Identifier(Identifier { name: sym::Poseidon8, .. }) => {
-                construct_simple_function_call(&input.name, "psd8", arguments)
+                construct_simple_function_call(&input.name, Algorithm::Psd8, arguments)
             }
             Type::Identifier(Identifier { name: sym::SHA3_256, .. }) => {
-                construct_simple_function_call(&input.name, "sha3_256", arguments)
+                construct_simple_function_call(&input.name, Algorithm::Sha3_256, arguments)
             }
             Type::Identifier(Identifier { name: sym::SHA3_384, .. }) => {
-                construct_simple_function_call(&input.name, "sha3_384", arguments)
+                construct_simple_function_call(&input.name, Algorithm::Sha3_384, arguments)
             }
             Type::Identifier(Identifier { name: sym::SHA3_512, .. }) => {
-                construct_simple_function_call(&input.name, "sha3_512", arguments)
+                construct_simple_function_call(&input.name, Algorithm::Sha3_512, arguments)
             }
-            Type::Identifier(Identifier { name: sym::Mapping, .. }) => match input.name.name {
-                sym::get => {
-                    let mut instruction = "    get".to_string();
-                    let destination_register = get_destination_register();
-                    // Write the mapping name and the key.
-                    writeln!(instruction, " {}[{}] into {destination_register};", arguments[0], arguments[1])
-                        .expect("failed to write to string");
-                    (destination_register, instruction)
-                }
-                sym::get_or_use => {
-                    let mut instruction = "    get.or_use".to_string();
-                    let destination_register = get_destination_register();
-                    // Write the mapping name, the key, and the default value.
-                    writeln!(
-                        instruction,
-                        " {}[{}] {} into {destination_register};",
-                        arguments[0], arguments[1], arguments[2]
-                    )
-                    .expect("failed to write to string");
-                    (destination_register, instruction)
-                }
-                sym::set => {
-                    let mut instruction = "    set".to_string();
-                    // Write the value, mapping name, and the key.
-                    writeln!(instruction, " {} into {}[{}];", arguments[2], arguments[0], arguments[1])
-                        .expect("failed to write to string");
-                    (String::new(), instruction)
-                }
-                sym::remove => {
-                    let mut instruction = "    remove".to_string();
-                    // Write the mapping name and the key.
-                    writeln!(instruction, " {}[{}];", arguments[0], arguments[1]).expect("failed to write to string");
-                    (String::new(), instruction)
-                }
-                sym::contains => {
-                    let mut instruction = "    contains".to_string();
-                    let destination_register = get_destination_register();
-                    // Write the mapping name and the key.
-                    writeln!(instruction, " {}[{}] into {destination_register};", arguments[0], arguments[1])
-                        .expect("failed to write to string");
-                    (destination_register, instruction)
-                }
-                _ => unreachable!("The only variants of Mapping are get, get_or, and set"),
-            },
-            Type::Identifier(Identifier { name: sym::group, .. }) => {
-                match input.name {
-                    Identifier { name: sym::to_x_coordinate, .. } => {
-                        let mut instruction = "    cast".to_string();
-                        let destination_register = get_destination_register();
-                        // Write the argument and the destination register.
-                        writeln!(instruction, " {} into {destination_register} as group.x;", arguments[0],)
-                            .expect("failed to write to string");
-                        (destination_register, instruction)
+            Type::Identifier(Identifier { name: sym::Mapping, .. }) => {
+                // The arguments are the mapping name, the key, and the value or default value, if any.
+                let mut arguments = arguments.into_iter();
+                let mapping = arguments.next().expect("failed to get mapping");
+                let key = arguments.next().expect("failed to get key");
+                match input.name.name {
+                    sym::get => {
+                        let destination = get_destination_register();
+                        (Some(destination.into()), Instruction::Get { mapping, key, destination })
                     }
-                    Identifier { name: sym::to_y_coordinate, .. } => {
-                        let mut instruction = "    cast".to_string();
-                        let destination_register = get_destination_register();
-                        // Write the argument and the destination register.
-                        writeln!(instruction, " {} into {destination_register} as group.y;", arguments[0],)
-                            .expect("failed to write to string");
-                        (destination_register, instruction)
+                    sym::get_or_use => {
+                        let default = arguments.next().expect("failed to get default value");
+                        let destination = get_destination_register();
+                        (Some(destination.into()), Instruction::GetOrUse { mapping, key, default, destination })
                     }
-                    _ => unreachable!("The only associated methods of group are to_x_coordinate and to_y_coordinate"),
+                    sym::set => {
+                        let value = arguments.next().expect("failed to get value");
+                        (None, Instruction::Set { mapping, key, value })
+                    }
+                    sym::remove => (None, Instruction::Remove { mapping, key }),
+                    sym::contains => {
+                        let destination = get_destination_register();
+                        (Some(destination.into()), Instruction::Contains { mapping, key, destination })
+                    }
+                    _ => unreachable!("The only variants of Mapping are get, get_or, and set"),
                 }
             }
+            Type::Identifier(Identifier { name: sym::group, .. }) => {
+                let type_ = match input.name {
+                    Identifier { name: sym::to_x_coordinate, .. } => aleo_ir::Type::GroupX,
+                    Identifier { name: sym::to_y_coordinate, .. } => aleo_ir::Type::GroupY,
+                    _ => unreachable!("The only associated methods of group are to_x_coordinate and to_y_coordinate"),
+                };
+                let destination = get_destination_register();
+                let instruction = Instruction::Operation {
+                    opcode: Opcode::Cast,
+                    operands: arguments,
+                    destination,
+                    type_: Some(type_),
+                };
+                (Some(destination.into()), instruction)
+            }
             Type::Identifier(Identifier { name: sym::ChaCha, .. }) => {
-                // Get the destination register.
-                let destination_register = get_destination_register();
-                // Construct the instruction template.
-                let mut instruction = format!("    rand.chacha into {destination_register} as ");
-                // Write the return type.
-                match input.name {
-                    Identifier { name: sym::rand_address, .. } => writeln!(instruction, "address;"),
-                    Identifier { name: sym::rand_bool, .. } => writeln!(instruction, "boolean;"),
-                    Identifier { name: sym::rand_field, .. } => writeln!(instruction, "field;"),
-                    Identifier { name: sym::rand_group, .. } => writeln!(instruction, "group;"),
-                    Identifier { name: sym::rand_i8, .. } => writeln!(instruction, "i8;"),
-                    Identifier { name: sym::rand_i16, .. } => writeln!(instruction, "i16;"),
-                    Identifier { name: sym::rand_i32, .. } => writeln!(instruction, "i32;"),
-                    Identifier { name: sym::rand_i64, .. } => writeln!(instruction, "i64;"),
-                    Identifier { name: sym::rand_i128, .. } => writeln!(instruction, "i128;"),
-                    Identifier { name: sym::rand_scalar, .. } => writeln!(instruction, "scalar;"),
-                    Identifier { name: sym::rand_u8, .. } => writeln!(instruction, "u8;"),
-                    Identifier { name: sym::rand_u16, .. } => writeln!(instruction, "u16;"),
-                    Identifier { name: sym::rand_u32, .. } => writeln!(instruction, "u32;"),
-                    Identifier { name: sym::rand_u64, .. } => writeln!(instruction, "u64;"),
-                    Identifier { name: sym::rand_u128, .. } => writeln!(instruction, "u128;"),
+                // Get the return type.
+                let type_ = match input.name {
+                    Identifier { name: sym::rand_address, .. } => aleo_ir::Type::Address,
+                    Identifier { name: sym::rand_bool, .. } => aleo_ir::Type::Boolean,
+                    Identifier { name: sym::rand_field, .. } => aleo_ir::Type::Field,
+                    Identifier { name: sym::rand_group, .. } => aleo_ir::Type::Group,
+                    Identifier { name: sym::rand_i8, .. } => aleo_ir::Type::Integer(IntegerType::I8),
+                    Identifier { name: sym::rand_i16, .. } => aleo_ir::Type::Integer(IntegerType::I16),
+                    Identifier { name: sym::rand_i32, .. } => aleo_ir::Type::Integer(IntegerType::I32),
+                    Identifier { name: sym::rand_i64, .. } => aleo_ir::Type::Integer(IntegerType::I64),
+                    Identifier { name: sym::rand_i128, .. } => aleo_ir::Type::Integer(IntegerType::I128),
+                    Identifier { name: sym::rand_scalar, .. } => aleo_ir::Type::Scalar,
+                    Identifier { name: sym::rand_u8, .. } => aleo_ir::Type::Integer(IntegerType::U8),
+                    Identifier { name: sym::rand_u16, .. } => aleo_ir::Type::Integer(IntegerType::U16),
+                    Identifier { name: sym::rand_u32, .. } => aleo_ir::Type::Integer(IntegerType::U32),
+                    Identifier { name: sym::rand_u64, .. } => aleo_ir::Type::Integer(IntegerType::U64),
+                    Identifier { name: sym::rand_u128, .. } => aleo_ir::Type::Integer(IntegerType::U128),
                     _ => unreachable!("The only associated methods of ChaCha are `rand_*`"),
-                }
-                .expect("failed to write to string");
-                (destination_register, instruction)
+                };
+                let destination = get_destination_register();
+                let instruction = Instruction::Operation {
+                    opcode: Opcode::RandChaCha,
+                    operands: Vec::new(),
+                    destination,
+                    type_: Some(type_),
+                };
+                (Some(destination.into()), instruction)
             }
             Type::Identifier(Identifier { name: sym::signature, .. }) => {
-                let mut instruction = "    sign.verify".to_string();
-                let destination_register = get_destination_register();
-                // Write the arguments and the destination register.
-                writeln!(
-                    instruction,
-                    " {} {} {} into {destination_register};",
-                    arguments[0], arguments[1], arguments[2]
-                )
-                .expect("failed to write to string");
-                (destination_register, instruction)
+                let destination = get_destination_register();
+                let instruction = Instruction::Operation {
+                    opcode: Opcode::SignVerify,
+                    operands: arguments,
+                    destination,
+                    type_: None,
+                };
+                (Some(destination.into()), instruction)
             }
             _ => unreachable!("All core functions should be known at this phase of compilation"),
         };
         // Add the instruction to the list of instructions.
-        instructions.push_str(&instruction);
+        instructions.push(instruction);
 
         (destination, instructions)
     }
 
-    fn visit_access(&mut self, input: &'a AccessExpression) -> (String, String) {
+    fn visit_access(&mut self, input: &'a AccessExpression) -> (Vec<Operand>, Vec<Instruction>) {
+        let single = |(operand, instructions): (Operand, Vec<Instruction>)| (vec![operand], instructions);
         match input {
-            AccessExpression::Array(array) => self.visit_array_access(array),
-            AccessExpression::Member(access) => self.visit_member_access(access),
-            AccessExpression::AssociatedConstant(constant) => self.visit_associated_constant(constant),
-            AccessExpression::AssociatedFunction(function) => self.visit_associated_function(function),
+            AccessExpression::Array(array) => single(self.visit_array_access(array)),
+            AccessExpression::Member(access) => single(self.visit_member_access(access)),
+            AccessExpression::AssociatedConstant(constant) => single(self.visit_associated_constant(constant)),
+            AccessExpression::AssociatedFunction(function) => {
+                let (operand, instructions) = self.visit_associated_function(function);
+                (operand.into_iter().collect(), instructions)
+            }
             AccessExpression::Tuple(_) => {
                 unreachable!("Tuple access should not be in the AST at this phase of compilation.")
             }
@@ -519,8 +529,8 @@ impl<'a> CodeGenerator<'a> {
     }
 
     // TODO: Cleanup
-    fn visit_call(&mut self, input: &'a CallExpression) -> (String, String) {
-        let (mut call_instruction, has_finalize) = match &input.external {
+    fn visit_call(&mut self, input: &'a CallExpression) -> (Vec<Operand>, Vec<Instruction>) {
+        let (callee, has_finalize) = match &input.external {
             Some(external) => {
                 // If the function is an external call, then check whether or not it has an associated finalize block.
                 // Extract the program name from the external call.
@@ -548,16 +558,17 @@ impl<'a> CodeGenerator<'a> {
                     Some((_, function)) => function.finalize.is_some(),
                     None => unreachable!("Type checking guarantees that imported functions are well defined."),
                 };
-                (format!("    call {external}.aleo/{}", input.function), has_finalize)
+                (format!("{external}.aleo/{}", input.function), has_finalize)
             }
-            None => (format!("    call {}", input.function), false),
+            None => (input.function.to_string(), false),
         };
-        let mut instructions = String::new();
+        let mut instructions = Vec::new();
+        let mut arguments = Vec::with_capacity(input.arguments.len());
 
         for argument in input.arguments.iter() {
-            let (argument, argument_instructions) = self.visit_expression(argument);
-            write!(call_instruction, " {argument}").expect("failed to write to string");
-            instructions.push_str(&argument_instructions);
+            let (argument, argument_instructions) = self.visit_operand(argument);
+            arguments.push(argument);
+            instructions.extend(argument_instructions);
         }
 
         // Lookup the function return type.
@@ -576,27 +587,21 @@ impl<'a> CodeGenerator<'a> {
                 0 | 1 => unreachable!("Parsing guarantees that a tuple type has at least two elements"),
                 len => {
                     for _ in 0..len {
-                        let destination_register = format!("r{}", self.next_register);
-                        destinations.push(destination_register);
-                        self.next_register += 1;
+                        destinations.push(self.allocate_register());
                     }
                 }
             },
-            _ => {
-                let destination_register = format!("r{}", self.next_register);
-                destinations.push(destination_register);
-                self.next_register += 1;
-            }
+            _ => destinations.push(self.allocate_register()),
         }
 
         // Construct the output operands. These are the destination registers **without** the future.
-        let output_operands = destinations.join(" ");
+        let output_operands: Vec<Operand> =
+            destinations.iter().map(|destination| Operand::Register(*destination)).collect();
 
         // If `has_finalize`, create another destination register for the future.
         if has_finalize {
             // Construct the future register.
-            let future_register = format!("r{}", self.next_register);
-            self.next_register += 1;
+            let future_register = self.allocate_register();
 
             // Construct the future type.
             let program_id = match input.external.as_deref() {
@@ -605,48 +610,37 @@ impl<'a> CodeGenerator<'a> {
             };
 
             // Add the futures register to the list of futures.
-            self.futures.push((future_register.clone(), format!("{program_id}.aleo/{function_name}")));
+            let future_type =
+                aleo_ir::Type::Future { program: program_id.to_string(), function: function_name.to_string() };
+            self.futures.push((future_register, future_type));
 
             // Add the future register to the list of destinations.
             destinations.push(future_register);
         }
 
-        // If destination registers were created, write them to the call instruction.
-        if !destinations.is_empty() {
-            write!(call_instruction, " into").expect("failed to write to string");
-            for destination in &destinations {
-                write!(call_instruction, " {}", destination).expect("failed to write to string");
-            }
-        }
-
-        // Write the closing semicolon.
-        writeln!(call_instruction, ";").expect("failed to write to string");
-
         // Push the call instruction to the list of instructions.
-        instructions.push_str(&call_instruction);
+        instructions.push(Instruction::Call { callee, arguments, destinations });
 
         // Return the output operands and the instructions.
         (output_operands, instructions)
     }
 
-    fn visit_tuple(&mut self, input: &'a TupleExpression) -> (String, String) {
-        // Need to return a single string here so we will join the tuple elements with ' '
-        // and split them after this method is called.
+    fn visit_tuple(&mut self, input: &'a TupleExpression) -> (Vec<Operand>, Vec<Instruction>) {
         let mut tuple_elements = Vec::with_capacity(input.elements.len());
-        let mut instructions = String::new();
+        let mut instructions = Vec::new();
 
         // Visit each tuple element and accumulate instructions from expressions.
         for element in input.elements.iter() {
             let (element, element_instructions) = self.visit_expression(element);
-            tuple_elements.push(element);
-            instructions.push_str(&element_instructions);
+            tuple_elements.extend(element);
+            instructions.extend(element_instructions);
         }
 
         // CAUTION: does not return the destination_register.
-        (tuple_elements.join(" "), instructions)
+        (tuple_elements, instructions)
     }
 
-    fn visit_unit(&mut self, _input: &'a UnitExpression) -> (String, String) {
+    fn visit_unit(&mut self, _input: &'a UnitExpression) -> (Vec<Operand>, Vec<Instruction>) {
         unreachable!("`UnitExpression`s should not be visited during code generation.")
     }
 }
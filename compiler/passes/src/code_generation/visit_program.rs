@@ -14,33 +14,24 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::CodeGenerator;
+use crate::{
+    aleo_ir::{self, CompositeKind, FunctionKind, Instruction, Operand, SpannedInstruction},
+    CodeGenerator,
+};
 
-use leo_ast::{functions, Function, Mapping, Mode, Program, ProgramScope, Struct, Type, Variant};
+use leo_ast::{functions, Function, Mapping, Mode, Node, Program, ProgramScope, Struct, Type, Variant};
 
 use indexmap::IndexMap;
-use itertools::Itertools;
 use leo_span::{sym, Symbol};
-use std::fmt::Write as _;
 
 impl<'a> CodeGenerator<'a> {
-    pub(crate) fn visit_program(&mut self, input: &'a Program) -> String {
-        // Accumulate instructions into a program string.
-        let mut program_string = String::new();
-
-        if !input.imports.is_empty() {
-            // Visit each import statement and produce a Aleo import instruction.
-            program_string.push_str(
-                &input
-                    .imports
-                    .iter()
-                    .map(|(identifier, (imported_program, _))| self.visit_import(identifier, imported_program))
-                    .join("\n"),
-            );
-
-            // Newline separator.
-            program_string.push('\n');
-        }
+    pub(crate) fn visit_program(&mut self, input: &'a Program) -> aleo_ir::Program {
+        // Visit each import statement and produce an Aleo import.
+        let imports = input
+            .imports
+            .iter()
+            .map(|(identifier, (imported_program, _))| self.visit_import(identifier, imported_program))
+            .collect();
 
         // Retrieve the program scope.
         // Note that type checking guarantees that there is exactly one program scope.
@@ -48,13 +39,6 @@ impl<'a> CodeGenerator<'a> {
 
         self.program_id = Some(program_scope.program_id);
 
-        // Print the program id.
-        writeln!(program_string, "program {};", program_scope.program_id)
-            .expect("Failed to write program id to string.");
-
-        // Newline separator.
-        program_string.push('\n');
-
         // Get the post-order ordering of the composite data types.
         // Note that the unwrap is safe since type checking guarantees that the struct dependency graph is acyclic.
         let order = self.struct_graph.post_order().unwrap();
@@ -64,132 +48,150 @@ impl<'a> CodeGenerator<'a> {
             program_scope.structs.iter().map(|(name, struct_)| (*name, struct_)).collect();
 
         // Visit each `Struct` or `Record` in the post-ordering and produce an Aleo struct or record.
-        program_string.push_str(
-            &order
-                .into_iter()
-                .map(|name| {
-                    match structs_map.get(&name) {
-                        // If the struct is found, it is a local struct.
-                        Some(struct_) => self.visit_struct_or_record(struct_),
-                        // If the struct is not found, it is an imported struct.
-                        None => String::new(),
-                    }
-                })
-                .join("\n"),
-        );
-
-        // Newline separator.
-        program_string.push('\n');
+        let composites = order
+            .into_iter()
+            .map(|name| {
+                match structs_map.get(&name) {
+                    // If the struct is found, it is a local struct.
+                    Some(struct_) => self.visit_struct_or_record(struct_),
+                    // If the struct is not found, it is an imported struct.
+                    None => aleo_ir::Composite {
+                        name: name.to_string(),
+                        kind: CompositeKind::Imported,
+                        members: Vec::new(),
+                    },
+                }
+            })
+            .collect();
 
         // Visit each mapping in the Leo AST and produce an Aleo mapping declaration.
-        program_string
-            .push_str(&program_scope.mappings.iter().map(|(_, mapping)| self.visit_mapping(mapping)).join("\n"));
+        let mappings = program_scope.mappings.iter().map(|(_, mapping)| self.visit_mapping(mapping)).collect();
 
         // Visit each function in the program scope and produce an Aleo function.
         // Note that in the function inlining pass, we reorder the functions such that they are in post-order.
         // In other words, a callee function precedes its caller function in the program scope.
-        program_string.push_str(
-            &program_scope
+        // Stubs only declare the interface of a program, so there are no functions to generate.
+        let functions = match program_scope.is_stub {
+            true => Vec::new(),
+            false => program_scope
                 .functions
                 .iter()
                 .map(|(_, function)| {
                     // Set the `is_transition_function` flag.
                     self.is_transition_function = matches!(function.variant, Variant::Transition);
 
-                    let function_string = self.visit_function(function);
+                    let aleo_function = self.visit_function(function);
 
                     // Unset the `is_transition_function` flag.
                     self.is_transition_function = false;
 
-                    function_string
+                    aleo_function
                 })
-                .join("\n"),
-        );
+                .collect(),
+        };
 
-        program_string
+        aleo_ir::Program { imports, id: program_scope.program_id.to_string(), composites, mappings, functions }
     }
 
     fn visit_import(&mut self, import_name: &'a Symbol, import_program: &'a Program) -> String {
         // Load symbols into composite mapping.
-        let _import_program_string = self.visit_program(import_program);
-        // todo: We do not need the import program string because we generate instructions for imports separately during leo build.
+        // Note that the imports of an imported program are unresolved, since the compiler stores every dependency as an import of the main program.
+        if !import_program.program_scopes.is_empty() {
+            let _import_program = self.visit_program(import_program);
+            // todo: We do not need the import program because we generate instructions for imports separately during leo build.
+        }
 
-        // Generate string for import statement.
-        format!("import {import_name}.aleo;")
+        import_name.to_string()
     }
 
-    fn visit_struct_or_record(&mut self, struct_: &'a Struct) -> String {
+    fn visit_struct_or_record(&mut self, struct_: &'a Struct) -> aleo_ir::Composite {
         if struct_.is_record { self.visit_record(struct_) } else { self.visit_struct(struct_) }
     }
 
-    fn visit_struct(&mut self, struct_: &'a Struct) -> String {
+    fn visit_struct(&mut self, struct_: &'a Struct) -> aleo_ir::Composite {
         // Add private symbol to composite types.
         self.composite_mapping.insert(&struct_.identifier.name, (false, String::from("private"))); // todo: private by default here.
 
-        let mut output_string = format!("struct {}:\n", struct_.identifier); // todo: check if this is safe from name conflicts.
-
-        // Construct and append the record variables.
-        for var in struct_.members.iter() {
-            writeln!(output_string, "    {} as {};", var.identifier, Self::visit_type(&var.type_),)
-                .expect("failed to write to string");
-        }
-
-        output_string
+        // Construct the struct members.
+        let members = struct_
+            .members
+            .iter()
+            .map(|var| aleo_ir::Member {
+                name: var.identifier.to_string(),
+                type_: aleo_ir::ValueType { type_: Self::visit_type(&var.type_), visibility: None },
+            })
+            .collect();
+
+        // todo: check if this is safe from name conflicts.
+        aleo_ir::Composite { name: struct_.identifier.to_string(), kind: CompositeKind::Struct, members }
     }
 
-    fn visit_record(&mut self, record: &'a Struct) -> String {
+    fn visit_record(&mut self, record: &'a Struct) -> aleo_ir::Composite {
         // Add record symbol to composite types.
-        let mut output_string = String::from("record");
-        self.composite_mapping.insert(&record.identifier.name, (true, output_string.clone()));
-        writeln!(output_string, " {}:", record.identifier).expect("failed to write to string"); // todo: check if this is safe from name conflicts.
-
-        // Construct and append the record variables.
-        for var in record.members.iter() {
-            let mode = match var.mode {
-                Mode::Constant => "constant",
-                Mode::Public => "public",
-                Mode::None | Mode::Private => "private",
-            };
-            writeln!(
-                output_string,
-                "    {} as {}.{mode};", // todo: CAUTION private record variables only.
-                var.identifier,
-                Self::visit_type(&var.type_)
-            )
-            .expect("failed to write to string");
-        }
-
-        output_string
+        self.composite_mapping.insert(&record.identifier.name, (true, String::from("record")));
+
+        // Construct the record variables.
+        let members = record
+            .members
+            .iter()
+            .map(|var| {
+                let visibility = match var.mode {
+                    Mode::Constant => aleo_ir::Visibility::Constant,
+                    Mode::Public => aleo_ir::Visibility::Public,
+                    Mode::None | Mode::Private => aleo_ir::Visibility::Private,
+                };
+                // todo: CAUTION private record variables only.
+                let type_ = aleo_ir::ValueType { type_: Self::visit_type(&var.type_), visibility: Some(visibility) };
+                aleo_ir::Member { name: var.identifier.to_string(), type_ }
+            })
+            .collect();
+
+        // todo: check if this is safe from name conflicts.
+        aleo_ir::Composite { name: record.identifier.to_string(), kind: CompositeKind::Record, members }
     }
 
-    fn visit_function(&mut self, function: &'a Function) -> String {
+    fn visit_function(&mut self, function: &'a Function) -> aleo_ir::Function {
         // Initialize the state of `self` with the appropriate values before visiting `function`.
         self.next_register = 0;
         self.variable_mapping = IndexMap::new();
         self.futures.clear();
+        self.outputs.clear();
+        self.names.clear();
         // TODO: Figure out a better way to initialize.
-        self.variable_mapping.insert(&sym::SelfLower, "self".to_string());
-        self.variable_mapping.insert(&sym::block, "block".to_string());
+        self.variable_mapping.insert(&sym::SelfLower, Operand::Identifier("self".to_string()));
+        self.variable_mapping.insert(&sym::block, Operand::Identifier("block".to_string()));
         self.current_function = Some(function);
 
-        // Construct the header of the function.
+        // Construct the function.
         // If a function is a program function, generate an Aleo `function`,
         // if it is a standard function generate an Aleo `closure`,
         // otherwise, it is an inline function, in which case a function should not be generated.
-        let mut function_string = match function.variant {
-            Variant::Transition => format!("\nfunction {}:\n", function.identifier),
-            Variant::Standard => format!("\nclosure {}:\n", function.identifier),
-            Variant::Inline => return String::from("\n"),
+        let mut aleo_function = aleo_ir::Function {
+            name: function.identifier.to_string(),
+            kind: match function.variant {
+                Variant::Transition => FunctionKind::Function,
+                Variant::Standard => FunctionKind::Closure,
+                Variant::Inline => FunctionKind::Inline,
+            },
+            inputs: Vec::new(),
+            instructions: Vec::new(),
+            outputs: Vec::new(),
+            finalize: None,
+            span: function.span,
+            names: IndexMap::new(),
         };
+        if aleo_function.kind == FunctionKind::Inline {
+            return aleo_function;
+        }
 
-        // Construct and append the input declarations of the function.
+        // Construct the input declarations of the function.
         for input in function.input.iter() {
-            let register_string = format!("r{}", self.next_register);
-            self.next_register += 1;
+            let register = self.allocate_register();
 
-            let type_string = match input {
+            let type_ = match input {
                 functions::Input::Internal(input) => {
-                    self.variable_mapping.insert(&input.identifier.name, register_string.clone());
+                    self.variable_mapping.insert(&input.identifier.name, register.into());
+                    self.record_name(&register.into(), input.identifier.name);
                     let visibility = match (self.is_transition_function, input.mode) {
                         (true, Mode::None) => Mode::Private,
                         _ => input.mode,
@@ -197,54 +199,62 @@ impl<'a> CodeGenerator<'a> {
                     self.visit_type_with_visibility(&input.type_, visibility)
                 }
                 functions::Input::External(input) => {
-                    self.variable_mapping.insert(&input.identifier.name, register_string.clone());
-                    format!("{}.aleo/{}.record", input.program_name, input.record)
+                    self.variable_mapping.insert(&input.identifier.name, register.into());
+                    self.record_name(&register.into(), input.identifier.name);
+                    Self::visit_external_record(input)
                 }
             };
 
-            writeln!(function_string, "    input {register_string} as {type_string};",)
-                .expect("failed to write to string");
+            aleo_function.inputs.push(aleo_ir::Input { register, type_, span: input.span() });
         }
 
-        //  Construct and append the function body.
-        let block_string = self.visit_block(&function.block);
-        function_string.push_str(&block_string);
+        //  Construct the function body.
+        aleo_function.instructions = self.visit_block(&function.block);
+        aleo_function.names = std::mem::take(&mut self.names);
+        aleo_function.outputs = std::mem::take(&mut self.outputs);
 
         // If the finalize block exists, generate the appropriate bytecode.
         if !self.futures.is_empty() || function.finalize.is_some() {
             // Clear the register count.
             self.next_register = 0;
+            self.next_label = 0;
             self.in_finalize = true;
 
             // Clear the variable mapping.
             // TODO: Figure out a better way to initialize.
             self.variable_mapping = IndexMap::new();
-            self.variable_mapping.insert(&sym::SelfLower, "self".to_string());
-            self.variable_mapping.insert(&sym::block, "block".to_string());
-
-            function_string.push_str(&format!("\nfinalize {}:\n", function.identifier));
+            self.variable_mapping.insert(&sym::SelfLower, Operand::Identifier("self".to_string()));
+            self.variable_mapping.insert(&sym::block, Operand::Identifier("block".to_string()));
+
+            let mut finalize = aleo_ir::Finalize {
+                name: function.identifier.to_string(),
+                inputs: Vec::new(),
+                instructions: Vec::new(),
+                outputs: Vec::new(),
+                span: function.finalize.as_ref().map_or(function.span, |finalize| finalize.span),
+                names: IndexMap::new(),
+            };
 
             // If the function contained calls that produced futures, then we need to add the futures to the finalize block as input.
             // Store the new future registers.
             let mut future_registers = Vec::new();
-            for (_, future_type) in self.futures.drain(..) {
-                let register_string = format!("r{}", self.next_register);
-                writeln!(function_string, "    input {register_string} as {future_type}.future;")
-                    .expect("failed to write to string");
-                future_registers.push(register_string);
-                self.next_register += 1;
+            for (_, future_type) in std::mem::take(&mut self.futures) {
+                let register = self.allocate_register();
+                let type_ = aleo_ir::ValueType { type_: future_type, visibility: None };
+                finalize.inputs.push(aleo_ir::Input { register, type_, span: function.span });
+                future_registers.push(register);
             }
 
             // Construct and append the input declarations of the finalize block, if it exists.
-            if let Some(finalize) = &function.finalize {
-                for input in finalize.input.iter() {
-                    let register_string = format!("r{}", self.next_register);
-                    self.next_register += 1;
+            if let Some(finalize_block) = &function.finalize {
+                for input in finalize_block.input.iter() {
+                    let register = self.allocate_register();
 
                     // TODO: Dedup code.
-                    let type_string = match input {
+                    let type_ = match input {
                         functions::Input::Internal(input) => {
-                            self.variable_mapping.insert(&input.identifier.name, register_string.clone());
+                            self.variable_mapping.insert(&input.identifier.name, register.into());
+                            self.record_name(&register.into(), input.identifier.name);
 
                             let visibility = match (self.is_transition_function, input.mode) {
                                 (true, Mode::None) => Mode::Public,
@@ -253,36 +263,38 @@ impl<'a> CodeGenerator<'a> {
                             self.visit_type_with_visibility(&input.type_, visibility)
                         }
                         functions::Input::External(input) => {
-                            self.variable_mapping.insert(&input.program_name.name, register_string.clone());
-                            format!("{}.aleo/{}.record", input.program_name, input.record)
+                            self.variable_mapping.insert(&input.program_name.name, register.into());
+                            self.record_name(&register.into(), input.identifier.name);
+                            Self::visit_external_record(input)
                         }
                     };
 
-                    writeln!(function_string, "    input {register_string} as {type_string};",)
-                        .expect("failed to write to string");
+                    finalize.inputs.push(aleo_ir::Input { register, type_, span: input.span() });
                 }
             }
 
             // Invoke `await` on each future.
-            for register in future_registers {
-                writeln!(function_string, "    await {register};").expect("failed to write to string");
-            }
+            finalize.instructions.extend(
+                future_registers
+                    .into_iter()
+                    .map(|register| SpannedInstruction::new(Instruction::Await(register.into()), function.span)),
+            );
 
-            // Construct and append the finalize block body, if it exists.
-            if let Some(finalize) = &function.finalize {
-                function_string.push_str(&self.visit_block(&finalize.block));
+            // Construct the finalize block body, if it exists.
+            if let Some(finalize_block) = &function.finalize {
+                finalize.instructions.extend(self.visit_block(&finalize_block.block));
             }
+            finalize.names = std::mem::take(&mut self.names);
+            finalize.outputs = std::mem::take(&mut self.outputs);
 
+            aleo_function.finalize = Some(finalize);
             self.in_finalize = false;
         }
 
-        function_string
+        aleo_function
     }
 
-    fn visit_mapping(&mut self, mapping: &'a Mapping) -> String {
-        // Create the prefix of the mapping string, e.g. `mapping foo:`.
-        let mut mapping_string = format!("\nmapping {}:\n", mapping.identifier);
-
+    fn visit_mapping(&mut self, mapping: &'a Mapping) -> aleo_ir::Mapping {
         // Helper to construct the string associated with the type.
         let create_type = |type_: &Type| {
             match type_ {
@@ -301,15 +313,16 @@ impl<'a> CodeGenerator<'a> {
             }
         };
 
-        // Create the key string, e.g. `    key as address.public`.
-        mapping_string.push_str(&format!("\tkey as {};\n", create_type(&mapping.key_type)));
-
-        // Create the value string, e.g. `    value as address.public`.
-        mapping_string.push_str(&format!("\tvalue as {};\n", create_type(&mapping.value_type)));
+        // Create the key and value types, e.g. `address.public`.
+        let aleo_mapping = aleo_ir::Mapping {
+            name: mapping.identifier.to_string(),
+            key_type: create_type(&mapping.key_type),
+            value_type: create_type(&mapping.value_type),
+        };
 
         // Add the mapping to the variable mapping.
-        self.global_mapping.insert(&mapping.identifier.name, mapping.identifier.to_string());
+        self.global_mapping.insert(&mapping.identifier.name, Operand::Identifier(mapping.identifier.to_string()));
 
-        mapping_string
+        aleo_mapping
     }
 }
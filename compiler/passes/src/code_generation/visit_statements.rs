@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::CodeGenerator;
+use crate::{
+    aleo_ir::{self, Instruction, Literal, Operand, SpannedInstruction},
+    CodeGenerator,
+};
 
 use leo_ast::{
     AssertStatement,
@@ -28,21 +31,22 @@ use leo_ast::{
     ExpressionStatement,
     IterationStatement,
     Mode,
+    Node,
     Output,
     ReturnStatement,
     Statement,
 };
 
 use itertools::Itertools;
-use std::fmt::Write as _;
 
 impl<'a> CodeGenerator<'a> {
-    fn visit_statement(&mut self, input: &'a Statement) -> String {
-        match input {
+    fn visit_statement(&mut self, input: &'a Statement) -> Vec<SpannedInstruction> {
+        let instructions = match input {
+            // Blocks and conditional statements attach the spans to their instructions themselves.
+            Statement::Block(stmt) => return self.visit_block(stmt),
+            Statement::Conditional(stmt) => return self.visit_conditional(stmt),
             Statement::Assert(stmt) => self.visit_assert(stmt),
             Statement::Assign(stmt) => self.visit_assign(stmt),
-            Statement::Block(stmt) => self.visit_block(stmt),
-            Statement::Conditional(stmt) => self.visit_conditional(stmt),
             Statement::Console(stmt) => self.visit_console(stmt),
             Statement::Const(_) => {
                 unreachable!("`ConstStatement`s should not be in the AST at this phase of compilation.")
@@ -51,41 +55,47 @@ impl<'a> CodeGenerator<'a> {
             Statement::Expression(stmt) => self.visit_expression_statement(stmt),
             Statement::Iteration(stmt) => self.visit_iteration(stmt),
             Statement::Return(stmt) => self.visit_return(stmt),
-        }
+        };
+
+        // The assignments introduced by earlier passes may not have a span, but the values that they assign do.
+        let span = match input {
+            Statement::Assign(stmt) if !stmt.value.span().is_dummy() => stmt.value.span(),
+            _ => input.span(),
+        };
+        instructions.into_iter().map(|instruction| SpannedInstruction::new(instruction, span)).collect()
     }
 
-    fn visit_assert(&mut self, input: &'a AssertStatement) -> String {
-        let mut generate_assert_instruction = |name: &str, left: &'a Expression, right: &'a Expression| {
-            let (left_operand, left_instructions) = self.visit_expression(left);
-            let (right_operand, right_instructions) = self.visit_expression(right);
-            let assert_instruction = format!("    {name} {left_operand} {right_operand};\n");
+    fn visit_assert(&mut self, input: &'a AssertStatement) -> Vec<Instruction> {
+        let mut generate_assert_instruction =
+            |instruction: fn(Operand, Operand) -> Instruction, left: &'a Expression, right: &'a Expression| {
+                let (left_operand, left_instructions) = self.visit_operand(left);
+                let (right_operand, right_instructions) = self.visit_operand(right);
 
-            // Concatenate the instructions.
-            let mut instructions = left_instructions;
-            instructions.push_str(&right_instructions);
-            instructions.push_str(&assert_instruction);
+                // Concatenate the instructions.
+                let mut instructions = left_instructions;
+                instructions.extend(right_instructions);
+                instructions.push(instruction(left_operand, right_operand));
 
-            instructions
-        };
+                instructions
+            };
         match &input.variant {
             AssertVariant::Assert(expr) => {
-                let (operand, mut instructions) = self.visit_expression(expr);
-                let assert_instruction = format!("    assert.eq {operand} true;\n");
+                let (operand, mut instructions) = self.visit_operand(expr);
 
-                instructions.push_str(&assert_instruction);
+                instructions.push(Instruction::AssertEq(operand, Operand::Literal(Literal::Boolean(true))));
                 instructions
             }
-            AssertVariant::AssertEq(left, right) => generate_assert_instruction("assert.eq", left, right),
-            AssertVariant::AssertNeq(left, right) => generate_assert_instruction("assert.neq", left, right),
+            AssertVariant::AssertEq(left, right) => generate_assert_instruction(Instruction::AssertEq, left, right),
+            AssertVariant::AssertNeq(left, right) => generate_assert_instruction(Instruction::AssertNeq, left, right),
         }
     }
 
-    fn visit_return(&mut self, input: &'a ReturnStatement) -> String {
-        let mut outputs = match input.expression {
+    fn visit_return(&mut self, input: &'a ReturnStatement) -> Vec<Instruction> {
+        let expression_instructions = match input.expression {
             // Skip empty return statements.
-            Expression::Unit(_) => String::new(),
+            Expression::Unit(_) => Vec::new(),
             _ => {
-                let (operand, mut expression_instructions) = self.visit_expression(&input.expression);
+                let (operands, expression_instructions) = self.visit_expression(&input.expression);
                 // Get the output type of the function.
                 let output = if self.in_finalize {
                     // Note that the first unwrap is safe, since `current_function` is set in `visit_function`.
@@ -94,13 +104,8 @@ impl<'a> CodeGenerator<'a> {
                     // Note that this unwrap is safe, since `current_function` is set in `visit_function`.
                     self.current_function.unwrap().output.iter()
                 };
-                // If the operand string is empty, initialize an empty vector.
-                let operand_strings = match operand.is_empty() {
-                    true => vec![],
-                    false => operand.split(' ').collect_vec(),
-                };
-                let instructions = operand_strings
-                    .iter()
+                let outputs = operands
+                    .into_iter()
                     .zip_eq(output)
                     .map(|(operand, output)| {
                         match output {
@@ -122,73 +127,68 @@ impl<'a> CodeGenerator<'a> {
                                     // Only program functions have visibilities associated with their outputs.
                                     Mode::None
                                 };
-                                format!(
-                                    "    output {} as {};\n",
+                                aleo_ir::Output {
                                     operand,
-                                    self.visit_type_with_visibility(&output.type_, visibility)
-                                )
+                                    type_: self.visit_type_with_visibility(&output.type_, visibility),
+                                }
                             }
                             Output::External(output) => {
-                                format!(
-                                    "    output {} as {}.aleo/{}.record;\n",
-                                    operand, output.program_name, output.record,
-                                )
+                                aleo_ir::Output { operand, type_: Self::visit_external_record(output) }
                             }
                         }
                     })
-                    .join("");
+                    .collect_vec();
 
-                expression_instructions.push_str(&instructions);
+                self.outputs.extend(outputs);
 
                 expression_instructions
             }
         };
 
         // Initialize storage for the instructions.
-        let mut instructions = String::new();
+        let mut instructions = Vec::new();
 
         // If there are any futures or if the return instruction has `finalize_arguments`, then
         // create an `async` instruction that uses them.
         if !self.futures.is_empty() || input.finalize_arguments.is_some() {
             // Note that this unwrap is safe, since `current_function` is set in `visit_function`.
             let function_id = self.current_function.unwrap().name();
-            let mut async_instruction = format!("    async {function_id}");
             // Add the futures to the async instruction.
-            for (future_register, _) in self.futures.iter() {
-                write!(async_instruction, " {}", future_register).expect("failed to write to string");
-            }
+            let mut arguments =
+                self.futures.iter().map(|(future_register, _)| Operand::Register(*future_register)).collect_vec();
             // Add the finalize arguments to the async instruction.
-            if let Some(arguments) = &input.finalize_arguments {
-                for argument in arguments.iter() {
-                    let (argument, argument_instructions) = self.visit_expression(argument);
-                    write!(async_instruction, " {argument}").expect("failed to write to string");
-                    instructions.push_str(&argument_instructions);
+            if let Some(finalize_arguments) = &input.finalize_arguments {
+                for argument in finalize_arguments.iter() {
+                    let (argument, argument_instructions) = self.visit_operand(argument);
+                    arguments.push(argument);
+                    instructions.extend(argument_instructions);
                 }
             }
-            // Write the destination register.
-            let destination_register = format!("r{}", self.next_register);
-            writeln!(async_instruction, " into {};", destination_register).expect("failed to write to string");
-            // Increment the register counter.
-            self.next_register += 1;
+            // Get the destination register.
+            let destination = self.allocate_register();
             // Add the async instruction to the instructions.
-            instructions.push_str(&async_instruction);
+            instructions.push(Instruction::Async { function: function_id.to_string(), arguments, destination });
 
             // Add the destination register to the outputs.
             let program_id = match self.program_id {
                 Some(program_id) => program_id,
                 None => unreachable!("`program_id` should be set in `visit_function`"),
             };
-            outputs
-                .push_str(&format!("    output {} as {}/{}.future;\n", destination_register, program_id, function_id));
+            let type_ =
+                aleo_ir::Type::Future { program: program_id.name.to_string(), function: function_id.to_string() };
+            self.outputs.push(aleo_ir::Output {
+                operand: destination.into(),
+                type_: aleo_ir::ValueType { type_, visibility: None },
+            });
         }
 
-        // Extend the instructions with the outputs.
-        instructions.push_str(&outputs);
+        // The instructions of the returned expression follow the `async` instruction.
+        instructions.extend(expression_instructions);
 
         instructions
     }
 
-    fn visit_definition(&mut self, _input: &'a DefinitionStatement) -> String {
+    fn visit_definition(&mut self, _input: &'a DefinitionStatement) -> Vec<Instruction> {
         // TODO: If SSA is made optional, then conditionally enable codegen for DefinitionStatement
         // let (operand, expression_instructions) = self.visit_expression(&input.value);
         // self.variable_mapping.insert(&input.variable_name.name, operand);
@@ -196,26 +196,26 @@ impl<'a> CodeGenerator<'a> {
         unreachable!("DefinitionStatement's should not exist in SSA form.")
     }
 
-    fn visit_expression_statement(&mut self, input: &'a ExpressionStatement) -> String {
+    fn visit_expression_statement(&mut self, input: &'a ExpressionStatement) -> Vec<Instruction> {
         self.visit_expression(&input.expression).1
     }
 
-    fn visit_assign(&mut self, input: &'a AssignStatement) -> String {
+    fn visit_assign(&mut self, input: &'a AssignStatement) -> Vec<Instruction> {
         match (&input.place, &input.value) {
             (Expression::Identifier(identifier), _) => {
-                let (operand, expression_instructions) = self.visit_expression(&input.value);
+                let (operand, expression_instructions) = self.visit_operand(&input.value);
+                self.record_name(&operand, identifier.name);
                 self.variable_mapping.insert(&identifier.name, operand);
                 expression_instructions
             }
             (Expression::Tuple(tuple), Expression::Call(_)) => {
-                let (operand, expression_instructions) = self.visit_expression(&input.value);
-                // Split out the destinations from the tuple.
-                let operands = operand.split(' ').collect::<Vec<_>>();
+                let (operands, expression_instructions) = self.visit_expression(&input.value);
                 // Add the destinations to the variable mapping.
                 tuple.elements.iter().zip_eq(operands).for_each(|(element, operand)| {
                     match element {
                         Expression::Identifier(identifier) => {
-                            self.variable_mapping.insert(&identifier.name, operand.to_string())
+                            self.record_name(&operand, identifier.name);
+                            self.variable_mapping.insert(&identifier.name, operand)
                         }
                         _ => {
                             unreachable!("Type checking ensures that tuple elements on the lhs are always identifiers.")
@@ -230,22 +230,68 @@ impl<'a> CodeGenerator<'a> {
         }
     }
 
-    fn visit_conditional(&mut self, _input: &'a ConditionalStatement) -> String {
-        // TODO: Once SSA is made optional, create a Leo error informing the user to enable the SSA pass.
-        unreachable!("`ConditionalStatement`s should not be in the AST at this phase of compilation.")
+    /// Conditional statements in finalize blocks are compiled to branches, so that only the taken path executes.
+    /// For example, `if c { .. } else { .. }` is compiled to the following:
+    /// ```text
+    /// branch.eq c false to end_then_0;
+    /// ..
+    /// branch.eq true true to end_otherwise_0;
+    /// position end_then_0;
+    /// ..
+    /// position end_otherwise_0;
+    /// ```
+    fn visit_conditional(&mut self, input: &'a ConditionalStatement) -> Vec<SpannedInstruction> {
+        // Outside of finalize blocks, conditional statements are removed by the flattening pass.
+        if !self.in_finalize {
+            unreachable!("`ConditionalStatement`s should not be in the AST at this phase of compilation.")
+        }
+
+        let label = self.next_label;
+        self.next_label += 1;
+        let end_then = format!("end_then_{label}");
+        let end_otherwise = format!("end_otherwise_{label}");
+
+        // The branches and positions are generated from the conditional statement itself.
+        let spanned = |instruction| SpannedInstruction::new(instruction, input.span);
+
+        // Skip the `then` block if the condition is false.
+        let (condition, condition_instructions) = self.visit_operand(&input.condition);
+        let mut instructions = condition_instructions.into_iter().map(spanned).collect::<Vec<_>>();
+        instructions.push(spanned(Instruction::BranchEq {
+            first: condition,
+            second: Operand::Literal(Literal::Boolean(false)),
+            position: end_then.clone(),
+        }));
+        instructions.extend(self.visit_block(&input.then));
+
+        match &input.otherwise {
+            Some(otherwise) => {
+                // Skip the `otherwise` block at the end of the `then` block.
+                instructions.push(spanned(Instruction::BranchEq {
+                    first: Operand::Literal(Literal::Boolean(true)),
+                    second: Operand::Literal(Literal::Boolean(true)),
+                    position: end_otherwise.clone(),
+                }));
+                instructions.push(spanned(Instruction::Position(end_then)));
+                instructions.extend(self.visit_statement(otherwise));
+                instructions.push(spanned(Instruction::Position(end_otherwise)));
+            }
+            None => instructions.push(spanned(Instruction::Position(end_then))),
+        }
+
+        instructions
     }
 
-    fn visit_iteration(&mut self, _input: &'a IterationStatement) -> String {
-        // TODO: Once loop unrolling is made optional, create a Leo error informing the user to enable the loop unrolling pass..
+    fn visit_iteration(&mut self, _input: &'a IterationStatement) -> Vec<Instruction> {
         unreachable!("`IterationStatement`s should not be in the AST at this phase of compilation.");
     }
 
-    fn visit_console(&mut self, _: &'a ConsoleStatement) -> String {
+    fn visit_console(&mut self, _: &'a ConsoleStatement) -> Vec<Instruction> {
         unreachable!("Parsing guarantees that `ConsoleStatement`s are not present in the AST.")
     }
 
-    pub(crate) fn visit_block(&mut self, input: &'a Block) -> String {
+    pub(crate) fn visit_block(&mut self, input: &'a Block) -> Vec<SpannedInstruction> {
         // For each statement in the block, visit it and add its instructions to the list.
-        input.statements.iter().map(|stmt| self.visit_statement(stmt)).join("")
+        input.statements.iter().flat_map(|stmt| self.visit_statement(stmt)).collect()
     }
 }
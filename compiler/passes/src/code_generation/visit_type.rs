@@ -14,24 +14,24 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::CodeGenerator;
+use crate::{aleo_ir, CodeGenerator};
 
-use leo_ast::{Mode, Type};
+use leo_ast::{External, Mode, Type};
 
 impl<'a> CodeGenerator<'a> {
-    pub(crate) fn visit_type(input: &Type) -> String {
+    pub(crate) fn visit_type(input: &Type) -> aleo_ir::Type {
         match input {
-            Type::Address
-            | Type::Boolean
-            | Type::Field
-            | Type::Group
-            | Type::Scalar
-            | Type::Signature
-            | Type::String
-            | Type::Identifier(..)
-            | Type::Integer(..) => format!("{input}"),
+            Type::Address => aleo_ir::Type::Address,
+            Type::Boolean => aleo_ir::Type::Boolean,
+            Type::Field => aleo_ir::Type::Field,
+            Type::Group => aleo_ir::Type::Group,
+            Type::Scalar => aleo_ir::Type::Scalar,
+            Type::Signature => aleo_ir::Type::Signature,
+            Type::String => aleo_ir::Type::String,
+            Type::Identifier(identifier) => aleo_ir::Type::Struct(identifier.to_string()),
+            Type::Integer(type_) => aleo_ir::Type::Integer(*type_),
             Type::Array(array_type) => {
-                format!("[{}; {}u32]", Self::visit_type(array_type.element_type()), array_type.length())
+                aleo_ir::Type::Array(Box::new(Self::visit_type(array_type.element_type())), array_type.length() as u32)
             }
             Type::Mapping(_) => {
                 unreachable!("Mapping types are not supported at this phase of compilation")
@@ -44,17 +44,31 @@ impl<'a> CodeGenerator<'a> {
         }
     }
 
-    pub(crate) fn visit_type_with_visibility(&self, type_: &'a Type, visibility: Mode) -> String {
+    pub(crate) fn visit_type_with_visibility(&self, type_: &'a Type, visibility: Mode) -> aleo_ir::ValueType {
         match type_ {
             // When the type is a record.
             // Note that this unwrap is safe because all composite types have been added to the mapping.
             Type::Identifier(identifier) if self.composite_mapping.get(&identifier.name).unwrap().0 => {
-                format!("{identifier}.record")
+                aleo_ir::ValueType { type_: aleo_ir::Type::Record(identifier.to_string()), visibility: None }
             }
-            _ => match visibility {
-                Mode::None => Self::visit_type(type_),
-                _ => format!("{}.{visibility}", Self::visit_type(type_)),
-            },
+            _ => aleo_ir::ValueType { type_: Self::visit_type(type_), visibility: Self::visit_mode(visibility) },
+        }
+    }
+
+    /// Returns the type of a record of an imported program, e.g. `credits.aleo/credits.record`.
+    pub(crate) fn visit_external_record(input: &External) -> aleo_ir::ValueType {
+        let type_ =
+            aleo_ir::Type::ExternalRecord { program: input.program_name.to_string(), record: input.record.to_string() };
+        aleo_ir::ValueType { type_, visibility: None }
+    }
+
+    /// Returns the visibility of a value declared with `mode`, if any.
+    pub(crate) fn visit_mode(mode: Mode) -> Option<aleo_ir::Visibility> {
+        match mode {
+            Mode::None => None,
+            Mode::Constant => Some(aleo_ir::Visibility::Constant),
+            Mode::Public => Some(aleo_ir::Visibility::Public),
+            Mode::Private => Some(aleo_ir::Visibility::Private),
         }
     }
 }
@@ -42,7 +42,7 @@ pub struct FunctionSymbol {
     /// The `Span` associated with the function.
     pub(crate) _span: Span,
     /// The inputs to the function.
-    pub(crate) input: Vec<Input>,
+    pub input: Vec<Input>,
     /// Metadata associated with the finalize block.
     pub(crate) finalize: Option<FinalizeData>,
 }
@@ -37,4 +37,14 @@ impl TypeTable {
     pub fn insert(&self, index: NodeID, value: Type) {
         self.inner.borrow_mut().insert(index, value);
     }
+
+    /// Returns the number of entries in the table.
+    pub fn len(&self) -> usize {
+        self.inner.borrow().len()
+    }
+
+    /// Returns whether the table is empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.borrow().is_empty()
+    }
 }
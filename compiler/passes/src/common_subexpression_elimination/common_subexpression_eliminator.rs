@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeTable;
+
+use leo_ast::{AccessExpression, CoreFunction, Expression, NodeBuilder, Type};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+pub struct CommonSubexpressionEliminator<'a> {
+    /// A counter to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+    /// A mapping from each pure expression in the current function body to the variable that first holds its value.
+    /// The expressions are keyed by their Leo code, which does not depend on their spans or node IDs.
+    pub(crate) expressions: IndexMap<String, Symbol>,
+    /// A mapping from the variables in the current function body that hold a copy of another variable to that variable.
+    pub(crate) copies: IndexMap<Symbol, Symbol>,
+}
+
+impl<'a> CommonSubexpressionEliminator<'a> {
+    /// Initializes a new `CommonSubexpressionEliminator`.
+    pub fn new(node_builder: &'a NodeBuilder, type_table: &'a TypeTable) -> Self {
+        Self { node_builder, type_table, expressions: Default::default(), copies: Default::default() }
+    }
+
+    /// Returns whether evaluating `expression` has no side effects, and always produces the same value.
+    /// Note that a pure expression may still halt, e.g. on overflow, in which case every copy of it halts.
+    pub(crate) fn is_pure(expression: &Expression) -> bool {
+        match expression {
+            Expression::Access(AccessExpression::AssociatedFunction(function)) => match &function.ty {
+                Type::Identifier(identifier) => CoreFunction::from_symbols(identifier.name, function.name.name)
+                    .map_or(false, |core_function| !core_function.is_finalize_command()),
+                _ => false,
+            },
+            Expression::Access(AccessExpression::AssociatedConstant(_)) => false,
+            Expression::Access(_)
+            | Expression::Array(_)
+            | Expression::Binary(_)
+            | Expression::Cast(_)
+            | Expression::Ternary(_)
+            | Expression::Unary(_) => true,
+            // Records must be constructed separately, so struct initializers are not deduplicated.
+            Expression::Call(_)
+            | Expression::Struct(_)
+            | Expression::Err(_)
+            | Expression::Identifier(_)
+            | Expression::Literal(_)
+            | Expression::Tuple(_)
+            | Expression::Unit(_) => false,
+        }
+    }
+
+    /// Clears the state of the eliminator, before a function or finalize body is traversed.
+    pub(crate) fn clear(&mut self) {
+        self.expressions.clear();
+        self.copies.clear();
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::CommonSubexpressionEliminator;
+
+use leo_ast::{
+    AssignStatement,
+    ConditionalStatement,
+    ConsoleStatement,
+    DefinitionStatement,
+    Expression,
+    ExpressionReconstructor,
+    Identifier,
+    IterationStatement,
+    Node,
+    Statement,
+    StatementReconstructor,
+};
+
+impl StatementReconstructor for CommonSubexpressionEliminator<'_> {
+    /// Reconstructs an assignment statement, replacing its value with a variable if the value was already computed.
+    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
+        let value = self.reconstruct_expression(input.value).0;
+
+        let value = match (&input.place, value) {
+            // Record the copies, so that later uses of the place are replaced with the variable it copies.
+            (Expression::Identifier(place), Expression::Identifier(variable)) => {
+                self.copies.insert(place.name, variable.name);
+                Expression::Identifier(variable)
+            }
+            (Expression::Identifier(place), value) if Self::is_pure(&value) => {
+                let key = value.to_string();
+                match self.expressions.get(&key).copied() {
+                    // If the value was already computed, then the place is a copy of the variable that holds it.
+                    Some(name) => {
+                        self.copies.insert(place.name, name);
+                        let identifier = Identifier { name, span: value.span(), id: self.node_builder.next_id() };
+                        if let Some(type_) = self.type_table.get(&value.id()) {
+                            self.type_table.insert(identifier.id, type_);
+                        }
+                        Expression::Identifier(identifier)
+                    }
+                    // Otherwise, the place holds the first computation of the value.
+                    None => {
+                        self.expressions.insert(key, place.name);
+                        value
+                    }
+                }
+            }
+            (_, value) => value,
+        };
+
+        (
+            Statement::Assign(Box::new(AssignStatement { place: input.place, value, span: input.span, id: input.id })),
+            Default::default(),
+        )
+    }
+
+    /// Reconstructs the blocks of a conditional statement.
+    /// Flattening only keeps the conditional statements in finalize blocks that are compiled to branches.
+    /// The values computed in a block are only available within it, since the other block may be taken instead.
+    fn reconstruct_conditional(&mut self, input: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
+        let condition = self.reconstruct_expression(input.condition).0;
+        let (expressions, copies) = (self.expressions.clone(), self.copies.clone());
+
+        let then = self.reconstruct_block(input.then).0;
+        (self.expressions, self.copies) = (expressions.clone(), copies.clone());
+
+        let otherwise = input.otherwise.map(|statement| Box::new(self.reconstruct_statement(*statement).0));
+        (self.expressions, self.copies) = (expressions, copies);
+
+        (
+            Statement::Conditional(ConditionalStatement { condition, then, otherwise, span: input.span, id: input.id }),
+            Default::default(),
+        )
+    }
+
+    /// Parsing guarantees that console statements are not present in the program.
+    fn reconstruct_console(&mut self, _: ConsoleStatement) -> (Statement, Self::AdditionalOutput) {
+        unreachable!("`ConsoleStatement`s should not be in the AST at this phase of compilation.")
+    }
+
+    /// Static single assignment replaces definition statements with assignment statements.
+    fn reconstruct_definition(&mut self, _: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        unreachable!("`DefinitionStatement`s should not exist in the AST at this phase of compilation.")
+    }
+
+    /// Loop unrolling unrolls and removes iteration statements from the program.
+    fn reconstruct_iteration(&mut self, _: IterationStatement) -> (Statement, Self::AdditionalOutput) {
+        unreachable!("`IterationStatement`s should not be in the AST at this phase of compilation.");
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Common Subexpression Elimination pass traverses the AST and replaces repeated computations of the same value,
+//! within the boundary of `transition`s and `function`s, with the variable that holds the first computation.
+//! The pass is run after the Function Inlining pass and before the Dead Code Elimination pass, which removes the
+//! assignments that are no longer used.
+//!
+//! See https://en.wikipedia.org/wiki/Common_subexpression_elimination for more information.
+//!
+//! Consider the following flattened Leo code.
+//! ```leo
+//! function main(a: Point, b: Point, flag: bool) -> field {
+//!     $var$0 = a.x + b.x;
+//!     $var$1 = a.x + b.x;
+//!     $var$2 = BHP256::hash_to_field($var$1);
+//!     $var$3 = flag ? $var$0 : $var$2;
+//!     $var$4 = BHP256::hash_to_field($var$0);
+//!     $var$5 = $var$3 + $var$4;
+//!     return $var$5;
+//! }
+//! ```
+//!
+//! The common subexpression elimination pass produces the following code.
+//! ```leo
+//! function main(a: Point, b: Point, flag: bool) -> field {
+//!     $var$0 = a.x + b.x;
+//!     $var$1 = $var$0;
+//!     $var$2 = BHP256::hash_to_field($var$0);
+//!     $var$3 = flag ? $var$0 : $var$2;
+//!     $var$4 = $var$2;
+//!     $var$5 = $var$3 + $var$2;
+//!     return $var$5;
+//! }
+//! ```
+//! Only pure expressions are replaced, i.e. operators, casts, ternaries, accesses, and the core functions that are not
+//! finalize commands. Calls, struct and record initializers, and commands such as `Mapping::get` are left unchanged.
+//!
+//! Note this pass relies on the following invariants:
+//! - No shadowing for all variables, struct names, function names, etc.
+//! - Unique variable names (provided by SSA)
+//! - Flattened code (provided by the flattening pass)
+
+mod eliminate_expression;
+
+mod eliminate_statement;
+
+mod eliminate_program;
+
+pub mod common_subexpression_eliminator;
+pub use common_subexpression_eliminator::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for CommonSubexpressionEliminator<'a> {
+    type Input = (Ast, &'a NodeBuilder, &'a TypeTable);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, node_builder, type_table): Self::Input) -> Self::Output {
+        let mut reconstructor = CommonSubexpressionEliminator::new(node_builder, type_table);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
@@ -116,9 +116,27 @@ impl StatementReconstructor for DeadCodeEliminator<'_> {
         (Block { statements, span: block.span, id: block.id }, Default::default())
     }
 
-    /// Flattening removes conditional statements from the program.
-    fn reconstruct_conditional(&mut self, _: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
-        unreachable!("`ConditionalStatement`s should not be in the AST at this phase of compilation.")
+    /// Reconstructs a conditional statement by eliminating any dead code in its blocks.
+    /// Flattening only keeps the conditional statements in finalize blocks that are compiled to branches.
+    /// Note that the variables assigned in the blocks are not used after the statement.
+    fn reconstruct_conditional(&mut self, input: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
+        // Reconstruct the blocks.
+        let then = self.reconstruct_block(input.then).0;
+        let otherwise = input.otherwise.map(|statement| Box::new(self.reconstruct_statement(*statement).0));
+
+        // Set the `is_necessary` flag, since the condition is used by the branch.
+        self.is_necessary = true;
+
+        // Visit the condition.
+        let condition = self.reconstruct_expression(input.condition).0;
+
+        // Unset the `is_necessary` flag.
+        self.is_necessary = false;
+
+        (
+            Statement::Conditional(ConditionalStatement { condition, then, otherwise, span: input.span, id: input.id }),
+            Default::default(),
+        )
     }
 
     /// Parsing guarantees that console statements are not present in the program.
@@ -220,8 +220,21 @@ impl StatementReconstructor for Destructurer<'_> {
         (Block { span: block.span, statements, id: self.node_builder.next_id() }, Default::default())
     }
 
-    fn reconstruct_conditional(&mut self, _: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
-        unreachable!("`ConditionalStatement`s should not be in the AST at this phase of compilation.")
+    /// Reconstructs the blocks of a conditional statement.
+    /// Flattening only keeps the conditional statements in finalize blocks that are compiled to branches.
+    fn reconstruct_conditional(&mut self, input: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
+        let then = self.reconstruct_block(input.then).0;
+        let otherwise = input.otherwise.map(|statement| Box::new(self.reconstruct_statement(*statement).0));
+        (
+            Statement::Conditional(ConditionalStatement {
+                condition: input.condition,
+                then,
+                otherwise,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
     }
 
     fn reconstruct_console(&mut self, _: ConsoleStatement) -> (Statement, Self::AdditionalOutput) {
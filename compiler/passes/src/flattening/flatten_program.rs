@@ -16,7 +16,9 @@
 
 use crate::Flattener;
 
-use leo_ast::{Finalize, Function, ProgramReconstructor, StatementReconstructor};
+use crate::UsageCounter;
+
+use leo_ast::{Finalize, Function, ProgramReconstructor, StatementReconstructor, StatementVisitor};
 
 impl ProgramReconstructor for Flattener<'_> {
     /// Flattens a function's body and finalize block, if it exists.
@@ -24,8 +26,16 @@ impl ProgramReconstructor for Flattener<'_> {
         // First, flatten the finalize block. This allows us to initialize self.finalizes correctly.
         // Note that this is safe since the finalize block is independent of the function body.
         let finalize = function.finalize.map(|finalize| {
+            // Count the uses of each variable, which determine the conditional statements that are compiled to branches.
+            if self.finalize_branching {
+                let mut counter = UsageCounter::default();
+                counter.visit_block(&finalize.block);
+                self.finalize_uses = Some(counter.uses);
+            }
+
             // Flatten the finalize block.
             let mut block = self.reconstruct_block(finalize.block).0;
+            self.finalize_uses = None;
 
             // Get all of the guards and return expression.
             let returns = self.clear_early_returns();
@@ -219,7 +219,26 @@ impl StatementReconstructor for Flattener<'_> {
     }
 
     /// Flatten a conditional statement into a list of statements.
+    /// A conditional statement that is compiled to a branch is kept, and only its blocks are flattened.
     fn reconstruct_conditional(&mut self, conditional: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
+        if self.is_branch(&conditional) {
+            let then = self.reconstruct_block(conditional.then).0;
+            let otherwise = conditional.otherwise.map(|statement| match *statement {
+                Statement::Block(block) => Box::new(Statement::Block(self.reconstruct_block(block).0)),
+                _ => unreachable!("SSA guarantees that the `otherwise` is always a `Block`"),
+            });
+            return (
+                Statement::Conditional(ConditionalStatement {
+                    condition: conditional.condition,
+                    then,
+                    otherwise,
+                    span: conditional.span,
+                    id: conditional.id,
+                }),
+                Default::default(),
+            );
+        }
+
         let mut statements = Vec::with_capacity(conditional.then.statements.len());
 
         // Add condition to the condition stack.
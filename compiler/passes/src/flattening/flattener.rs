@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Assigner, SymbolTable, TypeTable};
+use crate::{Assigner, SymbolTable, TypeTable, UsageCounter};
 
 use leo_ast::{
     AccessExpression,
@@ -24,6 +24,7 @@ use leo_ast::{
     BinaryExpression,
     BinaryOperation,
     Block,
+    ConditionalStatement,
     Expression,
     ExpressionReconstructor,
     Identifier,
@@ -36,6 +37,7 @@ use leo_ast::{
     NonNegativeNumber,
     ReturnStatement,
     Statement,
+    StatementVisitor,
     Struct,
     StructExpression,
     StructVariableInitializer,
@@ -46,6 +48,9 @@ use leo_ast::{
     Type,
     UnitExpression,
 };
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
 
 pub struct Flattener<'a> {
     /// The symbol table associated with the program.
@@ -63,6 +68,10 @@ pub struct Flattener<'a> {
     /// Note that returns are inserted in the order they are encountered during a pre-order traversal of the AST.
     /// Note that type checking guarantees that there is at most one return in a basic block.
     pub(crate) returns: Vec<(Option<Expression>, ReturnStatement)>,
+    /// Whether the conditional statements in finalize blocks may be compiled to branches, instead of being flattened.
+    pub(crate) finalize_branching: bool,
+    /// The number of uses of each variable in the current finalize block, if its conditional statements may be compiled to branches.
+    pub(crate) finalize_uses: Option<IndexMap<Symbol, usize>>,
 }
 
 impl<'a> Flattener<'a> {
@@ -71,8 +80,37 @@ impl<'a> Flattener<'a> {
         type_table: &'a TypeTable,
         node_builder: &'a NodeBuilder,
         assigner: &'a Assigner,
+        finalize_branching: bool,
     ) -> Self {
-        Self { symbol_table, type_table, node_builder, assigner, condition_stack: Vec::new(), returns: Vec::new() }
+        Self {
+            symbol_table,
+            type_table,
+            node_builder,
+            assigner,
+            condition_stack: Vec::new(),
+            returns: Vec::new(),
+            finalize_branching,
+            finalize_uses: None,
+        }
+    }
+
+    /// Returns whether a conditional statement is kept, to be compiled to a branch, instead of being flattened.
+    /// This is the case for a conditional statement in a finalize block that is not nested in a flattened one,
+    /// if none of the variables assigned in its blocks are used after it, and if its blocks do not return.
+    /// Note that by SSA, a variable assigned in a block is only used after the block by the phi functions that follow it.
+    pub(crate) fn is_branch(&self, conditional: &ConditionalStatement) -> bool {
+        match &self.finalize_uses {
+            Some(finalize_uses) if self.condition_stack.is_empty() => {
+                let mut counter = UsageCounter::default();
+                counter.visit_conditional(conditional);
+                !counter.has_return
+                    && counter.assigned.iter().all(|variable| {
+                        counter.uses.get(variable).copied().unwrap_or_default()
+                            == finalize_uses.get(variable).copied().unwrap_or_default()
+                    })
+            }
+            _ => false,
+        }
     }
 
     /// Clears the state associated with `ReturnStatements`, returning the ones that were previously stored.
@@ -49,6 +49,10 @@
 //!     return ret$4;
 //! }
 //! ```
+//!
+//! If finalize branching is enabled, a conditional statement in a finalize block is not flattened if the variables it assigns are
+//! only used within it, and if it does not return. Code generation compiles such a statement to branches, so that only the taken
+//! path executes, e.g. a `Mapping::get` in the untaken path does not fail.
 
 mod flatten_expression;
 
@@ -59,17 +63,20 @@ mod flatten_statement;
 pub mod flattener;
 pub use flattener::*;
 
+mod usage_counter;
+pub(crate) use usage_counter::*;
+
 use crate::{Assigner, Pass, SymbolTable, TypeTable};
 
 use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
 use leo_errors::Result;
 
 impl<'a> Pass for Flattener<'a> {
-    type Input = (Ast, &'a SymbolTable, &'a TypeTable, &'a NodeBuilder, &'a Assigner);
+    type Input = (Ast, &'a SymbolTable, &'a TypeTable, &'a NodeBuilder, &'a Assigner, bool);
     type Output = Result<Ast>;
 
-    fn do_pass((ast, st, tt, node_builder, assigner): Self::Input) -> Self::Output {
-        let mut reconstructor = Flattener::new(st, tt, node_builder, assigner);
+    fn do_pass((ast, st, tt, node_builder, assigner, finalize_branching): Self::Input) -> Self::Output {
+        let mut reconstructor = Flattener::new(st, tt, node_builder, assigner, finalize_branching);
         let program = reconstructor.reconstruct_program(ast.into_repr());
 
         Ok(Ast::new(program))
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{
+    AssignStatement,
+    Expression,
+    ExpressionVisitor,
+    Identifier,
+    ReturnStatement,
+    StatementVisitor,
+    StructExpression,
+};
+use leo_span::Symbol;
+
+use indexmap::{IndexMap, IndexSet};
+
+/// Counts the uses of each variable in a subtree of the AST, and collects the variables that it assigns.
+/// The flattener uses these to decide whether a conditional statement in a finalize block can be compiled to a branch.
+#[derive(Default)]
+pub(crate) struct UsageCounter {
+    /// The number of times that each variable is read.
+    pub(crate) uses: IndexMap<Symbol, usize>,
+    /// The variables that are assigned.
+    pub(crate) assigned: IndexSet<Symbol>,
+    /// Whether the subtree contains a return statement.
+    pub(crate) has_return: bool,
+}
+
+impl<'a> ExpressionVisitor<'a> for UsageCounter {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        *self.uses.entry(input.name).or_default() += 1;
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        for member in input.members.iter() {
+            match &member.expression {
+                Some(expression) => self.visit_expression(expression, additional),
+                None => self.visit_identifier(&member.identifier, additional),
+            }
+        }
+    }
+}
+
+impl<'a> StatementVisitor<'a> for UsageCounter {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        match &input.place {
+            Expression::Identifier(identifier) => {
+                self.assigned.insert(identifier.name);
+            }
+            Expression::Tuple(tuple) => {
+                self.assigned.extend(tuple.elements.iter().filter_map(|element| match element {
+                    Expression::Identifier(identifier) => Some(identifier.name),
+                    _ => None,
+                }));
+            }
+            _ => {}
+        }
+        self.visit_expression(&input.value, &Default::default());
+    }
+
+    fn visit_return(&mut self, input: &'a ReturnStatement) {
+        self.has_return = true;
+        self.visit_expression(&input.expression, &Default::default());
+        if let Some(arguments) = &input.finalize_arguments {
+            arguments.iter().for_each(|argument| self.visit_expression(argument, &Default::default()));
+        }
+    }
+}
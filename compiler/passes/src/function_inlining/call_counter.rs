@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{CallExpression, Expression, ExpressionVisitor, StatementVisitor, StructExpression};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+/// Counts the calls to each local function in a subtree of the AST.
+/// The function inliner uses these counts to estimate the cost of inlining a function.
+#[derive(Default)]
+pub(crate) struct CallCounter {
+    /// The number of calls to each local function.
+    pub(crate) calls: IndexMap<Symbol, usize>,
+}
+
+impl<'a> ExpressionVisitor<'a> for CallCounter {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        if let (None, Expression::Identifier(identifier)) = (&input.external, &*input.function) {
+            *self.calls.entry(identifier.name).or_default() += 1;
+        }
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        for member in input.members.iter() {
+            if let Some(expression) = &member.expression {
+                self.visit_expression(expression, additional);
+            }
+        }
+    }
+}
+
+impl<'a> StatementVisitor<'a> for CallCounter {}
@@ -14,10 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Assigner, AssignmentRenamer, CallGraph, TypeTable};
+use crate::{Assigner, AssignmentRenamer, CallGraph, InlinedCall, InliningStrategy, TypeTable};
 
-use leo_ast::{Function, NodeBuilder};
-use leo_span::Symbol;
+use leo_ast::{Expression, Function, Input, NodeBuilder, Output, Statement, Type};
+use leo_span::{sym, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
 
 pub struct FunctionInliner<'a> {
     /// A counter used to create unique NodeIDs.
@@ -30,6 +32,22 @@ pub struct FunctionInliner<'a> {
     pub(crate) type_table: &'a TypeTable,
     /// A map of reconstructed functions in the current program scope.
     pub(crate) reconstructed_functions: Vec<(Symbol, Function)>,
+    /// The strategy that decides which `inline` functions are inlined.
+    pub(crate) strategy: InliningStrategy,
+    /// The number of calls to each function that remain calls in the bodies of transitions,
+    /// including the calls from `inline` functions that are inlined into them.
+    pub(crate) call_counts: IndexMap<Symbol, usize>,
+    /// The records declared in the current program scope, which closures cannot take or return.
+    pub(crate) records: IndexSet<Symbol>,
+    /// The `inline` functions that are emitted as Aleo closures, instead of being inlined.
+    pub(crate) outlined: IndexSet<Symbol>,
+    /// The name of the function being reconstructed.
+    pub(crate) current_function: Option<Symbol>,
+    /// Whether the block being reconstructed keeps its calls to closures,
+    /// i.e. whether it is the body of a transition, or of an `inline` function that is inlined.
+    pub(crate) calls_closures: bool,
+    /// The calls to local functions, and whether or not they were inlined.
+    pub(crate) report: Vec<InlinedCall>,
 }
 
 impl<'a> FunctionInliner<'a> {
@@ -39,6 +57,7 @@ impl<'a> FunctionInliner<'a> {
         call_graph: &'a CallGraph,
         assigner: &'a Assigner,
         type_table: &'a TypeTable,
+        strategy: InliningStrategy,
     ) -> Self {
         Self {
             node_builder,
@@ -46,6 +65,63 @@ impl<'a> FunctionInliner<'a> {
             assignment_renamer: AssignmentRenamer::new(assigner),
             reconstructed_functions: Default::default(),
             type_table,
+            strategy,
+            call_counts: Default::default(),
+            records: Default::default(),
+            outlined: Default::default(),
+            current_function: None,
+            calls_closures: false,
+            report: Default::default(),
+        }
+    }
+
+    /// Returns whether the `inline` function `function` is emitted as an Aleo closure,
+    /// in which case the calls from the bodies of transitions are not inlined.
+    /// Note that the calls from the callers of `function` must already be counted.
+    pub(crate) fn is_outlined(&self, function: &Function) -> bool {
+        let calls = self.call_counts.get(&function.identifier.name).copied().unwrap_or_default();
+
+        // Note that a closure must have at least one input and one output, and cannot take or return records.
+        if calls == 0 || function.input.is_empty() || function.output.is_empty() {
+            return false;
+        }
+        let is_record =
+            |type_: Type| matches!(type_, Type::Identifier(identifier) if self.records.contains(&identifier.name));
+        if function.input.iter().any(|input| matches!(input, Input::External(_)) || is_record(input.type_()))
+            || function.output.iter().any(|output| matches!(output, Output::External(_)) || is_record(output.type_()))
+        {
+            return false;
+        }
+
+        // An `@inline(always)` or `@inline(never)` annotation takes precedence over the strategy.
+        let annotation = function
+            .annotations
+            .iter()
+            .find(|annotation| annotation.identifier.name == sym::inline)
+            .and_then(|annotation| annotation.arguments.first());
+        match annotation {
+            Some(argument) => argument.name == sym::never,
+            None if self.strategy == InliningStrategy::Cost => {
+                // Inlining copies the body of the function into each call site,
+                // while a closure declares its inputs and outputs once and adds a `call` instruction to each call site.
+                let size =
+                    function.block.statements.iter().filter(|statement| Self::emits_instructions(statement)).count();
+                let closure_cost = size + function.input.len() + function.output.len() + calls;
+                closure_cost < size * calls
+            }
+            None => false,
+        }
+    }
+
+    /// Returns whether `statement` is compiled to instructions, which inlining copies into each call site.
+    /// Empty blocks, which inlining leaves in place of a call, returns, and assignments of literals or variables
+    /// are compiled to operands instead.
+    fn emits_instructions(statement: &Statement) -> bool {
+        match statement {
+            Statement::Block(block) => !block.statements.is_empty(),
+            Statement::Return(_) => false,
+            Statement::Assign(assign) => !matches!(assign.value, Expression::Literal(_) | Expression::Identifier(_)),
+            _ => true,
         }
     }
 }
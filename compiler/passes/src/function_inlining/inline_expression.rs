@@ -14,9 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{FunctionInliner, Replacer};
+use crate::{FunctionInliner, InlinedCall, Replacer};
 
 use leo_ast::{
+    Block,
     CallExpression,
     Expression,
     ExpressionReconstructor,
@@ -51,10 +52,21 @@ impl ExpressionReconstructor for FunctionInliner<'_> {
         // Since this pass processes functions in post-order, the callee function is guaranteed to exist in `self.reconstructed_functions`
         let (_, callee) = self.reconstructed_functions.iter().find(|(symbol, _)| *symbol == function_name).unwrap();
 
+        // Determine whether the call is inlined.
+        // Note that an `inline` function that is emitted as a closure can only be called from the body of a transition.
+        let keeps_closure_calls = callee.variant == Variant::Inline;
+        let inlined = match callee.variant {
+            Variant::Transition => false,
+            Variant::Standard => self.outlined.contains(&function_name) && !self.calls_closures,
+            Variant::Inline => true,
+        };
+        // Note that this unwrap is safe since the current function is set before reconstructing a function.
+        self.report.push(InlinedCall { caller: self.current_function.unwrap(), callee: function_name, inlined });
+
         // Inline the callee function, if required, otherwise, return the call expression.
-        match callee.variant {
-            Variant::Transition | Variant::Standard => (Expression::Call(input), Default::default()),
-            Variant::Inline => {
+        match inlined {
+            false => (Expression::Call(input), Default::default()),
+            true => {
                 // Construct a mapping from input variables of the callee function to arguments passed to the callee.
                 let parameter_to_argument = callee
                     .input
@@ -101,7 +113,18 @@ impl ExpressionReconstructor for FunctionInliner<'_> {
                     }
                 };
 
-                (result, inlined_statements)
+                // An `inline` function keeps its calls to closures, which are inlined if the caller cannot call closures.
+                match keeps_closure_calls && !self.calls_closures {
+                    true => {
+                        let id = self.node_builder.next_id();
+                        let block = Block { statements: inlined_statements, span: Default::default(), id };
+                        let mut statements = self.reconstruct_block(block).0.statements;
+                        let (result, additional_statements) = self.reconstruct_expression(result);
+                        statements.extend(additional_statements);
+                        (result, statements)
+                    }
+                    false => (result, inlined_statements),
+                }
             }
         }
     }
@@ -14,9 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::FunctionInliner;
+use crate::{CallCounter, FunctionInliner};
 
-use leo_ast::{Function, ProgramReconstructor, ProgramScope};
+use leo_ast::{
+    Finalize,
+    Function,
+    ProgramReconstructor,
+    ProgramScope,
+    StatementReconstructor,
+    StatementVisitor,
+    Variant,
+};
 use leo_span::Symbol;
 
 use indexmap::IndexMap;
@@ -31,13 +39,43 @@ impl ProgramReconstructor for FunctionInliner<'_> {
         // Construct map to provide faster lookup of functions
         let mut function_map: IndexMap<Symbol, Function> = input.functions.into_iter().collect();
 
+        // Decide which `inline` functions are emitted as closures, visiting each caller before its callees.
+        self.records = input.structs.iter().filter(|(_, struct_)| struct_.is_record).map(|(name, _)| *name).collect();
+        self.call_counts.clear();
+        for function_name in order.iter().rev() {
+            if let Some(function) = function_map.get(function_name) {
+                if function.variant == Variant::Inline && self.is_outlined(function) {
+                    self.outlined.insert(*function_name);
+                }
+                // The calls from the body of a transition remain calls, and so do the calls from the body of an
+                // `inline` function that is inlined, once for each of the calls to it that remain calls.
+                let weight = match function.variant {
+                    Variant::Transition => 1,
+                    Variant::Inline if !self.outlined.contains(function_name) => {
+                        self.call_counts.get(function_name).copied().unwrap_or_default()
+                    }
+                    _ => 0,
+                };
+                let mut counter = CallCounter::default();
+                counter.visit_block(&function.block);
+                for (callee, calls) in counter.calls {
+                    *self.call_counts.entry(callee).or_default() += weight * calls;
+                }
+            }
+        }
+
         // Reconstruct and accumulate each of the functions in post-order.
         for function_name in &order {
             // None: If `function_name` is not in `input.functions`, then it must be an external function.
             // TODO: Check that this is indeed an external function. Requires a redesign of the symbol table.
             if let Some(function) = function_map.remove(function_name) {
                 // Reconstruct the function.
-                let reconstructed_function = self.reconstruct_function(function);
+                self.current_function = Some(*function_name);
+                let mut reconstructed_function = self.reconstruct_function(function);
+                // Emit the `inline` function as a closure, if it is not inlined.
+                if self.outlined.contains(function_name) {
+                    reconstructed_function.variant = Variant::Standard;
+                }
                 // Add the reconstructed function to the mapping.
                 self.reconstructed_functions.push((*function_name, reconstructed_function));
             }
@@ -55,6 +93,45 @@ impl ProgramReconstructor for FunctionInliner<'_> {
             functions,
             consts: input.consts,
             span: input.span,
+            is_stub: input.is_stub,
+        }
+    }
+
+    /// Inlines the calls in a function's body and finalize block, if it exists.
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        // Only the body of a transition can call the `inline` functions that are emitted as closures.
+        // The calls to them from the body of an `inline` function are kept until it is inlined into a caller,
+        // while every other call to them is inlined, since closures and finalize blocks cannot call closures.
+        // Note that this unwrap is safe since the current function is set before reconstructing a function.
+        self.calls_closures = match input.variant {
+            Variant::Transition => true,
+            Variant::Inline => !self.outlined.contains(&self.current_function.unwrap()),
+            Variant::Standard => false,
+        };
+        let block = self.reconstruct_block(input.block).0;
+
+        self.calls_closures = false;
+        let finalize = input.finalize.map(|finalize| Finalize {
+            identifier: finalize.identifier,
+            input: finalize.input,
+            output: finalize.output,
+            output_type: finalize.output_type,
+            block: self.reconstruct_block(finalize.block).0,
+            span: finalize.span,
+            id: finalize.id,
+        });
+
+        Function {
+            annotations: input.annotations,
+            variant: input.variant,
+            identifier: input.identifier,
+            input: input.input,
+            output: input.output,
+            output_type: input.output_type,
+            block,
+            finalize,
+            span: input.span,
+            id: input.id,
         }
     }
 }
@@ -69,9 +69,21 @@ impl StatementReconstructor for FunctionInliner<'_> {
         (Block { span: block.span, statements, id: block.id }, Default::default())
     }
 
-    /// Flattening removes conditional statements from the program.
-    fn reconstruct_conditional(&mut self, _: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
-        unreachable!("`ConditionalStatement`s should not be in the AST at this phase of compilation.")
+    /// Reconstructs the blocks of a conditional statement.
+    /// Flattening only keeps the conditional statements in finalize blocks that are compiled to branches.
+    fn reconstruct_conditional(&mut self, input: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
+        let then = self.reconstruct_block(input.then).0;
+        let otherwise = input.otherwise.map(|statement| Box::new(self.reconstruct_statement(*statement).0));
+        (
+            Statement::Conditional(ConditionalStatement {
+                condition: input.condition,
+                then,
+                otherwise,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
     }
 
     /// Parsing guarantees that console statements are not present in the program.
@@ -0,0 +1,39 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_span::Symbol;
+
+/// The strategy that decides which `inline` functions are inlined at their call sites.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InliningStrategy {
+    /// Inline every call to an `inline` function.
+    #[default]
+    Always,
+    /// Inline a call to an `inline` function only if it is cheaper than calling the function as an Aleo closure.
+    /// The cost of a function is estimated from the size of its body and the number of times that it is called.
+    Cost,
+}
+
+/// A call to a local function, and whether or not it was inlined.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InlinedCall {
+    /// The function that contains the call.
+    pub caller: Symbol,
+    /// The function that is called.
+    pub callee: Symbol,
+    /// Whether the call was inlined.
+    pub inlined: bool,
+}
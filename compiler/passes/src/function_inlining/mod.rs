@@ -51,10 +51,19 @@
 //!     return value$3;
 //! }
 //! ```
+//!
+//! Functions declared with `inline` are always inlined, unless the inlining strategy is `Cost` or the function is annotated
+//! with `@inline(never)`. In that case, the function may instead be emitted as an Aleo closure, which the bodies of
+//! transitions call, directly or through the `inline` functions inlined into them,
+//! while the calls from finalize blocks and closures are still inlined.
+//! The pass also reports which calls were inlined.
 
 pub mod assignment_renamer;
 pub use assignment_renamer::*;
 
+mod call_counter;
+pub(crate) use call_counter::*;
+
 mod inline_expression;
 
 mod inline_statement;
@@ -64,19 +73,22 @@ mod inline_program;
 pub mod function_inliner;
 pub use function_inliner::*;
 
+pub mod inlining_strategy;
+pub use inlining_strategy::*;
+
 use crate::{Assigner, CallGraph, Pass, TypeTable};
 
 use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
 use leo_errors::Result;
 
 impl<'a> Pass for FunctionInliner<'a> {
-    type Input = (Ast, &'a NodeBuilder, &'a CallGraph, &'a Assigner, &'a TypeTable);
-    type Output = Result<Ast>;
+    type Input = (Ast, &'a NodeBuilder, &'a CallGraph, &'a Assigner, &'a TypeTable, InliningStrategy);
+    type Output = Result<(Ast, Vec<InlinedCall>)>;
 
-    fn do_pass((ast, node_builder, call_graph, assigner, tt): Self::Input) -> Self::Output {
-        let mut reconstructor = FunctionInliner::new(node_builder, call_graph, assigner, tt);
+    fn do_pass((ast, node_builder, call_graph, assigner, tt, strategy): Self::Input) -> Self::Output {
+        let mut reconstructor = FunctionInliner::new(node_builder, call_graph, assigner, tt, strategy);
         let program = reconstructor.reconstruct_program(ast.into_repr());
 
-        Ok(Ast::new(program))
+        Ok((Ast::new(program), reconstructor.report))
     }
 }
@@ -0,0 +1,313 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{DiGraphError, ImportGraph, ImportResolver, ImportSource, StubLifter};
+
+use leo_ast::{NodeBuilder, Program};
+use leo_errors::{emitter::Handler, CompilerError, Result};
+use leo_span::{symbol::with_session_globals, Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+
+/// A compiler pass during which the programs that the main program depends on are resolved.
+/// These are the programs it imports, transitively, and the other program scopes declared in its file.
+/// Each imported program is parsed exactly once, and cyclic imports are rejected.
+/// The resolved programs are stored in the imports of the main program in post-order,
+/// so that every program precedes the programs that depend on it.
+pub struct ImportCollector<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// The `NodeBuilder` used to assign `NodeID`s to the imported programs.
+    node_builder: &'a NodeBuilder,
+    /// The resolver used to locate the source of the imported programs.
+    resolver: &'a dyn ImportResolver,
+    /// The dependency graph of the programs.
+    import_graph: ImportGraph,
+    /// The programs resolved so far, along with the span of the declaration that introduced them.
+    resolved: IndexMap<Symbol, (Program, Span)>,
+    /// The imports that have yet to be resolved.
+    pending: Vec<(Symbol, Span)>,
+    /// The bytecode of the programs imported as Aleo instructions.
+    pub(crate) bytecode: IndexMap<Symbol, String>,
+}
+
+impl<'a> ImportCollector<'a> {
+    pub fn new(handler: &'a Handler, node_builder: &'a NodeBuilder, resolver: &'a dyn ImportResolver) -> Self {
+        Self {
+            handler,
+            node_builder,
+            resolver,
+            import_graph: ImportGraph::new(IndexSet::new()),
+            resolved: IndexMap::new(),
+            pending: Vec::new(),
+            bytecode: IndexMap::new(),
+        }
+    }
+
+    /// Checks that `program` declares a program scope named `name`.
+    pub fn check_program_scope_name(program: &Program, name: Symbol) -> Result<()> {
+        match program.program_scopes.contains_key(&name) {
+            true => Ok(()),
+            false => {
+                let program_scope = program.program_scopes.values().next().unwrap();
+                Err(CompilerError::program_scope_name_does_not_match(
+                    program_scope.program_id.name,
+                    name,
+                    program_scope.program_id.name.span,
+                )
+                .into())
+            }
+        }
+    }
+
+    /// Resolves the dependencies of the main program `program_name`, returning the program with its imports resolved.
+    pub(crate) fn collect(&mut self, program_name: Symbol, program: Program) -> Result<Program> {
+        // Register the main program and the program scopes declared alongside it.
+        self.import_graph = ImportGraph::new(IndexSet::from([program_name]));
+        let mut program = self.register_program(program_name, program)?;
+
+        // Resolve the imported programs until none remain.
+        while let Some((import_name, span)) = self.pending.pop() {
+            if import_name == program_name || self.resolved.contains_key(&import_name) {
+                continue;
+            }
+
+            let import = self.parse_import(import_name, span)?;
+            let import = self.register_program(import_name, import)?;
+            self.resolved.insert(import_name, (import, span));
+        }
+
+        // Order the programs so that every program precedes the programs that depend on it.
+        let order = match self.import_graph.post_order() {
+            Ok(order) => order,
+            Err(DiGraphError::CycleDetected(path)) => return Err(CompilerError::cyclic_import(path).into()),
+        };
+
+        program.imports = order
+            .into_iter()
+            .filter(|name| *name != program_name)
+            .map(|name| (name, self.resolved.remove(&name).unwrap()))
+            .collect();
+
+        Ok(program)
+    }
+
+    /// Registers a parsed program named `name` in the import graph, returning it without its other program scopes.
+    /// Each of the other program scopes in the file becomes a separate program, which may use the programs
+    /// imported by the file, as well as the program scopes declared before it.
+    /// The imports of the returned program, and of the separated programs, are left unresolved.
+    fn register_program(&mut self, name: Symbol, mut program: Program) -> Result<Program> {
+        // Note that the caller guarantees that the program contains a program scope named `name`.
+        let program_scope = program.program_scopes.remove(&name).unwrap();
+        let mut imports = core::mem::take(&mut program.imports);
+
+        // Add the imports declared in the file to the import graph.
+        for (import_name, (_, span)) in imports.iter() {
+            self.import_graph.add_edge(name, *import_name);
+            self.pending.push((*import_name, *span));
+        }
+
+        // Separate the other program scopes into their own programs.
+        for (scope_name, scope) in program.program_scopes {
+            if self.import_graph.contains_node(scope_name) {
+                return Err(CompilerError::conflicting_program_name(scope_name, scope.program_id.name.span).into());
+            }
+            for import_name in imports.keys() {
+                self.import_graph.add_edge(scope_name, *import_name);
+            }
+            self.import_graph.add_edge(name, scope_name);
+
+            let span = scope.span;
+            let separated = Program { imports: imports.clone(), program_scopes: IndexMap::from([(scope_name, scope)]) };
+            self.resolved.insert(scope_name, (separated, span));
+
+            // Subsequent program scopes in the file may depend on this one.
+            imports.insert(scope_name, (Program::default(), span));
+        }
+
+        Ok(Program { imports, program_scopes: IndexMap::from([(name, program_scope)]) })
+    }
+
+    /// Parses the program imported as `import_name`, using the resolver to locate its source.
+    /// Programs written in Aleo instructions are lifted into stubs, and their bytecode is stored.
+    fn parse_import(&mut self, import_name: Symbol, span: Span) -> Result<Program> {
+        // Throw an error if the import cannot be resolved.
+        let (file_name, source) = match self.resolver.resolve(import_name)? {
+            Some(resolved) => resolved,
+            None => return Err(CompilerError::import_not_found(import_name, span).into()),
+        };
+
+        let program = match source {
+            ImportSource::Leo(source) => {
+                // Register the source in the source map.
+                let prg_sf = with_session_globals(|s| s.source_map.new_source(&source, file_name));
+
+                // Use the parser to construct the imported abstract syntax tree (ast).
+                leo_parser::parse_ast(self.handler, self.node_builder, &prg_sf.src, prg_sf.start_pos)?.into_repr()
+            }
+            ImportSource::Aleo(source) => {
+                let (name, program) = StubLifter::new(self.node_builder)
+                    .lift(&source)
+                    .map_err(|error| CompilerError::failed_to_parse_aleo_import(import_name, error, span))?;
+                // Check that the name of the Aleo program matches the import.
+                if name != import_name {
+                    return Err(CompilerError::program_scope_name_does_not_match(name, import_name, span).into());
+                }
+                self.bytecode.insert(import_name, source);
+                program
+            }
+        };
+
+        // Check that the imported file declares a program scope with the same name.
+        Self::check_program_scope_name(&program, import_name)?;
+
+        Ok(program)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::VirtualResolver;
+
+    use leo_ast::Variant;
+
+    use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+    /// Returns the source of a program `name` importing each of `imports`.
+    fn program_source(name: &str, imports: &[&str]) -> String {
+        let imports: String = imports.iter().map(|import| format!("import {import}.aleo;\n")).collect();
+        format!("{imports}program {name}.aleo {{\n    transition main(a: u8) -> u8 {{\n        return a;\n    }}\n}}\n")
+    }
+
+    /// Collects the dependencies of the program `main`, whose imports are resolved from `files`.
+    fn collect(files: &[(&str, &[&str])]) -> Result<Vec<Symbol>> {
+        let handler = Handler::default();
+        let node_builder = NodeBuilder::default();
+
+        let mut resolver = VirtualResolver::default();
+        for (name, imports) in files {
+            resolver.insert(Symbol::intern(name), ImportSource::Leo(program_source(name, imports)));
+        }
+
+        let main_name = Symbol::intern("main");
+        let (file_name, source) = match resolver.resolve(main_name)? {
+            Some((file_name, ImportSource::Leo(source))) => (file_name, source),
+            _ => unreachable!("The main program is written in Leo."),
+        };
+        let sf = with_session_globals(|s| s.source_map.new_source(&source, file_name));
+        let program = leo_parser::parse_ast(&handler, &node_builder, &sf.src, sf.start_pos)?.into_repr();
+
+        let program = ImportCollector::new(&handler, &node_builder, &resolver).collect(main_name, program)?;
+        Ok(program.imports.into_keys().collect())
+    }
+
+    #[test]
+    fn test_transitive_imports() {
+        create_session_if_not_set_then(|_| {
+            let order = collect(&[("main", &["b", "c"]), ("b", &["d"]), ("c", &["d"]), ("d", &[])]).unwrap();
+            let expected: Vec<Symbol> = ["d", "b", "c"].into_iter().map(Symbol::intern).collect();
+            assert_eq!(order, expected);
+        })
+    }
+
+    #[test]
+    fn test_aleo_import() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let node_builder = NodeBuilder::default();
+
+            let token = "program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+mapping balances:
+    key as address.public;
+    value as u64.public;
+
+closure twice:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 r1 into r2 as token.record;
+    output r2 as token.record;
+
+function deposit:
+    input r0 as address.public;
+    input r1 as u64.public;
+    async deposit r0 r1 into r2;
+    output r2 as token.aleo/deposit.future;
+
+finalize deposit:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use balances[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into balances[r0];
+";
+            let mut resolver = VirtualResolver::default();
+            resolver.insert(Symbol::intern("token"), ImportSource::Aleo(token.to_string()));
+
+            let main_name = Symbol::intern("main");
+            let source = program_source("main", &["token"]);
+            let sf = with_session_globals(|s| s.source_map.new_source(&source, FileName::Custom("main.leo".into())));
+            let program = leo_parser::parse_ast(&handler, &node_builder, &sf.src, sf.start_pos).unwrap().into_repr();
+
+            let mut collector = ImportCollector::new(&handler, &node_builder, &resolver);
+            let program = collector.collect(main_name, program).unwrap();
+
+            // The imported program is lifted into a stub declaring its interface.
+            let token_name = Symbol::intern("token");
+            let stub = &program.imports.get(&token_name).unwrap().0.program_scopes[&token_name];
+            assert!(stub.is_stub);
+            assert_eq!(stub.structs.len(), 1);
+            assert!(stub.structs[0].1.is_record);
+            assert_eq!(stub.mappings.len(), 1);
+            assert_eq!(stub.functions.len(), 3);
+
+            // Closures cannot be called from other programs, unlike functions.
+            let (twice, mint, deposit) = (&stub.functions[0].1, &stub.functions[1].1, &stub.functions[2].1);
+            assert_eq!(twice.variant, Variant::Standard);
+            assert_eq!((twice.input.len(), twice.output.len()), (1, 1));
+            assert_eq!(mint.variant, Variant::Transition);
+            assert_eq!(mint.input.len(), 2);
+            assert!(mint.finalize.is_none());
+
+            // The finalize block declares the inputs of the finalize logic.
+            assert_eq!(deposit.variant, Variant::Transition);
+            // The future of the finalize block is introduced by code generation.
+            assert_eq!((deposit.input.len(), deposit.output.len()), (2, 0));
+            assert_eq!(deposit.finalize.as_ref().unwrap().input.len(), 2);
+
+            // The bytecode of the imported program is kept as is.
+            assert_eq!(collector.bytecode.get(&token_name).unwrap(), token);
+        })
+    }
+
+    #[test]
+    fn test_cyclic_imports() {
+        create_session_if_not_set_then(|_| {
+            let result = collect(&[("main", &["b"]), ("b", &["c"]), ("c", &["main"])]);
+            assert!(result.unwrap_err().to_string().contains("Cyclic dependency between programs"));
+        })
+    }
+}
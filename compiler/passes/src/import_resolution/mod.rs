@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod collector;
+pub use collector::*;
+
+pub mod resolver;
+pub use resolver::*;
+
+mod stub_lifter;
+pub(crate) use stub_lifter::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, NodeBuilder};
+use leo_errors::{emitter::Handler, Result};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+impl<'a> Pass for ImportCollector<'a> {
+    type Input = (Ast, &'a Handler, &'a NodeBuilder, &'a dyn ImportResolver, Symbol);
+    /// The AST with its imports resolved, and the bytecode of the programs imported as Aleo instructions.
+    type Output = Result<(Ast, IndexMap<Symbol, String>)>;
+
+    fn do_pass((ast, handler, node_builder, resolver, program_name): Self::Input) -> Self::Output {
+        let mut collector = ImportCollector::new(handler, node_builder, resolver);
+        let program = collector.collect(program_name, ast.into_repr())?;
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok((Ast::new(program), collector.bytecode))
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_errors::{CompilerError, Result};
+use leo_span::{source_map::FileName, Symbol};
+
+use indexmap::IndexMap;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The source of an imported program.
+#[derive(Clone, Debug)]
+pub enum ImportSource {
+    /// A program written in Leo.
+    Leo(String),
+    /// A program written in Aleo instructions, e.g. a deployed program.
+    Aleo(String),
+}
+
+/// Locates the source of the programs imported by a Leo program.
+/// An import `import foo.leo;` or `import foo.aleo;` is resolved by asking the resolver for the program `foo`.
+pub trait ImportResolver {
+    /// Returns the name and source of the file declaring the program `name`, or `None` if it cannot be found.
+    fn resolve(&self, name: Symbol) -> Result<Option<(FileName, ImportSource)>>;
+}
+
+/// Resolves imports from the file system, by searching a list of directories in order for a file `<name>.leo`.
+/// If a directory does not contain a Leo file, then the resolver looks for Aleo instructions in `<name>.aleo`.
+/// Programs with an explicit location, e.g. the dependencies declared in a manifest, take precedence over the search.
+#[derive(Clone, Debug, Default)]
+pub struct FileSystemResolver {
+    /// The file declaring each program with an explicit location.
+    files: IndexMap<Symbol, PathBuf>,
+    /// The directories to search.
+    search_paths: Vec<PathBuf>,
+}
+
+impl FileSystemResolver {
+    /// Initializes a new `FileSystemResolver` that searches the given directories.
+    pub fn new(search_paths: Vec<PathBuf>) -> Self {
+        Self { files: IndexMap::new(), search_paths }
+    }
+
+    /// Initializes a new `FileSystemResolver` that searches the `imports` directory of the package at `package_root`.
+    pub fn for_package(package_root: &Path) -> Self {
+        Self::new(vec![package_root.join("imports")])
+    }
+
+    /// Adds a directory to the end of the search paths, e.g. a cache of deployed programs.
+    pub fn with_search_path(mut self, search_path: PathBuf) -> Self {
+        self.search_paths.push(search_path);
+        self
+    }
+
+    /// Resolves the program `name` to the file at `path`, which is a `.leo` or a `.aleo` file.
+    pub fn with_file(mut self, name: Symbol, path: PathBuf) -> Self {
+        self.files.insert(name, path);
+        self
+    }
+}
+
+impl ImportResolver for FileSystemResolver {
+    fn resolve(&self, name: Symbol) -> Result<Option<(FileName, ImportSource)>> {
+        if let Some(file_path) = self.files.get(&name) {
+            let source = read_file(file_path)?;
+            let source = match file_path.extension().and_then(|extension| extension.to_str()) {
+                Some("aleo") => ImportSource::Aleo(source),
+                _ => ImportSource::Leo(source),
+            };
+            return Ok(Some((FileName::Real(file_path.clone()), source)));
+        }
+
+        for search_path in self.search_paths.iter() {
+            // Prefer the Leo source of the program, if it is available.
+            let leo_file_path = search_path.join(format!("{name}.leo"));
+            if leo_file_path.exists() {
+                let source = read_file(&leo_file_path)?;
+                return Ok(Some((FileName::Real(leo_file_path), ImportSource::Leo(source))));
+            }
+
+            let aleo_file_path = search_path.join(format!("{name}.aleo"));
+            if aleo_file_path.exists() {
+                let source = read_file(&aleo_file_path)?;
+                return Ok(Some((FileName::Real(aleo_file_path), ImportSource::Aleo(source))));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Reads the file at `path` into a string.
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|e| CompilerError::file_read_error(path, e).into())
+}
+
+/// Resolves imports from an in-memory set of programs, keyed by program name.
+#[derive(Clone, Debug, Default)]
+pub struct VirtualResolver {
+    /// The source of each program.
+    files: IndexMap<Symbol, ImportSource>,
+}
+
+impl VirtualResolver {
+    /// Adds the source of the program `name` to the resolver.
+    pub fn insert(&mut self, name: Symbol, source: ImportSource) {
+        self.files.insert(name, source);
+    }
+}
+
+impl ImportResolver for VirtualResolver {
+    fn resolve(&self, name: Symbol) -> Result<Option<(FileName, ImportSource)>> {
+        Ok(self.files.get(&name).map(|source| {
+            let extension = match source {
+                ImportSource::Leo(_) => "leo",
+                ImportSource::Aleo(_) => "aleo",
+            };
+            (FileName::Custom(format!("{name}.{extension}")), source.clone())
+        }))
+    }
+}
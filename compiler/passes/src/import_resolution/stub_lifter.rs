@@ -0,0 +1,364 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{
+    ArrayType,
+    Block,
+    External,
+    Finalize,
+    Function,
+    FunctionInput,
+    FunctionOutput,
+    Identifier,
+    Input,
+    IntegerType,
+    Mapping,
+    Member,
+    Mode,
+    NodeBuilder,
+    Output,
+    Program,
+    ProgramId,
+    ProgramScope,
+    Struct,
+    Type,
+    Variant,
+};
+use leo_span::{Span, Symbol};
+
+use snarkvm::{
+    console::program::{EntryType, FinalizeType, LiteralType, PlaintextType, RegisterType, ValueType},
+    prelude::Testnet3,
+    synthesizer::program::Program as AleoProgram,
+};
+
+use indexmap::IndexMap;
+use std::{fmt::Display, str::FromStr};
+
+/// Lifts a program written in Aleo instructions into a Leo program containing a stub program scope.
+/// The stub declares the structs, records, mappings, and function signatures of the program,
+/// which allows the type checker to validate calls to the program.
+/// Functions are lifted into transitions, and closures into standard functions, which cannot be called from other programs.
+pub(crate) struct StubLifter<'a> {
+    /// The `NodeBuilder` used to assign `NodeID`s to the lifted nodes.
+    node_builder: &'a NodeBuilder,
+}
+
+impl<'a> StubLifter<'a> {
+    pub(crate) fn new(node_builder: &'a NodeBuilder) -> Self {
+        Self { node_builder }
+    }
+
+    /// Parses `source` as Aleo instructions and lifts it into a Leo program.
+    /// Returns the name of the lifted program, and the program itself.
+    pub(crate) fn lift(&self, source: &str) -> Result<(Symbol, Program), String> {
+        let program = AleoProgram::<Testnet3>::from_str(source).map_err(|err| err.to_string())?;
+
+        let program_id =
+            ProgramId { name: self.identifier(program.id().name()), network: self.identifier(program.id().network()) };
+
+        // Lift the structs and records of the program.
+        let structs = program
+            .structs()
+            .iter()
+            .map(|(name, struct_)| {
+                let members = struct_
+                    .members()
+                    .iter()
+                    .map(|(identifier, type_)| self.member(Mode::None, identifier, self.plaintext_type(type_)))
+                    .collect();
+                self.struct_(name, members, false)
+            })
+            .chain(program.records().iter().map(|(name, record)| {
+                let owner_mode = if record.owner().is_public() { Mode::Public } else { Mode::Private };
+                let owner = self.member(owner_mode, "owner", Type::Address);
+                let entries = record.entries().iter().map(|(identifier, entry)| {
+                    let (mode, type_) = match entry {
+                        EntryType::Constant(type_) => (Mode::Constant, type_),
+                        EntryType::Public(type_) => (Mode::Public, type_),
+                        EntryType::Private(type_) => (Mode::Private, type_),
+                    };
+                    self.member(mode, identifier, self.plaintext_type(type_))
+                });
+                self.struct_(name, std::iter::once(owner).chain(entries).collect(), true)
+            }))
+            .collect();
+
+        // Lift the mappings of the program.
+        let mappings = program
+            .mappings()
+            .iter()
+            .map(|(name, mapping)| {
+                let identifier = self.identifier(name);
+                (identifier.name, Mapping {
+                    identifier,
+                    key_type: self.plaintext_type(mapping.key().plaintext_type()),
+                    value_type: self.plaintext_type(mapping.value().plaintext_type()),
+                    span: Default::default(),
+                    id: self.node_builder.next_id(),
+                })
+            })
+            .collect();
+
+        // Lift the signatures of the closures and functions of the program.
+        let closures = program.closures().iter().map(|(name, closure)| {
+            let input = closure
+                .inputs()
+                .iter()
+                .enumerate()
+                .filter_map(|(index, input)| self.register_input(format!("r{index}"), input.register_type()))
+                .collect();
+            let output =
+                closure.outputs().iter().filter_map(|output| self.register_output(output.register_type())).collect();
+            self.function(Variant::Standard, name, input, output, None)
+        });
+        let functions = program.functions().iter().map(|(name, function)| {
+            let input = function
+                .inputs()
+                .iter()
+                .enumerate()
+                .filter_map(|(index, input)| self.input(format!("r{index}"), input.value_type()))
+                .collect();
+            let output = function.outputs().iter().filter_map(|output| self.output(output.value_type())).collect();
+            let finalize = function.finalize_logic().map(|finalize| {
+                finalize
+                    .inputs()
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, input)| self.finalize_input(format!("r{index}"), input.finalize_type()))
+                    .collect()
+            });
+            self.function(Variant::Transition, name, input, output, finalize)
+        });
+        let functions = closures.chain(functions).collect();
+
+        // The imports of the program are resolved by the compiler.
+        let imports = program
+            .imports()
+            .keys()
+            .map(|import_id| (self.identifier(import_id.name()).name, (Program::default(), Span::default())))
+            .collect();
+
+        let name = program_id.name.name;
+        let program_scope = ProgramScope {
+            program_id,
+            consts: Vec::new(),
+            structs,
+            mappings,
+            functions,
+            span: Default::default(),
+            is_stub: true,
+        };
+
+        Ok((name, Program { imports, program_scopes: IndexMap::from([(name, program_scope)]) }))
+    }
+
+    /// Returns a Leo identifier with the same name as an Aleo identifier.
+    fn identifier(&self, name: impl Display) -> Identifier {
+        Identifier {
+            name: Symbol::intern(&name.to_string()),
+            span: Default::default(),
+            id: self.node_builder.next_id(),
+        }
+    }
+
+    /// Returns an empty block.
+    fn block(&self) -> Block {
+        Block { statements: Vec::new(), span: Default::default(), id: self.node_builder.next_id() }
+    }
+
+    /// Returns a function named `name` with an empty body, and a finalize block with the inputs `finalize`, if it has one.
+    fn function(
+        &self,
+        variant: Variant,
+        name: impl Display,
+        input: Vec<Input>,
+        output: Vec<Output>,
+        finalize: Option<Vec<Input>>,
+    ) -> (Symbol, Function) {
+        let identifier = self.identifier(name);
+        let finalize = finalize.map(|input| {
+            let identifier = Identifier { id: self.node_builder.next_id(), ..identifier };
+            Finalize::new(identifier, input, Vec::new(), self.block(), Default::default(), self.node_builder.next_id())
+        });
+        (
+            identifier.name,
+            Function::new(
+                Vec::new(),
+                variant,
+                identifier,
+                input,
+                output,
+                self.block(),
+                finalize,
+                Default::default(),
+                self.node_builder.next_id(),
+            ),
+        )
+    }
+
+    /// Returns a struct or record named `name` with the given members.
+    fn struct_(&self, name: impl Display, members: Vec<Member>, is_record: bool) -> (Symbol, Struct) {
+        let identifier = self.identifier(name);
+        (identifier.name, Struct {
+            identifier,
+            members,
+            is_record,
+            span: Default::default(),
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    /// Returns a struct or record member named `name`.
+    fn member(&self, mode: Mode, name: impl Display, type_: Type) -> Member {
+        Member {
+            mode,
+            identifier: self.identifier(name),
+            type_,
+            span: Default::default(),
+            id: self.node_builder.next_id(),
+        }
+    }
+
+    /// Returns the Leo type of an Aleo plaintext type.
+    fn plaintext_type(&self, type_: &PlaintextType<Testnet3>) -> Type {
+        match type_ {
+            PlaintextType::Literal(literal) => match literal {
+                LiteralType::Address => Type::Address,
+                LiteralType::Boolean => Type::Boolean,
+                LiteralType::Field => Type::Field,
+                LiteralType::Group => Type::Group,
+                LiteralType::I8 => Type::Integer(IntegerType::I8),
+                LiteralType::I16 => Type::Integer(IntegerType::I16),
+                LiteralType::I32 => Type::Integer(IntegerType::I32),
+                LiteralType::I64 => Type::Integer(IntegerType::I64),
+                LiteralType::I128 => Type::Integer(IntegerType::I128),
+                LiteralType::U8 => Type::Integer(IntegerType::U8),
+                LiteralType::U16 => Type::Integer(IntegerType::U16),
+                LiteralType::U32 => Type::Integer(IntegerType::U32),
+                LiteralType::U64 => Type::Integer(IntegerType::U64),
+                LiteralType::U128 => Type::Integer(IntegerType::U128),
+                LiteralType::Scalar => Type::Scalar,
+                LiteralType::Signature => Type::Signature,
+                LiteralType::String => Type::String,
+            },
+            PlaintextType::Struct(name) => Type::Identifier(self.identifier(name)),
+            PlaintextType::Array(array) => Type::Array(ArrayType::new(
+                self.plaintext_type(array.next_element_type()),
+                (**array.length() as usize).into(),
+            )),
+        }
+    }
+
+    /// Returns the Leo input of a function input named `name`, or `None` if it has no Leo counterpart.
+    fn input(&self, name: String, type_: &ValueType<Testnet3>) -> Option<Input> {
+        let identifier = self.identifier(name);
+        let (mode, type_) = match type_ {
+            ValueType::Constant(type_) => (Mode::Constant, self.plaintext_type(type_)),
+            ValueType::Public(type_) => (Mode::Public, self.plaintext_type(type_)),
+            ValueType::Private(type_) => (Mode::Private, self.plaintext_type(type_)),
+            ValueType::Record(record) => (Mode::None, Type::Identifier(self.identifier(record))),
+            ValueType::ExternalRecord(locator) => {
+                return Some(Input::External(External {
+                    identifier,
+                    program_name: self.identifier(locator.program_id().name()),
+                    record: self.identifier(locator.resource()),
+                    span: Default::default(),
+                    id: self.node_builder.next_id(),
+                }));
+            }
+            ValueType::Future(_) => return None,
+        };
+        Some(Input::Internal(FunctionInput {
+            identifier,
+            mode,
+            type_,
+            span: Default::default(),
+            id: self.node_builder.next_id(),
+        }))
+    }
+
+    /// Returns the Leo input of a closure input named `name`, or `None` if it has no Leo counterpart.
+    fn register_input(&self, name: String, type_: &RegisterType<Testnet3>) -> Option<Input> {
+        match type_ {
+            RegisterType::Plaintext(type_) => Some(self.plaintext_input(name, type_)),
+            RegisterType::Record(record) => self.input(name, &ValueType::Record(*record)),
+            RegisterType::ExternalRecord(locator) => self.input(name, &ValueType::ExternalRecord(*locator)),
+            RegisterType::Future(_) => None,
+        }
+    }
+
+    /// Returns the Leo input of a finalize input named `name`, or `None` if it has no Leo counterpart.
+    fn finalize_input(&self, name: String, type_: &FinalizeType<Testnet3>) -> Option<Input> {
+        match type_ {
+            FinalizeType::Plaintext(type_) => Some(self.plaintext_input(name, type_)),
+            FinalizeType::Future(_) => None,
+        }
+    }
+
+    /// Returns a Leo input named `name` of a plaintext type, without a mode.
+    fn plaintext_input(&self, name: String, type_: &PlaintextType<Testnet3>) -> Input {
+        Input::Internal(FunctionInput {
+            identifier: self.identifier(name),
+            mode: Mode::None,
+            type_: self.plaintext_type(type_),
+            span: Default::default(),
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    /// Returns the Leo output of a closure output, or `None` if it has no Leo counterpart.
+    fn register_output(&self, type_: &RegisterType<Testnet3>) -> Option<Output> {
+        match type_ {
+            RegisterType::Plaintext(type_) => Some(Output::Internal(FunctionOutput {
+                mode: Mode::None,
+                type_: self.plaintext_type(type_),
+                span: Default::default(),
+                id: self.node_builder.next_id(),
+            })),
+            RegisterType::Record(record) => self.output(&ValueType::Record(*record)),
+            RegisterType::ExternalRecord(locator) => self.output(&ValueType::ExternalRecord(*locator)),
+            RegisterType::Future(_) => None,
+        }
+    }
+
+    /// Returns the Leo output of a function output, or `None` if it has no Leo counterpart.
+    /// Note that futures are omitted, since they are introduced by code generation.
+    fn output(&self, type_: &ValueType<Testnet3>) -> Option<Output> {
+        let (mode, type_) = match type_ {
+            ValueType::Constant(type_) => (Mode::Constant, self.plaintext_type(type_)),
+            ValueType::Public(type_) => (Mode::Public, self.plaintext_type(type_)),
+            ValueType::Private(type_) => (Mode::Private, self.plaintext_type(type_)),
+            ValueType::Record(record) => (Mode::None, Type::Identifier(self.identifier(record))),
+            ValueType::ExternalRecord(locator) => {
+                return Some(Output::External(External {
+                    identifier: self.identifier("output"),
+                    program_name: self.identifier(locator.program_id().name()),
+                    record: self.identifier(locator.resource()),
+                    span: Default::default(),
+                    id: self.node_builder.next_id(),
+                }));
+            }
+            ValueType::Future(_) => return None,
+        };
+        Some(Output::Internal(FunctionOutput {
+            mode,
+            type_,
+            span: Default::default(),
+            id: self.node_builder.next_id(),
+        }))
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::SymbolTable;
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, InputError};
+use leo_span::sym;
+
+/// Checks the sections of an input file against the transitions of a program.
+pub struct InputValidator<'a> {
+    /// The symbol table of the program, which declares its transitions, structs, and records.
+    symbol_table: &'a SymbolTable,
+    /// The error handler.
+    handler: &'a Handler,
+}
+
+impl<'a> InputValidator<'a> {
+    pub fn new(symbol_table: &'a SymbolTable, handler: &'a Handler) -> Self {
+        Self { symbol_table, handler }
+    }
+
+    /// Checks the definitions of `section` against the inputs of the transition that it names.
+    pub fn validate_section(&self, section: &Section) {
+        let function = match self.symbol_table.lookup_fn_symbol(section.name) {
+            Some(function) if function.variant == Variant::Transition => function,
+            _ => {
+                let transitions = self
+                    .symbol_table
+                    .functions
+                    .iter()
+                    .filter(|(_, function)| function.variant == Variant::Transition)
+                    .map(|(name, _)| *name)
+                    .collect::<Vec<_>>();
+                self.handler.emit_err(InputError::unexpected_section(&transitions[..], section.name, section.span));
+                return;
+            }
+        };
+
+        // The definitions are the inputs of the transition, in order.
+        if section.definitions.len() != function.input.len() {
+            self.handler.emit_err(InputError::unexpected_input_count(
+                section.name,
+                function.input.len(),
+                section.definitions.len(),
+                section.span,
+            ));
+            return;
+        }
+        for (definition, input) in section.definitions.iter().zip(function.input.iter()) {
+            let expected = input.type_();
+            if !definition.type_.eq_flat(&expected) {
+                self.handler.emit_err(InputError::unexpected_type(&expected, &definition.type_, definition.span));
+                continue;
+            }
+            if visibility(definition.mode) != visibility(input.mode()) {
+                self.handler.emit_err(InputError::unexpected_input_mode(
+                    input.identifier(),
+                    visibility(input.mode()),
+                    visibility(definition.mode),
+                    definition.span,
+                ));
+            }
+            self.validate_value(&definition.type_, &definition.value);
+        }
+    }
+
+    /// Checks that `value` is a value of type `type_`.
+    fn validate_value(&self, type_: &Type, value: &Expression) {
+        match (type_, value) {
+            (Type::Identifier(identifier), value) => self.validate_struct(identifier, value),
+            (Type::Array(array_type), Expression::Array(array)) => match array.elements.len() == array_type.length() {
+                true => {
+                    array.elements.iter().for_each(|element| self.validate_value(array_type.element_type(), element))
+                }
+                false => self.handler.emit_err(InputError::unexpected_array_length(
+                    array_type.length(),
+                    array.elements.len(),
+                    array.span,
+                )),
+            },
+            (Type::Address | Type::Boolean | Type::Field | Type::Group | Type::Integer(_), value) => {
+                if let Err(err) = InputValue::try_from((type_.clone(), value.clone())) {
+                    self.handler.emit_err(err);
+                }
+            }
+            (Type::Scalar, Expression::Literal(Literal::Scalar(..))) => {}
+            (Type::Array(_) | Type::Scalar, value) => {
+                self.handler.emit_err(InputError::unexpected_type(type_, value, value.span()))
+            }
+            _ => {}
+        }
+    }
+
+    /// Checks that `value` initializes each member of the struct or record `identifier`, and nothing else.
+    fn validate_struct(&self, identifier: &Identifier, value: &Expression) {
+        let struct_ = match self.symbol_table.lookup_struct(identifier.name) {
+            Some(struct_) => struct_,
+            None => return self.handler.emit_err(InputError::unknown_struct(identifier, identifier.span)),
+        };
+        let expression = match value {
+            Expression::Struct(expression) if expression.name.name == identifier.name => expression,
+            value => return self.handler.emit_err(InputError::unexpected_type(identifier, value, value.span())),
+        };
+
+        // The value of a record also initializes its nonce.
+        let nonce = struct_.is_record.then(|| (Identifier::new(sym::_nonce, Default::default()), Type::Group));
+        let members = struct_
+            .members
+            .iter()
+            .map(|member| (member.identifier, member.type_.clone()))
+            .chain(nonce)
+            .collect::<Vec<_>>();
+        for (member, type_) in members.iter() {
+            match expression.members.iter().find(|initializer| initializer.identifier.name == member.name) {
+                Some(StructVariableInitializer { expression: Some(value), .. }) => self.validate_value(type_, value),
+                Some(initializer) => {
+                    self.handler.emit_err(InputError::illegal_expression(initializer, initializer.span))
+                }
+                None => self.handler.emit_err(InputError::missing_struct_member(identifier, member, expression.span)),
+            }
+        }
+        for initializer in expression.members.iter() {
+            if !members.iter().any(|(member, _)| member.name == initializer.identifier.name) {
+                self.handler.emit_err(InputError::unknown_struct_member(
+                    identifier,
+                    initializer.identifier,
+                    initializer.span,
+                ));
+            }
+        }
+    }
+}
+
+/// Returns the visibility of a value declared with `mode`, which is private if no mode is declared.
+fn visibility(mode: Mode) -> Mode {
+    match mode {
+        Mode::None => Mode::Private,
+        mode => mode,
+    }
+}
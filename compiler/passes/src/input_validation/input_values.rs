@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The values of a JSON or TOML input file, which maps the name of each transition to the values of its inputs.
+
+use crate::SymbolTable;
+
+use leo_ast::{
+    ArrayExpression,
+    Definition,
+    Expression,
+    GroupCoordinate,
+    GroupLiteral,
+    GroupTuple,
+    Identifier,
+    InputAst,
+    IntegerType,
+    Literal,
+    NodeBuilder,
+    NodeID,
+    Section,
+    StructExpression,
+    StructVariableInitializer,
+    Type,
+    Variant,
+};
+use leo_errors::{InputError, Result};
+use leo_span::{sym, Span, Symbol};
+
+use serde_json::Value;
+
+/// Reads the values of a JSON or TOML input file as an input AST, with a section for each transition that is given
+/// values. The values are typed by the inputs of the transitions declared in `symbol_table`.
+pub fn input_ast_from_values(
+    values: &Value,
+    symbol_table: &SymbolTable,
+    node_builder: &NodeBuilder,
+) -> Result<InputAst> {
+    let reader = ValueReader { symbol_table, node_builder };
+    let sections = values
+        .as_object()
+        .into_iter()
+        .flatten()
+        .map(|(function, values)| reader.section(function, values))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(InputAst { sections })
+}
+
+/// Reads values as expressions of the types declared in the symbol table.
+struct ValueReader<'a> {
+    symbol_table: &'a SymbolTable,
+    node_builder: &'a NodeBuilder,
+}
+
+impl ValueReader<'_> {
+    /// Reads the values of the inputs of `function`, in the order in which they are declared.
+    fn section(&self, function: &str, values: &Value) -> Result<Section> {
+        let name = Symbol::intern(function);
+        let transition = match self.symbol_table.lookup_fn_symbol(name) {
+            Some(transition) if transition.variant == Variant::Transition => transition,
+            _ => return Err(InputError::unknown_transition(function).into()),
+        };
+        let definitions = transition
+            .input
+            .iter()
+            .map(|input| {
+                let type_ = input.type_();
+                let path = format!("{function}.{}", input.identifier());
+                let value = values
+                    .get(input.identifier().to_string())
+                    .ok_or_else(|| InputError::missing_input_value(&path, &type_))?;
+                Ok(Definition {
+                    mode: input.mode(),
+                    value: self.expression(&path, &type_, value)?,
+                    type_,
+                    name: input.identifier(),
+                    span: Default::default(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Section { name, definitions, span: Default::default() })
+    }
+
+    /// Reads `value` as an expression of type `type_`. The path of the value, e.g. `main.a[0].b`, is used in errors.
+    fn expression(&self, path: &str, type_: &Type, value: &Value) -> Result<Expression> {
+        let invalid = || InputError::invalid_input_value(path, type_, value);
+        let (span, id) = (Span::default(), self.node_builder.next_id());
+        let literal = match (type_, value) {
+            (Type::Address, Value::String(address)) if address.starts_with("aleo1") => {
+                Literal::Address(address.clone(), span, id)
+            }
+            (Type::Boolean, Value::Bool(boolean)) => Literal::Boolean(*boolean, span, id),
+            (Type::Boolean, Value::String(boolean)) if boolean == "true" || boolean == "false" => {
+                Literal::Boolean(boolean == "true", span, id)
+            }
+            (Type::Integer(integer_type), value) => {
+                let digits = digits(type_, value).filter(|digits| integer_in_range(digits, integer_type));
+                Literal::Integer(*integer_type, digits.ok_or_else(invalid)?, span, id)
+            }
+            (Type::Field, value) => Literal::Field(digits(type_, value).ok_or_else(invalid)?, span, id),
+            (Type::Scalar, value) => Literal::Scalar(digits(type_, value).ok_or_else(invalid)?, span, id),
+            (Type::Group, value) => {
+                let group = match digits(type_, value) {
+                    Some(digits) => GroupLiteral::Single(digits, span, id),
+                    None => GroupLiteral::Tuple(coordinates(value, span, id).ok_or_else(invalid)?),
+                };
+                Literal::Group(Box::new(group))
+            }
+            (Type::Array(array_type), Value::Array(elements)) => {
+                let elements = elements
+                    .iter()
+                    .enumerate()
+                    .map(|(index, element)| {
+                        self.expression(&format!("{path}[{index}]"), array_type.element_type(), element)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                return Ok(Expression::Array(ArrayExpression { elements, span, id }));
+            }
+            (Type::Identifier(identifier), value) => {
+                let struct_ = self
+                    .symbol_table
+                    .lookup_struct(identifier.name)
+                    .ok_or_else(|| InputError::unknown_input_type(path, identifier))?;
+                let members = value.as_object().ok_or_else(invalid)?;
+
+                // The value of a record also initializes its nonce.
+                let nonce = struct_.is_record.then_some((sym::_nonce, Type::Group));
+                let members = struct_
+                    .members
+                    .iter()
+                    .map(|member| (member.identifier.name, member.type_.clone()))
+                    .chain(nonce)
+                    .map(|(name, type_)| {
+                        let path = format!("{path}.{name}");
+                        let value = members
+                            .get(&name.to_string())
+                            .ok_or_else(|| InputError::missing_input_value(&path, &type_))?;
+                        Ok(StructVariableInitializer {
+                            identifier: Identifier::new(name, self.node_builder.next_id()),
+                            expression: Some(self.expression(&path, &type_, value)?),
+                            span,
+                            id: self.node_builder.next_id(),
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                return Ok(Expression::Struct(StructExpression { name: *identifier, members, span, id }));
+            }
+            _ => return Err(invalid().into()),
+        };
+        Ok(Expression::Literal(literal))
+    }
+}
+
+/// Returns the digits of a number of type `type_`, which may be written as a JSON number or as a string with an
+/// optional type suffix and underscores, e.g. `"1_000u64"`.
+fn digits(type_: &Type, value: &Value) -> Option<String> {
+    let digits = match value {
+        Value::Number(number) if number.is_i64() || number.is_u64() => number.to_string(),
+        Value::String(string) => string.strip_suffix(&type_.to_string()).unwrap_or(string).replace('_', ""),
+        _ => return None,
+    };
+    let unsigned = digits.strip_prefix('-').unwrap_or(&digits);
+    (!unsigned.is_empty() && unsigned.chars().all(|c| c.is_ascii_digit())).then_some(digits)
+}
+
+/// Returns whether `digits` is in the range of `integer_type`.
+fn integer_in_range(digits: &str, integer_type: &IntegerType) -> bool {
+    use IntegerType::*;
+    match integer_type {
+        U8 => digits.parse::<u8>().is_ok(),
+        U16 => digits.parse::<u16>().is_ok(),
+        U32 => digits.parse::<u32>().is_ok(),
+        U64 => digits.parse::<u64>().is_ok(),
+        U128 => digits.parse::<u128>().is_ok(),
+        I8 => digits.parse::<i8>().is_ok(),
+        I16 => digits.parse::<i16>().is_ok(),
+        I32 => digits.parse::<i32>().is_ok(),
+        I64 => digits.parse::<i64>().is_ok(),
+        I128 => digits.parse::<i128>().is_ok(),
+    }
+}
+
+/// Reads a group written as coordinates, e.g. `"(0, 1)group"` or `"(0, +)"`.
+fn coordinates(value: &Value, span: Span, id: NodeID) -> Option<GroupTuple> {
+    let string = value.as_str()?;
+    let (x, y) =
+        string.strip_suffix("group").unwrap_or(string).strip_prefix('(')?.strip_suffix(')')?.split_once(',')?;
+    let coordinate = |coordinate: &str| match coordinate.trim() {
+        "+" => Some(GroupCoordinate::SignHigh),
+        "-" => Some(GroupCoordinate::SignLow),
+        "_" => Some(GroupCoordinate::Inferred),
+        number => digits(&Type::Field, &Value::String(number.to_string()))
+            .map(|number| GroupCoordinate::Number(number, Default::default())),
+    };
+    Some(GroupTuple { x: coordinate(x)?, y: coordinate(y)?, span, id })
+}
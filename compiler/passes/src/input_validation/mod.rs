@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The input validation pass checks the sections of an input file against the signatures of the transitions in the
+//! symbol table, so that a malformed input file is reported with the location of its mistakes before the program is
+//! run, rather than by snarkVM.
+//!
+//! Each section must name a transition and define a value for each of its inputs, in order, with the type and mode of
+//! the input. The values of structs and records must initialize exactly their declared members, along with the
+//! `_nonce` of a record.
+//!
+//! The values of a JSON or TOML input file are read into an input AST by `input_ast_from_values`, so that both kinds of
+//! input files are checked and run the same way.
+
+pub mod input_validator;
+pub use input_validator::*;
+
+pub mod input_values;
+pub use input_values::*;
+
+use crate::{Pass, SymbolTable};
+
+use leo_ast::InputAst;
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for InputValidator<'a> {
+    type Input = (&'a InputAst, &'a SymbolTable, &'a Handler);
+    type Output = Result<()>;
+
+    fn do_pass((input_ast, symbol_table, handler): Self::Input) -> Self::Output {
+        // Only the errors emitted by this pass fail it.
+        let errors = handler.err_count();
+        let validator = InputValidator::new(symbol_table, handler);
+        for section in input_ast.sections.iter() {
+            validator.validate_section(section);
+        }
+        match handler.err_count() > errors {
+            true => handler.last_err().map_err(|e| *e),
+            false => Ok(()),
+        }
+    }
+}
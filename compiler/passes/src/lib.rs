@@ -17,12 +17,18 @@
 #![forbid(unsafe_code)]
 #![doc = include_str!("../README.md")]
 
+pub mod bytecode_optimization;
+pub use bytecode_optimization::*;
+
 pub mod code_generation;
 pub use code_generation::*;
 
 pub mod common;
 pub use common::*;
 
+pub mod common_subexpression_elimination;
+pub use common_subexpression_elimination::*;
+
 pub mod dead_code_elimination;
 pub use dead_code_elimination::*;
 
@@ -35,9 +41,18 @@ pub use flattening::*;
 pub mod function_inlining;
 pub use function_inlining::*;
 
+pub mod import_resolution;
+pub use import_resolution::*;
+
+pub mod input_validation;
+pub use input_validation::*;
+
 pub mod loop_unrolling;
 pub use self::loop_unrolling::*;
 
+pub mod node_id_validation;
+pub use node_id_validation::*;
+
 pub mod pass;
 pub use self::pass::*;
 
@@ -29,20 +29,24 @@ pub use unroll_program::*;
 pub mod unroll_statement;
 pub use unroll_statement::*;
 
+pub mod unrolled_loop;
+pub use unrolled_loop::*;
+
 use crate::{Pass, SymbolTable, TypeTable};
 
 use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
 use leo_errors::{emitter::Handler, Result};
 
 impl<'a> Pass for Unroller<'a> {
-    type Input = (Ast, &'a Handler, &'a NodeBuilder, SymbolTable, &'a TypeTable);
-    type Output = Result<(Ast, SymbolTable)>;
+    type Input = (Ast, &'a Handler, &'a NodeBuilder, SymbolTable, &'a TypeTable, Option<u64>);
+    type Output = Result<(Ast, SymbolTable, Vec<UnrolledLoop>)>;
 
-    fn do_pass((ast, handler, node_builder, st, tt): Self::Input) -> Self::Output {
-        let mut reconstructor = Self::new(st, tt, handler, node_builder);
+    fn do_pass((ast, handler, node_builder, st, tt, max_iterations): Self::Input) -> Self::Output {
+        let mut reconstructor = Self::new(st, tt, handler, node_builder, max_iterations);
         let program = reconstructor.reconstruct_program(ast.into_repr());
         handler.last_err().map_err(|e| *e)?;
 
-        Ok((Ast::new(program), reconstructor.symbol_table.take()))
+        let unrolled_loops = reconstructor.unrolled_loops.into_values().collect();
+        Ok((Ast::new(program), reconstructor.symbol_table.take(), unrolled_loops))
     }
 }
@@ -26,10 +26,21 @@ use leo_errors::LeoError;
 pub(crate) trait LoopBound:
     Add<Output = Self> + Copy + Display + One + PartialOrd + TryFrom<Value, Error = LeoError>
 {
+    /// Returns the number of values between `self` and `other`, excluding one of them.
+    fn distance(self, other: Self) -> u128;
 }
 
-impl LoopBound for i128 {}
-impl LoopBound for u128 {}
+impl LoopBound for i128 {
+    fn distance(self, other: Self) -> u128 {
+        self.abs_diff(other)
+    }
+}
+
+impl LoopBound for u128 {
+    fn distance(self, other: Self) -> u128 {
+        self.abs_diff(other)
+    }
+}
 
 /// Whether or not a bound is inclusive or exclusive.
 pub(crate) enum Clusivity {
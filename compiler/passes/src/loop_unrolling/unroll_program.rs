@@ -31,6 +31,7 @@ impl ProgramReconstructor for Unroller<'_> {
             functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
             consts: Vec::new(),
             span: input.span,
+            is_stub: input.is_stub,
         }
     }
 
@@ -0,0 +1,31 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_span::{Span, Symbol};
+
+/// A loop that was unrolled, along with the code that unrolling it generated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnrolledLoop {
+    /// The loop variable.
+    pub variable: Symbol,
+    /// The span of the loop.
+    pub span: Span,
+    /// The number of iterations of the loop, summed over each time that it was unrolled.
+    /// Note that a nested loop is unrolled once for each iteration of its enclosing loops.
+    pub trip_count: u128,
+    /// The number of statements that unrolling the loop generated.
+    pub statements: usize,
+}
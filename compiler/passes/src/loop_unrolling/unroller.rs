@@ -21,6 +21,7 @@ use leo_ast::{
     IterationStatement,
     Literal,
     NodeBuilder,
+    NodeID,
     Statement,
     StatementReconstructor,
     Type,
@@ -37,8 +38,11 @@ use crate::{
     RangeIterator,
     SymbolTable,
     TypeTable,
+    UnrolledLoop,
 };
 
+use indexmap::IndexMap;
+
 pub struct Unroller<'a> {
     /// A table of constant variables.
     pub(crate) constant_propagation_table: RefCell<ConstantPropagationTable>,
@@ -54,6 +58,12 @@ pub struct Unroller<'a> {
     pub(crate) node_builder: &'a NodeBuilder,
     /// Are we in the midst of unrolling a loop?
     pub(crate) is_unrolling: bool,
+    /// The maximum total number of iterations to unroll, if any.
+    pub(crate) max_iterations: Option<u64>,
+    /// The total number of iterations unrolled so far, i.e. the sum of the trip counts of the unrolled loops.
+    pub(crate) unrolled_iterations: u128,
+    /// The loops that were unrolled, indexed by the ID of the loop.
+    pub(crate) unrolled_loops: IndexMap<NodeID, UnrolledLoop>,
 }
 
 impl<'a> Unroller<'a> {
@@ -62,6 +72,7 @@ impl<'a> Unroller<'a> {
         type_table: &'a TypeTable,
         handler: &'a Handler,
         node_builder: &'a NodeBuilder,
+        max_iterations: Option<u64>,
     ) -> Self {
         Self {
             constant_propagation_table: RefCell::new(ConstantPropagationTable::default()),
@@ -71,6 +82,9 @@ impl<'a> Unroller<'a> {
             handler,
             node_builder,
             is_unrolling: false,
+            max_iterations,
+            unrolled_iterations: 0,
+            unrolled_loops: IndexMap::new(),
         }
     }
 
@@ -86,8 +100,11 @@ impl<'a> Unroller<'a> {
         self.symbol_table.swap(previous_symbol_table.borrow().lookup_scope_by_index(index).unwrap());
         self.symbol_table.borrow_mut().parent = Some(Box::new(previous_symbol_table.into_inner()));
 
-        // Can assume that CPT has not constructed any scoping yet, since have never seen this scope before
-        self.constant_propagation_table.borrow_mut().insert_block();
+        // Can assume that CPT has not constructed any scoping yet, since have never seen this scope before.
+        // Note that the functions of stubs are not unrolled, so their scopes are constructed empty, to keep the indices aligned.
+        while self.constant_propagation_table.borrow().lookup_scope_by_index(index).is_none() {
+            self.constant_propagation_table.borrow_mut().insert_block();
+        }
 
         // Build CPT recursive scoping structure just like symbol table
         let previous_constant_propagation_table = std::mem::take(&mut self.constant_propagation_table);
@@ -142,6 +159,24 @@ impl<'a> Unroller<'a> {
             Err(s) => return s,
         };
 
+        // Check that unrolling the loop does not exceed the budget.
+        // Note that once the budget is exceeded, the remaining loops are not unrolled, nor reported.
+        let trip_count = match (start <= stop, input.inclusive) {
+            (true, true) => start.distance(stop).saturating_add(1),
+            (true, false) => start.distance(stop),
+            (false, _) => 0,
+        };
+        if let Some(max_iterations) = self.max_iterations {
+            if self.unrolled_iterations > max_iterations as u128 {
+                return Statement::dummy(input.span, self.node_builder.next_id());
+            }
+            self.unrolled_iterations = self.unrolled_iterations.saturating_add(trip_count);
+            if self.unrolled_iterations > max_iterations as u128 {
+                self.emit_err(LoopUnrollerError::unroll_budget_exceeded(max_iterations, input.span));
+                return Statement::dummy(input.span, self.node_builder.next_id());
+            }
+        }
+
         // Get the index of the current scope.
         let scope_index = self.current_scope_index();
 
@@ -174,9 +209,32 @@ impl<'a> Unroller<'a> {
         // Exit the scope of the loop body.
         self.exit_scope(previous_scope_index);
 
+        // Record the iterations of the loop, and the statements that they generated.
+        let statements = Self::count_statements(&iter_blocks);
+        let unrolled_loop = self.unrolled_loops.entry(input.id).or_insert(UnrolledLoop {
+            variable: input.variable.name,
+            span: input.span,
+            trip_count: 0,
+            statements: 0,
+        });
+        unrolled_loop.trip_count = unrolled_loop.trip_count.saturating_add(trip_count);
+        unrolled_loop.statements += statements;
+
         iter_blocks
     }
 
+    /// Returns the number of statements in `statement`, excluding the blocks that contain them.
+    fn count_statements(statement: &Statement) -> usize {
+        match statement {
+            Statement::Block(block) => block.statements.iter().map(Self::count_statements).sum(),
+            Statement::Conditional(conditional) => {
+                1 + conditional.then.statements.iter().map(Self::count_statements).sum::<usize>()
+                    + conditional.otherwise.as_deref().map_or(0, Self::count_statements)
+            }
+            _ => 1,
+        }
+    }
+
     /// A helper function to unroll a single iteration an IterationStatement.
     fn unroll_single_iteration<I: LoopBound>(&mut self, input: &IterationStatement, iteration_count: I) -> Statement {
         // Create a scope for a single unrolling of the `IterationStatement`.
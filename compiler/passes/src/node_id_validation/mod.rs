@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The node ID validation pass checks that each node of an AST has a unique `NodeID`.
+//! The parser guarantees this for the ASTs that it produces, but an AST that is constructed or deserialized
+//! by a user of the compiler must be validated before it is compiled, since the passes use `NodeID`s to
+//! associate nodes with their types.
+//!
+//! The pass returns the smallest `NodeID` that is greater than the `NodeID` of every node,
+//! from which the compiler creates the nodes that the later passes introduce.
+
+pub mod node_id_validator;
+pub use node_id_validator::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, NodeID, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for NodeIdValidator<'a> {
+    type Input = (&'a Ast, &'a Handler);
+    type Output = Result<NodeID>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut validator = NodeIdValidator::new(handler);
+        validator.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(validator.next_id)
+    }
+}
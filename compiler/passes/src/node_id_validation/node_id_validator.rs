@@ -15,32 +15,38 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use leo_ast::*;
+use leo_errors::{emitter::Handler, AstError};
 
-use std::{collections::HashSet, marker::PhantomData};
+use std::collections::HashSet;
 
-/// A utility that checks that each node in the AST has a unique `NodeID`.
-pub struct CheckUniqueNodeIds<'a> {
+/// A validator that checks that each node in the AST has a unique `NodeID`.
+pub struct NodeIdValidator<'a> {
+    /// The handler used to report duplicate `NodeID`s.
+    handler: &'a Handler,
     /// The set of `NodeID`s that have been seen.
     seen: HashSet<NodeID>,
-    _phantom: PhantomData<&'a ()>,
+    /// The smallest `NodeID` that is greater than every `NodeID` seen.
+    pub(crate) next_id: NodeID,
 }
 
-impl<'a> CheckUniqueNodeIds<'a> {
-    /// Creates a new `CheckUniqueNodeId`.
-    pub fn new() -> Self {
-        Self { seen: HashSet::new(), _phantom: PhantomData }
+impl<'a> NodeIdValidator<'a> {
+    /// Creates a new `NodeIdValidator`.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self { handler, seen: HashSet::new(), next_id: 0 }
     }
 
     /// Checks that the given `NodeID` has not been seen before.
     pub fn check(&mut self, id: NodeID) {
         if !self.seen.insert(id) {
-            panic!("Duplicate NodeID found in the AST: {}", id);
+            self.handler.emit_err(AstError::duplicate_node_id(id));
         }
+        self.next_id = self.next_id.max(id + 1);
     }
 
     /// Checks that the given `Type` has a unique `NodeID`.
     pub fn check_ty(&mut self, ty: &'a Type) {
         match ty {
+            Type::Array(array) => self.check_ty(array.element_type()),
             Type::Identifier(identifier) => self.visit_identifier(identifier, &Default::default()),
             Type::Mapping(mapping) => {
                 self.check_ty(&mapping.key);
@@ -56,7 +62,7 @@ impl<'a> CheckUniqueNodeIds<'a> {
     }
 }
 
-impl<'a> ExpressionVisitor<'a> for CheckUniqueNodeIds<'a> {
+impl<'a> ExpressionVisitor<'a> for NodeIdValidator<'a> {
     type AdditionalInput = ();
     type Output = ();
 
@@ -170,7 +176,7 @@ impl<'a> ExpressionVisitor<'a> for CheckUniqueNodeIds<'a> {
     }
 }
 
-impl<'a> StatementVisitor<'a> for CheckUniqueNodeIds<'a> {
+impl<'a> StatementVisitor<'a> for NodeIdValidator<'a> {
     fn visit_assert(&mut self, input: &'a AssertStatement) {
         match &input.variant {
             AssertVariant::Assert(expr) => self.visit_expression(expr, &Default::default()),
@@ -251,7 +257,7 @@ impl<'a> StatementVisitor<'a> for CheckUniqueNodeIds<'a> {
     }
 }
 
-impl<'a> ProgramVisitor<'a> for CheckUniqueNodeIds<'a> {
+impl<'a> ProgramVisitor<'a> for NodeIdValidator<'a> {
     fn visit_struct(&mut self, input: &'a Struct) {
         let Struct { identifier, members, id, .. } = input;
         self.visit_identifier(identifier, &Default::default());
@@ -274,8 +280,11 @@ impl<'a> ProgramVisitor<'a> for CheckUniqueNodeIds<'a> {
     fn visit_function(&mut self, input: &'a Function) {
         let Function { annotations, identifier, input, output, block, finalize, id, .. } = input;
         // Check the annotations.
-        for Annotation { identifier, id, .. } in annotations {
+        for Annotation { identifier, arguments, id, .. } in annotations {
             self.visit_identifier(identifier, &Default::default());
+            for argument in arguments {
+                self.visit_identifier(argument, &Default::default());
+            }
             self.check(*id);
         }
         // Check the function name.
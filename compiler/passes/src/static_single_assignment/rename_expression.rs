@@ -24,11 +24,13 @@ use leo_ast::{
     BinaryExpression,
     CallExpression,
     CastExpression,
+    CastKind,
     Expression,
     ExpressionConsumer,
     Identifier,
     Literal,
     MemberAccess,
+    Node,
     Statement,
     Struct,
     StructExpression,
@@ -36,6 +38,7 @@ use leo_ast::{
     TernaryExpression,
     TupleAccess,
     TupleExpression,
+    Type,
     UnaryExpression,
     UnitExpression,
 };
@@ -202,14 +205,41 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
     }
 
     /// Consumes a cast expression, accumulating any statements that are generated.
+    /// Saturating casts are lowered into comparisons and ternary expressions before being consumed, and lossy casts are lowered into checked casts.
     fn consume_cast(&mut self, input: CastExpression) -> Self::Output {
+        // Get the type of the expression being casted.
+        let source_type = self.type_table.get(&input.expression.id());
+
         // Reconstruct the expression being casted.
         let (expression, mut statements) = self.consume_expression(*input.expression);
 
+        if input.kind == CastKind::Saturating {
+            let (source, target) = match (source_type, input.type_) {
+                (Some(Type::Integer(source)), Type::Integer(target)) => (source, target),
+                _ => unreachable!("Type checking guarantees that saturating casts are between integer types."),
+            };
+            // Consume the lowered expression, accumulating any statements produced.
+            let (place, mut stmts) =
+                self.consume_expression(self.lower_saturating_cast(expression, source, target, input.span, input.id));
+            statements.append(&mut stmts);
+            return (place, statements);
+        }
+
+        if input.kind == CastKind::Lossy {
+            let source = match source_type {
+                Some(source) => source,
+                None => unreachable!("Type checking guarantees that all expressions have a type."),
+            };
+            let (place, mut stmts) = self.lower_lossy_cast(expression, source, input.type_, input.span, input.id);
+            statements.append(&mut stmts);
+            return (place, statements);
+        }
+
         // Construct and accumulate a unique assignment statement storing the result of the cast expression.
         let (place, statement) = self.unique_simple_assign_statement(Expression::Cast(CastExpression {
             expression: Box::new(expression),
             type_: input.type_,
+            kind: input.kind,
             span: input.span,
             id: input.id,
         }));
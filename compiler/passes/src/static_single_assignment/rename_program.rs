@@ -137,6 +137,7 @@ impl ProgramScopeConsumer for StaticSingleAssigner<'_> {
             functions: input.functions.into_iter().map(|(i, f)| (i, self.consume_function(f))).collect(),
             consts: input.consts,
             span: input.span,
+            is_stub: input.is_stub,
         }
     }
 }
@@ -154,7 +155,11 @@ impl ProgramConsumer for StaticSingleAssigner<'_> {
             program_scopes: input
                 .program_scopes
                 .into_iter()
-                .map(|(name, scope)| (name, self.consume_program_scope(scope)))
+                .map(|(name, scope)| match scope.is_stub {
+                    // Stubs do not have any function bodies to consume.
+                    true => (name, scope),
+                    false => (name, self.consume_program_scope(scope)),
+                })
                 .collect(),
         }
     }
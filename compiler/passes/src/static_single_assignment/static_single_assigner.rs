@@ -16,7 +16,24 @@
 
 use crate::{Assigner, RenameTable, SymbolTable, TypeTable};
 
-use leo_ast::{Expression, Identifier, Node, NodeBuilder, Statement};
+use leo_ast::{
+    BinaryExpression,
+    BinaryOperation,
+    CastExpression,
+    CastKind,
+    Expression,
+    ExpressionConsumer,
+    Identifier,
+    IntegerType,
+    Literal,
+    Node,
+    NodeBuilder,
+    NodeID,
+    Statement,
+    TernaryExpression,
+    Type,
+};
+use leo_span::Span;
 
 pub struct StaticSingleAssigner<'a> {
     /// A counter used to generate unique node IDs.
@@ -69,6 +86,236 @@ impl<'a> StaticSingleAssigner<'a> {
         self.assigner.simple_assign_statement(identifier, rhs, self.node_builder.next_id())
     }
 
+    /// Lowers a saturating cast of `expression` from `source` to `target` into comparisons against the bounds of `target` and ternary expressions,
+    /// which clamp the value before it is casted with a checked cast.
+    /// For example, `x as_saturating u8`, where `x` is an `i16`, is lowered into `(x > 255i16 ? 255i16 : (x < 0i16 ? 0i16 : x)) as u8`.
+    /// Note that only the bounds of `target` that lie strictly within the bounds of `source` are checked, so each bound is a value of `source`.
+    /// The resulting expression has the ID `id` and must itself be consumed.
+    pub(crate) fn lower_saturating_cast(
+        &self,
+        expression: Expression,
+        source: IntegerType,
+        target: IntegerType,
+        span: Span,
+        id: NodeID,
+    ) -> Expression {
+        let mut clamped = expression.clone();
+
+        // Select the bound if the value exceeds it, checking the lower bound before the upper bound.
+        let bounds = [
+            (target.min_value() > source.min_value(), BinaryOperation::Lt, target.min_value().to_string()),
+            (target.max_value() < source.max_value(), BinaryOperation::Gt, target.max_value().to_string()),
+        ];
+        for (_, op, bound) in bounds.into_iter().filter(|(is_checked, ..)| *is_checked) {
+            let condition = self.binary(
+                op,
+                self.operand(&expression, Type::Integer(source)),
+                self.integer(source, &bound, span),
+                Type::Boolean,
+                span,
+            );
+            clamped = self.ternary(condition, self.integer(source, &bound, span), clamped, source, span);
+        }
+
+        // The resulting expression takes the place of the original cast expression.
+        let mut result = self.cast(clamped, target, span);
+        result.set_id(id);
+
+        result
+    }
+
+    /// Lowers a lossy cast of `expression` from `source` to `target`, which keeps the low bits of the value, since snarkVM does not support `cast.lossy` yet.
+    /// The value is truncated with a bitwise AND, and a value that does not fit in `target` is reinterpreted with comparisons and ternary expressions
+    /// that select an operand in bounds for a checked cast. Since both branches of a ternary expression are evaluated, neither may overflow.
+    /// For example, `x as_lossy i8`, where `x` is a `u16`, is lowered into `u = x & 255u16; h = u > 127u16; w = (h ? 255u16 - u : u) as i8; h ? -1i8 - w : w`.
+    /// Casts from booleans, and casts that are exact, are lowered into checked casts.
+    /// Returns the resulting expression, which has the ID `id`, along with the statements that compute it.
+    pub(crate) fn lower_lossy_cast(
+        &mut self,
+        expression: Expression,
+        source: Type,
+        target: Type,
+        span: Span,
+        id: NodeID,
+    ) -> (Expression, Vec<Statement>) {
+        let mut statements = Vec::new();
+        let mut result = match (source, target) {
+            // The low bit of the value.
+            (Type::Integer(source), Type::Boolean) => {
+                let one = self.integer(source, 1, span);
+                let bit = self.binary(BinaryOperation::BitwiseAnd, expression, one, Type::Integer(source), span);
+                let bit = self.bind(bit, &mut statements);
+                self.binary(BinaryOperation::Eq, bit, self.integer(source, 1, span), Type::Boolean, span)
+            }
+            (Type::Integer(source), Type::Integer(target)) => {
+                self.lower_integer_lossy_cast(expression, source, target, span, &mut statements)
+            }
+            (_, target) => Expression::Cast(CastExpression {
+                expression: Box::new(expression),
+                type_: target.clone(),
+                kind: CastKind::Checked,
+                span,
+                id: self.typed_id(target),
+            }),
+        };
+
+        // The resulting expression takes the place of the original cast expression.
+        result.set_id(id);
+        let (place, mut stmts) = self.consume_expression(result);
+        statements.append(&mut stmts);
+
+        (place, statements)
+    }
+
+    /// Lowers a lossy cast of the integer `x` from `source` to `target`, accumulating the statements of its intermediate values.
+    fn lower_integer_lossy_cast(
+        &mut self,
+        x: Expression,
+        source: IntegerType,
+        target: IntegerType,
+        span: Span,
+        statements: &mut Vec<Statement>,
+    ) -> Expression {
+        // The mask of the bits of `target`, which is a value of `source` whenever it is used.
+        let mask = u128::MAX >> (128 - target.bits());
+
+        // The bits of `target` as a non-negative value of `source`, unless the value needs no truncation or reinterpretation.
+        let bits = if target.bits() < source.bits() {
+            let mask = self.integer(source, mask, span);
+            Some(self.binary(BinaryOperation::BitwiseAnd, x.clone(), mask, Type::Integer(source), span))
+        } else if source.is_signed() && !target.is_signed() {
+            // A negative value `x` wraps to `MAX - (-1 - x)`, where `-1 - x` is non-negative and does not overflow.
+            let zero = self.integer(source, 0, span);
+            let negative = self.binary(BinaryOperation::Lt, x.clone(), zero, Type::Boolean, span);
+            let negative = self.bind(negative, statements);
+            let minus_one = self.integer(source, -1, span);
+            let flipped = self.binary(
+                BinaryOperation::Sub,
+                minus_one,
+                self.operand(&x, Type::Integer(source)),
+                Type::Integer(source),
+                span,
+            );
+            let magnitude = self.ternary(self.operand(&negative, Type::Boolean), flipped, x, source, span);
+            let magnitude = self.bind(magnitude, statements);
+            let casted = self.bind(self.cast(magnitude, target, span), statements);
+            let wrapped = self.binary(
+                BinaryOperation::Sub,
+                self.integer(target, target.max_value(), span),
+                self.operand(&casted, Type::Integer(target)),
+                Type::Integer(target),
+                span,
+            );
+            return self.ternary(negative, wrapped, casted, target, span);
+        } else if !source.is_signed() && target.is_signed() && target.bits() == source.bits() {
+            Some(x.clone())
+        } else {
+            None
+        };
+
+        let bits = match (bits, target.is_signed()) {
+            (None, _) => return self.cast(x, target, span),
+            (Some(bits), false) => return self.cast(bits, target, span),
+            (Some(bits), true) => self.bind(bits, statements),
+        };
+
+        // Bits with the sign bit set are the value `-1 - (mask - bits)`, where `mask - bits` is within the bounds of `target`.
+        let sign = u128::MAX >> (129 - target.bits());
+        let high =
+            self.binary(BinaryOperation::Gt, bits.clone(), self.integer(source, sign, span), Type::Boolean, span);
+        let high = self.bind(high, statements);
+        let flipped = self.binary(
+            BinaryOperation::Sub,
+            self.integer(source, mask, span),
+            self.operand(&bits, Type::Integer(source)),
+            Type::Integer(source),
+            span,
+        );
+        let magnitude = self.ternary(
+            self.operand(&high, Type::Boolean),
+            flipped,
+            self.operand(&bits, Type::Integer(source)),
+            source,
+            span,
+        );
+        let magnitude = self.bind(magnitude, statements);
+        let casted = self.bind(self.cast(magnitude, target, span), statements);
+        let negative = self.binary(
+            BinaryOperation::Sub,
+            self.integer(target, -1, span),
+            self.operand(&casted, Type::Integer(target)),
+            Type::Integer(target),
+            span,
+        );
+        self.ternary(high, negative, casted, target, span)
+    }
+
+    /// Consumes `expression`, accumulating the statements that compute it, and returns the place that stores it.
+    fn bind(&mut self, expression: Expression, statements: &mut Vec<Statement>) -> Expression {
+        let (place, mut stmts) = self.consume_expression(expression);
+        statements.append(&mut stmts);
+        place
+    }
+
+    /// Returns a new node ID with the type `type_`.
+    fn typed_id(&self, type_: Type) -> NodeID {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, type_);
+        id
+    }
+
+    /// Returns a copy of the operand `expression`, of type `type_`, with a new node ID.
+    fn operand(&self, expression: &Expression, type_: Type) -> Expression {
+        let mut operand = expression.clone();
+        operand.set_id(self.typed_id(type_));
+        operand
+    }
+
+    /// Returns the integer `value` of type `type_`.
+    fn integer(&self, type_: IntegerType, value: impl ToString, span: Span) -> Expression {
+        Expression::Literal(Literal::Integer(type_, value.to_string(), span, self.typed_id(Type::Integer(type_))))
+    }
+
+    /// Returns the binary expression `left op right` of type `type_`.
+    fn binary(&self, op: BinaryOperation, left: Expression, right: Expression, type_: Type, span: Span) -> Expression {
+        Expression::Binary(BinaryExpression {
+            left: Box::new(left),
+            right: Box::new(right),
+            op,
+            span,
+            id: self.typed_id(type_),
+        })
+    }
+
+    /// Returns the ternary expression `condition ? if_true : if_false` of type `type_`.
+    fn ternary(
+        &self,
+        condition: Expression,
+        if_true: Expression,
+        if_false: Expression,
+        type_: IntegerType,
+        span: Span,
+    ) -> Expression {
+        Expression::Ternary(TernaryExpression {
+            condition: Box::new(condition),
+            if_true: Box::new(if_true),
+            if_false: Box::new(if_false),
+            span,
+            id: self.typed_id(Type::Integer(type_)),
+        })
+    }
+
+    /// Returns the checked cast of `expression` to `target`.
+    fn cast(&self, expression: Expression, target: IntegerType, span: Span) -> Expression {
+        Expression::Cast(CastExpression {
+            expression: Box::new(expression),
+            type_: Type::Integer(target),
+            kind: CastKind::Checked,
+            span,
+            id: self.typed_id(Type::Integer(target)),
+        })
+    }
+
     /// Constructs a simple assign statement for `expr` with a unique name.
     /// For example, `expr` is transformed into `$var$0 = expr;`.
     /// The lhs is guaranteed to be unique with respect to the `Assigner`.
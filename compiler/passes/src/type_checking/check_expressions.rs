@@ -29,7 +29,11 @@ fn return_incorrect_type(t1: Option<Type>, t2: Option<Type>, expected: &Option<T
         (Some(t1), Some(t2)) if t1 == t2 => Some(t1),
         (Some(t1), Some(t2)) => {
             if let Some(expected) = expected {
-                if &t1 != expected { Some(t1) } else { Some(t2) }
+                if &t1 != expected {
+                    Some(t1)
+                } else {
+                    Some(t2)
+                }
             } else {
                 Some(t1)
             }
@@ -593,6 +597,11 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                         self.emit_err(TypeCheckerError::cannot_call_external_inline_function(input.span));
                     }
 
+                    // Check that the call is not to an external standard function, which is compiled to a closure.
+                    if func.variant == Variant::Standard && input.external.is_some() {
+                        self.emit_err(TypeCheckerError::cannot_call_external_standard_function(input.span));
+                    }
+
                     let ret = self.assert_and_return_type(func.output_type, expected, input.span());
 
                     // Check number of function arguments.
@@ -634,6 +643,18 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
         let expression_type = self.visit_expression(&input.expression, &None);
         self.assert_castable_type(&expression_type, input.expression.span());
 
+        // Saturating casts are lowered into comparisons against the bounds of the target type, so both types must be integers.
+        if input.kind == CastKind::Saturating {
+            self.assert_int_type(&Some(input.type_.clone()), input.span());
+            self.assert_int_type(&expression_type, input.expression.span());
+        }
+
+        // Lossy casts are lowered into checked casts of the low bits of the value, so both types must be booleans or integers.
+        if input.kind == CastKind::Lossy {
+            self.assert_bool_int_type(&Some(input.type_.clone()), input.span());
+            self.assert_bool_int_type(&expression_type, input.expression.span());
+        }
+
         // Check that the expected type matches the target type.
         Some(self.assert_and_return_type(input.type_.clone(), expected, input.span()))
     }
@@ -28,24 +28,18 @@ use std::collections::HashSet;
 
 impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
     fn visit_program(&mut self, input: &'a Program) {
-        match self.is_imported {
-            // If the program is imported, then it is not allowed to import any other programs.
-            true => {
-                input.imports.values().for_each(|(_, span)| {
-                    self.emit_err(TypeCheckerError::imported_program_cannot_import_program(*span))
-                });
-            }
-            // Otherwise, typecheck the imported programs.
-            false => {
-                // Set `self.is_imported`.
-                let previous_is_imported = core::mem::replace(&mut self.is_imported, true);
-
-                // Typecheck the imported programs.
-                input.imports.values().for_each(|import| self.visit_import(&import.0));
-
-                // Set `self.is_imported` to its previous state.
-                self.is_imported = previous_is_imported;
-            }
+        // Typecheck the imported programs.
+        // Note that the compiler resolves imports transitively, storing every dependency as an import of the main program.
+        // Consequently, the imports of an imported program are unresolved and are checked as imports of the main program.
+        if !self.is_imported {
+            // Set `self.is_imported`.
+            let previous_is_imported = core::mem::replace(&mut self.is_imported, true);
+
+            // Typecheck the imported programs.
+            input.imports.values().for_each(|import| self.visit_import(&import.0));
+
+            // Set `self.is_imported` to its previous state.
+            self.is_imported = previous_is_imported;
         }
 
         // Typecheck the program scopes.
@@ -79,6 +73,11 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
             ));
         }
 
+        // Stubs only declare the interface of a program, so there are no function bodies to typecheck.
+        if input.is_stub {
+            return;
+        }
+
         // Typecheck each function definitions.
         let mut transition_count = 0;
         for (_, function) in input.functions.iter() {
@@ -205,10 +204,21 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
 
     fn visit_function(&mut self, function: &'a Function) {
         // Check that the function's annotations are valid.
-        // Note that Leo does not natively support any specific annotations.
+        // Note that `@inline(always)` and `@inline(never)` are the only annotations that Leo supports.
         for annotation in function.annotations.iter() {
-            // TODO: Change to compiler warning.
-            self.emit_err(TypeCheckerError::unknown_annotation(annotation, annotation.span))
+            // Note that the `inline` annotation takes exactly one argument, `always` or `never`.
+            let has_valid_argument = match annotation.arguments.as_slice() {
+                [argument] => argument.name == sym::always || argument.name == sym::never,
+                _ => false,
+            };
+            if annotation.identifier.name != sym::inline {
+                // TODO: Change to compiler warning.
+                self.emit_err(TypeCheckerError::unknown_annotation(annotation, annotation.span));
+            } else if function.variant != Variant::Inline {
+                self.emit_err(TypeCheckerError::inline_annotation_on_non_inline_function(annotation.span));
+            } else if !has_valid_argument {
+                self.emit_err(TypeCheckerError::invalid_inline_annotation(annotation, annotation.span));
+            }
         }
 
         self.variant = Some(function.variant);
@@ -230,6 +230,8 @@ symbols! {
 
     // general keywords
     As: "as",
+    as_lossy,
+    as_saturating,
     assert,
     assert_eq,
     assert_neq,
@@ -270,6 +272,8 @@ symbols! {
     program,
     block,
     height,
+    always,
+    never,
 }
 
 /// An interned string.
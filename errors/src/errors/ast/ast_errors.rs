@@ -145,4 +145,12 @@ create_messages!(
         msg: format!("failed to convert symbol_table to a json value {error}"),
         help: None,
     }
+
+    /// For when two nodes of an AST share a `NodeID`.
+    @backtraced
+    duplicate_node_id {
+        args: (id: impl Display),
+        msg: format!("The node ID `{id}` is used by more than one node of the AST."),
+        help: Some("Create the nodes of an AST with a single `NodeBuilder`, so that their IDs are unique.".to_string()),
+    }
 );
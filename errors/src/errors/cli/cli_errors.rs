@@ -178,4 +178,18 @@ create_messages!(
         msg: format!("Failed to write file.\nIO Error: {error}"),
         help: None,
     }
+
+    @backtraced
+    failed_to_watch {
+        args: (error: impl Display),
+        msg: format!("Failed to watch the package for changes: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_read_abi {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to read the ABI of the program at `{path}`: {error}"),
+        help: Some("The ABI is written to the `build` directory by `leo build`.".to_string()),
+    }
 );
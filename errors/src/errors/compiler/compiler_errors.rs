@@ -45,9 +45,9 @@ create_messages!(
 
     @formatted
     import_not_found {
-        args: (file_path: impl Display),
-        msg: format!("Attempted to import a file that does not exist `{file_path}`."),
-        help: None,
+        args: (name: impl Display),
+        msg: format!("Attempted to import a program that does not exist `{name}`."),
+        help: Some(format!("Add the program as Leo code in `{name}.leo`, or as Aleo instructions in `{name}.aleo`.")),
     }
 
     @formatted
@@ -70,4 +70,51 @@ create_messages!(
         msg: format!("The program scope name `{program_scope_name}` must match `{file_name}`."),
         help: None,
     }
+
+    @backtraced
+    cyclic_import {
+        args: (path: Vec<impl Display>),
+        msg: {
+            let path_string = path.into_iter().map(|name| format!("`{name}.aleo`")).collect::<Vec<String>>().join(" --> ");
+            format!("Cyclic dependency between programs: {path_string}")
+        },
+        help: None,
+    }
+
+    @formatted
+    conflicting_program_name {
+        args: (name: impl Display),
+        msg: format!("The program `{name}.aleo` is declared more than once."),
+        help: Some("Rename one of the program scopes, or remove the conflicting import.".to_string()),
+    }
+
+    @formatted
+    failed_to_parse_aleo_import {
+        args: (name: impl Display, error: impl Display),
+        msg: format!("Failed to parse the imported Aleo program `{name}.aleo`: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    required_pass_disabled {
+        args: (pass: impl Display),
+        msg: format!("The `{pass}` pass cannot be disabled."),
+        help: Some("Only common subexpression elimination and dead code elimination are optional.".to_string()),
+    }
+
+    /// For when the compiler can't write a file to the provided path.
+    @backtraced
+    file_write_error {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("Cannot write to the provided file path '{path:?}': {error}"),
+        help: None,
+    }
+
+    /// For when an AST given to the compiler does not declare any program scope.
+    @backtraced
+    missing_program_scope {
+        args: (name: impl Display),
+        msg: format!("The AST does not declare a program scope, so it cannot be compiled as `{name}`."),
+        help: Some(format!("Add a program scope `program {name}.aleo {{ ... }}` to the AST.")),
+    }
 );
@@ -55,4 +55,92 @@ create_messages!(
         ),
         help: None,
     }
+
+    /// For when an input of a transition has no value in a JSON or TOML input file.
+    @backtraced
+    missing_input_value {
+        args: (input: impl Display, type_: impl Display),
+        msg: format!("The input `{input}` of type `{type_}` has no value in the input file."),
+        help: None,
+    }
+
+    /// For when a value in a JSON or TOML input file does not have the type of its input.
+    @backtraced
+    invalid_input_value {
+        args: (input: impl Display, type_: impl Display, value: impl Display),
+        msg: format!("The value `{value}` of the input `{input}` is not a valid `{type_}`."),
+        help: None,
+    }
+
+    /// For when the type of an input is neither a literal type nor a struct or record of the program.
+    @backtraced
+    unknown_input_type {
+        args: (input: impl Display, type_: impl Display),
+        msg: format!("The type `{type_}` of the input `{input}` is not declared in the program."),
+        help: None,
+    }
+
+    /// For when a section of an input file does not define a value for each input of its function.
+    @formatted
+    unexpected_input_count {
+        args: (function: impl Display, expected: usize, received: usize),
+        msg: format!("The function `{function}` has {expected} input(s), but its section defines {received} value(s)."),
+        help: Some("The values of a section are the inputs of the function, in order.".to_string()),
+    }
+
+    /// For when a value in an input file does not have the mode of its input.
+    @formatted
+    unexpected_input_mode {
+        args: (input: impl Display, expected: impl Display, received: impl Display),
+        msg: format!("The input `{input}` is {expected}, but its value in the input file is {received}."),
+        help: None,
+    }
+
+    /// For when the type of a value in an input file is not a declared struct or record.
+    @formatted
+    unknown_struct {
+        args: (name: impl Display),
+        msg: format!("A struct or record declaration does not exist for `{name}`."),
+        help: None,
+    }
+
+    /// For when a struct or record value in an input file does not initialize one of its members.
+    @formatted
+    missing_struct_member {
+        args: (struct_: impl Display, member: impl Display),
+        msg: format!("The value of `{struct_}` does not initialize its member `{member}`."),
+        help: None,
+    }
+
+    /// For when a struct or record value in an input file initializes a member that is not declared.
+    @formatted
+    unknown_struct_member {
+        args: (struct_: impl Display, member: impl Display),
+        msg: format!("`{struct_}` does not have a member `{member}`."),
+        help: None,
+    }
+
+    /// For when an array value in an input file does not have the length of its type.
+    @formatted
+    unexpected_array_length {
+        args: (expected: usize, received: usize),
+        msg: format!("Expected an array of {expected} element(s), but the value has {received}."),
+        help: None,
+    }
+
+    /// For when an input file gives values to, or a command runs, a function that is not a transition of the program.
+    @backtraced
+    unknown_transition {
+        args: (function: impl Display),
+        msg: format!("`{function}` is not a transition of the program."),
+        help: None,
+    }
+
+    /// For when a transition that has inputs is run without values for them.
+    @backtraced
+    missing_input_section {
+        args: (function: impl Display),
+        msg: format!("The input file has no values for the inputs of `{function}`."),
+        help: Some("Add them to the input file, or pass them on the command line.".to_string()),
+    }
 );
@@ -313,4 +313,103 @@ create_messages!(
         msg: format!("IO error env file from the provided file path - {error}"),
         help: None,
     }
+
+    @backtraced
+    failed_to_write_manifest {
+        args: (error: impl ErrorArg),
+        msg: format!("Failed to write the manifest file: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    invalid_dependency {
+        args: (name: impl Display),
+        msg: format!("The dependency `{name}` must declare exactly one of a `version` in the registry or a `path`."),
+        help: Some("For example, `\"dependencies\": { \"token\": { \"version\": \"0.1.0\" } }`.".to_string()),
+    }
+
+    @backtraced
+    dependency_not_found {
+        args: (name: impl Display, location: impl Display),
+        msg: format!("Could not find the dependency `{name}` at `{location}`."),
+        help: Some("A dependency is a Leo package, a `<name>.leo` file, or a `<name>.aleo` file.".to_string()),
+    }
+
+    @backtraced
+    registry_not_found {
+        args: (name: impl Display),
+        msg: format!("Could not locate the registry to resolve the dependency `{name}`."),
+        help: Some("Pass the registry directory with `--registry` or the `LEO_REGISTRY` environment variable.".to_string()),
+    }
+
+    @backtraced
+    conflicting_dependency {
+        args: (name: impl Display, first: impl Display, second: impl Display),
+        msg: format!("The dependency `{name}` resolves to both `{first}` and `{second}`."),
+        help: None,
+    }
+
+    @backtraced
+    dependency_checksum_mismatch {
+        args: (name: impl Display, expected: impl Display, found: impl Display),
+        msg: format!("The checksum of the dependency `{name}` is `{found}`, but the lockfile records `{expected}`."),
+        help: Some("The registry entry has changed. Remove the dependency from the lockfile to accept the new contents.".to_string()),
+    }
+
+    @backtraced
+    failed_to_read_lockfile {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("Failed to read the lockfile at {path:?}: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_write_lockfile {
+        args: (error: impl ErrorArg),
+        msg: format!("Failed to write the lockfile: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    unknown_dependency {
+        args: (name: impl Display),
+        msg: format!("The package does not depend on `{name}`."),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_read_workspace {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("Failed to read the workspace manifest at {path:?}: {error}"),
+        help: Some("A workspace manifest lists its members, e.g. `{ \"members\": [\"token\", \"vault\"] }`.".to_string()),
+    }
+
+    @backtraced
+    cyclic_workspace_members {
+        args: (cycle: impl Display),
+        msg: format!("The workspace members import each other in a cycle: {cycle}."),
+        help: None,
+    }
+
+    @backtraced
+    unknown_workspace_member {
+        args: (name: impl Display),
+        msg: format!("The workspace does not have a member with the program `{name}`."),
+        help: None,
+    }
+
+    @backtraced
+    workspace_member_required {
+        args: (),
+        msg: "The command must be run for a member of the workspace.".to_string(),
+        help: Some("Run the command from the directory of a member, or select one with `--package`.".to_string()),
+    }
+
+    /// For when a JSON or TOML input file is malformed.
+    @backtraced
+    failed_to_parse_input_file {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to parse the input file `{path}`: {error}"),
+        help: Some("The input file maps each transition to the values of its inputs, e.g. `{ \"main\": { \"a\": 1 } }`.".to_string()),
+    }
 );
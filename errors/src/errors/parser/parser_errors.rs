@@ -218,7 +218,7 @@ create_messages!(
     leo_imports_only {
         args: (),
         msg: "Invalid import call to non-leo file.",
-        help: Some("Only imports of Leo `.leo` files are currently supported.".to_string()),
+        help: Some("Only imports of Leo `.leo` files and Aleo `.aleo` programs are currently supported.".to_string()),
     }
 
     @formatted
@@ -236,10 +236,10 @@ create_messages!(
     }
 
     @formatted
-    only_one_program_scope_is_allowed {
-        args: (),
-        msg: "Only one program scope is allowed in a Leo file.",
-        help: None,
+    duplicate_program_scope {
+        args: (name: impl Display),
+        msg: format!("A program scope named `{name}` is already declared in this file."),
+        help: Some("Each program scope in a Leo file must have a unique name.".to_string()),
     }
 
     @formatted
@@ -298,4 +298,5 @@ create_messages!(
         msg: format!("An array {kind} must have at least one element."),
         help: None,
     }
+
 );
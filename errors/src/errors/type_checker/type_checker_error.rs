@@ -441,10 +441,10 @@ create_messages!(
     }
 
     @formatted
-    imported_program_cannot_import_program {
-        args: (),
-        msg: format!("An imported program cannot import another program."),
-        help: None,
+    invalid_inline_annotation {
+        args: (annotation: impl Display),
+        msg: format!("Invalid annotation: `{annotation}`."),
+        help: Some("Use `@inline(always)` or `@inline(never)`.".to_string()),
     }
 
     @formatted
@@ -712,4 +712,18 @@ create_messages!(
         msg: format!("An array cannot have a record as an element type"),
         help: None,
     }
+
+    @formatted
+    inline_annotation_on_non_inline_function {
+        args: (),
+        msg: format!("Only `inline` functions can be annotated with `@inline`."),
+        help: None,
+    }
+
+    @formatted
+    cannot_call_external_standard_function {
+        args: (),
+        msg: format!("Cannot call an external `function`."),
+        help: Some("Only transitions can be called from other programs.".to_string()),
+    }
 );
@@ -61,6 +61,21 @@ enum Commands {
         #[clap(flatten)]
         command: Build,
     },
+    #[clap(about = "Generate client bindings for the current package")]
+    Bindings {
+        #[clap(subcommand)]
+        command: Bindings,
+    },
+    #[clap(about = "Add a dependency to the current package")]
+    Add {
+        #[clap(flatten)]
+        command: Add,
+    },
+    #[clap(about = "Remove a dependency from the current package")]
+    Remove {
+        #[clap(flatten)]
+        command: Remove,
+    },
     #[clap(about = "Clean the output directory")]
     Clean {
         #[clap(flatten)]
@@ -124,12 +139,24 @@ pub fn run_with_args(cli: CLI) -> Result<()> {
             // Drop tracing span
             drop(span);
 
-            command.try_execute(context)
+            match command.options.watch {
+                true => watch::watch(context, |context| command.clone().try_execute(context)),
+                false => command.try_execute(context),
+            }
         }
+        Commands::Bindings { command } => command.try_execute(context),
+        Commands::Add { command } => command.try_execute(context),
+        Commands::Remove { command } => command.try_execute(context),
         Commands::Clean { command } => command.try_execute(context),
         Commands::Example { command } => command.try_execute(context),
-        Commands::Run { command } => command.try_execute(context),
-        Commands::Execute { command } => command.try_execute(context),
+        Commands::Run { command } => match command.compiler_options.watch {
+            true => watch::watch(context, |context| command.clone().try_execute(context)),
+            false => command.try_execute(context),
+        },
+        Commands::Execute { command } => match command.compiler_options.watch {
+            true => watch::watch(context, |context| command.clone().try_execute(context)),
+            false => command.try_execute(context),
+        },
         Commands::Update { command } => command.try_execute(context),
     }
 }
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::cli::helpers::bindgen::{read_abi, rust_bindings};
+
+/// Commands to generate client bindings for the current package.
+#[derive(Parser, Debug)]
+pub enum Bindings {
+    /// Generates a Rust module with typed structs, records, and transition calls.
+    Rust {
+        /// The file to write the bindings to. Defaults to `build/bindings.rs`.
+        #[clap(short, long)]
+        output: Option<PathBuf>,
+        #[clap(flatten)]
+        compiler_options: BuildOptions,
+    },
+}
+
+impl Bindings {
+    fn compiler_options(&self) -> &BuildOptions {
+        match self {
+            Bindings::Rust { compiler_options, .. } => compiler_options,
+        }
+    }
+}
+
+impl Command for Bindings {
+    type Input = <Build as Command>::Output;
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        (Build { options: self.compiler_options().clone() }).execute(context)
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // The ABI of the program is written to the build directory by `leo build`.
+        let path = context.package_dir(self.compiler_options().package.as_deref())?;
+        let build_directory = BuildDirectory::open(&path)?;
+        let abi = read_abi(&build_directory)?;
+
+        match self {
+            Bindings::Rust { output, .. } => {
+                let output = output.unwrap_or_else(|| build_directory.join("bindings.rs"));
+                std::fs::write(&output, rust_bindings(&abi)).map_err(CliError::failed_to_write_file)?;
+                tracing::info!(
+                    "✅ Generated the Rust bindings of '{}' at {}",
+                    abi.program,
+                    output.display().to_string().dimmed()
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -15,14 +15,30 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-
-use leo_ast::{NodeBuilder, Struct};
-use leo_compiler::{Compiler, CompilerOptions, InputAst, OutputOptions};
+use crate::cli::helpers::source_map::write_source_map;
+
+use leo_ast::{NodeBuilder, Variant};
+use leo_compiler::{
+    input_ast_from_values,
+    Compiler,
+    CompilerOptions,
+    FileSystemResolver,
+    InliningStrategy,
+    InputAst,
+    InputValidator,
+    OutputOptions,
+    Pass,
+    SymbolTable,
+};
+use leo_errors::InputError;
 use leo_package::{
     build::BuildDirectory,
+    checksum,
+    dependencies::DependencyResolver,
+    file_checksum,
     imports::ImportsDirectory,
     inputs::InputFile,
-    outputs::OutputsDirectory,
+    outputs::{Fingerprint, FingerprintFile, OutputsDirectory, ABI_FILE_NAME},
     source::SourceDirectory,
 };
 use leo_span::{symbol::with_session_globals, Symbol};
@@ -36,45 +52,67 @@ use indexmap::IndexMap;
 use std::{
     io::Write,
     path::{Path, PathBuf},
+    rc::Rc,
 };
 
 impl From<BuildOptions> for CompilerOptions {
     fn from(options: BuildOptions) -> Self {
-        let mut out_options = Self {
-            build: leo_compiler::BuildOptions { dce_enabled: options.enable_dce },
-            output: OutputOptions {
-                symbol_table_spans_enabled: options.enable_symbol_table_spans,
-                initial_symbol_table: options.enable_initial_symbol_table_snapshot,
-                type_checked_symbol_table: options.enable_type_checked_symbol_table_snapshot,
-                unrolled_symbol_table: options.enable_unrolled_symbol_table_snapshot,
-                ast_spans_enabled: options.enable_ast_spans,
-                initial_input_ast: options.enable_initial_input_ast_snapshot,
-                initial_ast: options.enable_initial_ast_snapshot,
-                unrolled_ast: options.enable_unrolled_ast_snapshot,
-                ssa_ast: options.enable_ssa_ast_snapshot,
-                flattened_ast: options.enable_flattened_ast_snapshot,
-                destructured_ast: options.enable_destructured_ast_snapshot,
-                inlined_ast: options.enable_inlined_ast_snapshot,
-                dce_ast: options.enable_dce_ast_snapshot,
-            },
+        let mut output = OutputOptions {
+            symbol_table_spans_enabled: options.enable_symbol_table_spans,
+            ast_spans_enabled: options.enable_ast_spans,
+            stage_timings: options.timings.is_some(),
+            emit_to_stdout: emit::is_stdout(options.emit_dir.as_deref()),
+            ..Default::default()
         };
-        if options.enable_all_ast_snapshots {
-            out_options.output.initial_input_ast = true;
-            out_options.output.initial_ast = true;
-            out_options.output.unrolled_ast = true;
-            out_options.output.ssa_ast = true;
-            out_options.output.flattened_ast = true;
-            out_options.output.destructured_ast = true;
-            out_options.output.inlined_ast = true;
-            out_options.output.dce_ast = true;
+        for kind in options.emit.iter() {
+            kind.enable(&mut output);
         }
 
-        out_options
+        Self {
+            build: leo_compiler::BuildOptions {
+                dce_enabled: options.enable_dce,
+                cse_enabled: options.enable_cse,
+                finalize_branching: options.enable_finalize_branching,
+                optimization_level: options.optimization_level,
+                inlining_strategy: match options.inlining {
+                    Inlining::Always => InliningStrategy::Always,
+                    Inlining::Cost => InliningStrategy::Cost,
+                },
+                max_unrolled_iterations: (options.max_unrolled_iterations > 0)
+                    .then_some(options.max_unrolled_iterations),
+                source_comments: options.source_comments,
+            },
+            output,
+        }
+    }
+}
+
+/// The inputs of a package, read from its `.in` input file or from its JSON or TOML input file, along with the
+/// declarations of the transitions and structs that type them.
+#[derive(Clone, Debug)]
+pub struct PackageInputs {
+    pub input_ast: InputAst,
+    pub symbol_table: SymbolTable,
+}
+
+impl PackageInputs {
+    /// Returns the inputs of `function` for execution with `leo run`.
+    pub fn program_inputs(&self, function: &str) -> Result<Vec<String>> {
+        let name = Symbol::intern(function);
+        let transition = match self.symbol_table.lookup_fn_symbol(name) {
+            Some(transition) if transition.variant == Variant::Transition => transition,
+            _ => return Err(InputError::unknown_transition(function).into()),
+        };
+        // A transition without inputs needs no section.
+        if !transition.input.is_empty() && !self.input_ast.sections.iter().any(|section| section.name == name) {
+            return Err(InputError::missing_input_section(function).into());
+        }
+        self.input_ast.program_inputs(function, self.symbol_table.structs.clone())
     }
 }
 
 /// Compile and build program command.
-#[derive(Parser, Debug)]
+#[derive(Parser, Clone, Debug)]
 pub struct Build {
     #[clap(flatten)]
     pub(crate) options: BuildOptions,
@@ -82,7 +120,7 @@ pub struct Build {
 
 impl Command for Build {
     type Input = ();
-    type Output = (Option<InputAst>, IndexMap<Symbol, Struct>);
+    type Output = Option<PackageInputs>;
 
     fn log_span(&self) -> Span {
         tracing::span!(tracing::Level::INFO, "Leo")
@@ -93,161 +131,482 @@ impl Command for Build {
     }
 
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
-        // Get the package path.
-        let package_path = context.dir()?;
-
-        // Get the program id.
-        let manifest = context.open_manifest()?;
-        let program_id = manifest.program_id();
-
-        // Create the outputs directory.
-        let outputs_directory = OutputsDirectory::create(&package_path)?;
-
-        // Open the build directory.
-        let build_directory = BuildDirectory::open(&package_path)?;
-
-        // Initialize error handler
-        let handler = Handler::default();
-
-        // Initialize a node counter.
-        let node_builder = NodeBuilder::default();
-
-        // Fetch paths to all .leo files in the source directory.
-        let source_files = SourceDirectory::files(&package_path)?;
-
-        // Check the source files.
-        SourceDirectory::check_files(&source_files)?;
-
-        // Store all struct declarations made in the source files.
-        let mut structs = IndexMap::new();
-
-        // Compile all .leo files into .aleo files.
-        for file_path in source_files.into_iter() {
-            structs.extend(compile_leo_file(
-                file_path,
-                &package_path,
-                program_id,
-                &outputs_directory,
-                &build_directory,
-                &handler,
-                self.options.clone(),
-                false,
-            )?);
-        }
+        let workspace = match context.workspace()? {
+            Some(workspace) => workspace,
+            None => return build_package(&context, &self.options, &IndexMap::new()),
+        };
+
+        // The members of a workspace import each other directly from their sources.
+        let members = workspace
+            .members
+            .iter()
+            .map(|member| (member.name.clone(), member.main_file()))
+            .collect::<IndexMap<_, _>>();
+
+        // The output of the build is the output of the member selected by `--package` or the current directory, if any.
+        let selected = match self.options.package.is_some() {
+            true => Some(context.package_dir(self.options.package.as_deref())?),
+            false => context.package_dir(None).ok(),
+        };
 
-        if !ImportsDirectory::is_empty(&package_path)? {
-            // Create Aleo build/imports/ directory.
-            let build_imports_directory = ImportsDirectory::create(&build_directory)?;
-
-            // Fetch paths to all .leo files in the imports directory.
-            let import_files = ImportsDirectory::files(&package_path)?;
-
-            // Compile all .leo files into .aleo files.
-            for file_path in import_files.into_iter() {
-                structs.extend(compile_leo_file(
-                    file_path,
-                    &package_path,
-                    program_id,
-                    &outputs_directory,
-                    &build_imports_directory,
-                    &handler,
-                    self.options.clone(),
-                    true,
-                )?);
+        // Build each member after the members it imports.
+        let mut output = None;
+        for member in workspace.build_order()? {
+            tracing::info!("📦 Building workspace member '{}'", member.name);
+            let member_output = build_package(&Context::new(Some(member.path.clone()))?, &self.options, &members)?;
+            if selected.as_ref() == Some(&member.path) {
+                output = member_output;
             }
         }
 
-        // Load the input file at `package_name.in`
-        let input_file_path = InputFile::new(&manifest.program_id().name().to_string()).setup_file_path(&package_path);
-
-        // Parse the input file.
-        let input_ast = if input_file_path.exists() {
-            // Load the input file into the source map.
-            let input_sf = with_session_globals(|s| s.source_map.load_file(&input_file_path))
-                .map_err(|e| CompilerError::file_read_error(&input_file_path, e))?;
-
-            // TODO: This is a hack to notify the user that something is wrong with the input file. Redesign.
-            leo_parser::parse_input(&handler, &node_builder, &input_sf.src, input_sf.start_pos)
-                .map_err(|_e| println!("Warning: Failed to parse input file"))
-                .ok()
-        } else {
-            None
-        };
+        Ok(output)
+    }
+}
 
-        // `Package::open` checks that the build directory and that `main.aleo` and all imported files are well-formed.
-        Package::<CurrentNetwork>::open(&build_directory).map_err(CliError::failed_to_execute_build)?;
-
-        // // Unset the Leo panic hook.
-        // let _ = std::panic::take_hook();
-        //
-        // // Change the cwd to the build directory to compile aleo files.
-        // std::env::set_current_dir(&build_directory)
-        //     .map_err(|err| PackageError::failed_to_set_cwd(build_directory.display(), err))?;
-        //
-        // // Call the `build` command.
-        // let mut args = vec![SNARKVM_COMMAND];
-        // if self.options.offline {
-        //     args.push("--offline");
-        // }
-        // let command = AleoBuild::try_parse_from(&args).map_err(CliError::failed_to_execute_aleo_build)?;
-        // let result = command.parse().map_err(CliError::failed_to_execute_aleo_build)?;
-        //
-        // // Log the result of the build
-        // tracing::info!("{}", result);
-
-        Ok((input_ast, structs))
-    }
-}
-
-/// Compiles a Leo file in the `src/` directory.
-#[allow(clippy::too_many_arguments)]
+/// Builds the package at the context directory.
+/// The programs in `workspace_programs`, except the package's own program, are imported from the given files.
+fn build_package(
+    context: &Context,
+    options: &BuildOptions,
+    workspace_programs: &IndexMap<String, PathBuf>,
+) -> Result<<Build as Command>::Output> {
+    // Get the package path.
+    let package_path = context.dir()?;
+
+    // Get the program id.
+    let manifest = context.open_manifest()?;
+    let program_id = manifest.program_id();
+
+    // Create the outputs directory.
+    let outputs_directory = OutputsDirectory::create(&package_path)?;
+
+    // Open the build directory.
+    let build_directory = BuildDirectory::open(&package_path)?;
+
+    // Initialize error handler
+    let handler = Handler::default();
+
+    // Initialize a node counter.
+    let node_builder = NodeBuilder::default();
+
+    // Fetch paths to all .leo files in the source directory.
+    let source_files = SourceDirectory::files(&package_path)?;
+
+    // Check the source files.
+    SourceDirectory::check_files(&source_files)?;
+
+    // Resolve the dependencies declared in the manifest, and record them in the lockfile.
+    let lockfile = DependencyResolver::new(context.registry(options.registry.clone())).resolve(&package_path)?;
+    lockfile.write_to(&package_path)?;
+
+    // Import the other programs of the workspace, followed by the dependencies in the lockfile.
+    let program_name = program_id.name().to_string();
+    let mut imports = workspace_programs
+        .iter()
+        .filter(|(name, _)| **name != program_name)
+        .map(|(name, path)| (name.clone(), path.clone()))
+        .collect::<IndexMap<_, _>>();
+    for dependency in lockfile.dependencies.iter() {
+        imports.insert(dependency.name.clone(), package_path.join(&dependency.file));
+    }
+
+    // Store the declarations of the functions, structs, and records in the source files.
+    let mut symbol_table = SymbolTable::default();
+
+    // Compile all .leo files into .aleo files.
+    // Note that the programs each file depends on are compiled alongside it.
+    for file_path in source_files.into_iter() {
+        let declarations = compile_leo_file(
+            file_path,
+            &package_path,
+            program_id,
+            &outputs_directory,
+            &build_directory,
+            &imports,
+            &handler,
+            options.clone(),
+        )?;
+        symbol_table.functions.extend(declarations.functions);
+        symbol_table.structs.extend(declarations.structs);
+    }
+
+    // Load the input file at `package_name.in`
+    let input_file_path = InputFile::new(&manifest.program_id().name().to_string()).setup_file_path(&package_path);
+
+    // Parse the input file.
+    let input_ast = if input_file_path.exists() {
+        // Load the input file into the source map.
+        let input_sf = with_session_globals(|s| s.source_map.load_file(&input_file_path))
+            .map_err(|e| CompilerError::file_read_error(&input_file_path, e))?;
+
+        Some(leo_parser::parse_input(&handler, &node_builder, &input_sf.src, input_sf.start_pos)?)
+    } else {
+        // Without an `.in` file, read the JSON or TOML input file, whose values are typed by the transitions.
+        match InputFile::new(&program_name).values_file_path(&package_path) {
+            Some(path) => Some(input_ast_from_values(&InputFile::read_values(&path)?, &symbol_table, &node_builder)?),
+            None => None,
+        }
+    };
+
+    // Check the input file against the signatures of the transitions, rather than leaving it to snarkVM.
+    if let Some(input_ast) = &input_ast {
+        InputValidator::do_pass((input_ast, &symbol_table, &handler))?;
+    }
+
+    // Write the input AST snapshot, if requested.
+    if let (Some(input_ast), true) = (&input_ast, options.emit.contains(&EmitKind::InputAst)) {
+        let mut value = input_ast.to_json_value()?;
+        if !options.enable_ast_spans {
+            value = leo_ast::normalize_json_value(leo_ast::remove_key_from_json(value, "span"));
+        }
+        let json = serde_json::to_string_pretty(&value).map_err(CliError::failed_to_write_file)?;
+        let emit_dir = options.emit_dir.clone().unwrap_or_else(|| outputs_directory.clone());
+        emit_artifact(&emit_dir, &format!("{program_name}.initial_input_ast.json"), &json)?;
+    }
+
+    // `Package::open` checks that the build directory and that `main.aleo` and all imported files are well-formed.
+    Package::<CurrentNetwork>::open(&build_directory).map_err(CliError::failed_to_execute_build)?;
+
+    // // Unset the Leo panic hook.
+    // let _ = std::panic::take_hook();
+    //
+    // // Change the cwd to the build directory to compile aleo files.
+    // std::env::set_current_dir(&build_directory)
+    //     .map_err(|err| PackageError::failed_to_set_cwd(build_directory.display(), err))?;
+    //
+    // // Call the `build` command.
+    // let mut args = vec![SNARKVM_COMMAND];
+    // if options.offline {
+    //     args.push("--offline");
+    // }
+    // let command = AleoBuild::try_parse_from(&args).map_err(CliError::failed_to_execute_aleo_build)?;
+    // let result = command.parse().map_err(CliError::failed_to_execute_aleo_build)?;
+    //
+    // // Log the result of the build
+    // tracing::info!("{}", result);
+
+    Ok(input_ast.map(|input_ast| PackageInputs { input_ast, symbol_table }))
+}
+
+/// Compiles a Leo file in the `src/` directory, along with the programs it depends on.
+/// The dependencies are written to the `build/imports/` directory.
 fn compile_leo_file(
     file_path: PathBuf,
-    _package_path: &Path,
+    package_path: &Path,
     program_id: &ProgramID<Testnet3>,
     outputs: &Path,
     build: &Path,
+    imports: &IndexMap<String, PathBuf>,
     handler: &Handler,
     options: BuildOptions,
-    is_import: bool,
-) -> Result<IndexMap<Symbol, Struct>> {
+) -> Result<SymbolTable> {
     // Construct the Leo file name with extension `foo.leo`.
     let file_name =
         file_path.file_name().and_then(|name| name.to_str()).ok_or_else(PackageError::failed_to_get_file_name)?;
 
-    // If the program is an import, construct program name from file_path
-    // Otherwise, use the program_id found in `package.json`.
-    let program_name = match is_import {
-        false => program_id.name().to_string(),
-        true => file_name.strip_suffix(".leo").ok_or_else(PackageError::failed_to_get_file_name)?.to_string(),
-    };
+    // Use the program_id found in `package.json`.
+    let program_name = program_id.name().to_string();
 
     // Create the path to the Aleo file.
     let mut aleo_file_path = build.to_path_buf();
-    aleo_file_path.push(match is_import {
-        true => format!("{program_name}.{}", program_id.network()),
-        false => format!("main.{}", program_id.network()),
-    });
+    aleo_file_path.push(format!("main.{}", program_id.network()));
+
+    // Fingerprint the inputs of the build, and skip compilation if they are unchanged since the last build.
+    let fingerprint = Fingerprint::default()
+        .with_input("source", file_checksum(&file_path)?)
+        .with_input("imports", imports_checksum(package_path, imports, &options)?)
+        .with_input("compiler version", env!("CARGO_PKG_VERSION").to_string())
+        .with_input("build options", options_checksum(&options));
+    let fingerprint_file = FingerprintFile::new(&program_name);
+    let declarations_file_path = outputs.join(format!("{program_name}.declarations.json"));
+
+    // Earlier builds cached only the structs, in a file that the declarations replace.
+    let structs_file_path = outputs.join(format!("{program_name}.structs.json"));
+    if structs_file_path.exists() {
+        std::fs::remove_file(&structs_file_path).map_err(CliError::failed_to_write_file)?;
+    }
+
+    let reason = if options.timings.is_some() {
+        Some("timings were requested".to_string())
+    } else if options.inlining_report {
+        Some("an inlining report was requested".to_string())
+    } else if options.unroll_report {
+        Some("an unroll report was requested".to_string())
+    } else if !options.emit.is_empty() {
+        Some("artifacts were requested with `--emit`".to_string())
+    } else {
+        rebuild_reason(fingerprint_file.read_from(outputs).as_ref(), &fingerprint)
+    };
+    match reason {
+        None => {
+            if let Some(declarations) = read_declarations(&declarations_file_path) {
+                tracing::info!("✅ '{}' is up to date, skipping compilation", file_name);
+                return Ok(declarations);
+            }
+            tracing::info!("🔨 Compiling '{}' because the cached declarations are unreadable", file_name);
+        }
+        Some(reason) => tracing::info!("🔨 Compiling '{}' because {reason}", file_name),
+    }
+
+    // Resolve imports from the given files, followed by the `imports` directory and the dependency cache.
+    let mut import_resolver = FileSystemResolver::for_package(package_path);
+    for (name, path) in imports.iter() {
+        import_resolver = import_resolver.with_file(Symbol::intern(name), path.clone());
+    }
+    if let Some(dependency_cache) = &options.dependency_cache {
+        import_resolver = import_resolver.with_search_path(dependency_cache.clone());
+    }
 
-    // Create a new instance of the Leo compiler.
+    // Create a new instance of the Leo compiler, which writes the requested snapshots to the emit directory.
+    let timings = options.timings;
+    let inlining_report = options.inlining_report;
+    let unroll_report = options.unroll_report;
+    let emit_dir = options.emit_dir.clone().unwrap_or_else(|| outputs.to_path_buf());
+    let emit_aleo = options.emit.contains(&EmitKind::Aleo);
+    let emit_abi = options.emit.contains(&EmitKind::Abi);
+    if !emit::is_stdout(Some(emit_dir.as_path())) {
+        std::fs::create_dir_all(&emit_dir).map_err(CliError::failed_to_write_file)?;
+    }
     let mut compiler = Compiler::new(
         program_name,
         program_id.network().to_string(),
         handler,
         file_path.clone(),
-        outputs.to_path_buf(),
+        emit_dir.clone(),
         Some(options.into()),
+        Rc::new(import_resolver),
     );
 
     // Compile the Leo program into Aleo instructions.
     let (symbol_table, instructions) = compiler.compile()?;
 
     // Write the instructions.
-    std::fs::File::create(&aleo_file_path)
+    write_instructions(&aleo_file_path, &instructions)?;
+    if emit_aleo {
+        emit_artifact(&emit_dir, &format!("{}.aleo", compiler.program_name), &instructions)?;
+    }
+    let source_map_file_path = write_source_map(&aleo_file_path, &compiler.source_map)?;
+    let mut fingerprint = fingerprint.with_output(aleo_file_path).with_output(source_map_file_path);
+
+    // Write the ABI, which describes the interface of the program to its clients.
+    let abi_file_path = build.join(ABI_FILE_NAME);
+    let abi = serde_json::to_string_pretty(&compiler.abi).map_err(CliError::failed_to_write_file)?;
+    std::fs::write(&abi_file_path, &abi).map_err(CliError::failed_to_write_file)?;
+    if emit_abi {
+        emit_artifact(&emit_dir, &format!("{}.abi.json", compiler.program_name), &abi)?;
+    }
+    fingerprint = fingerprint.with_output(abi_file_path);
+
+    // Write the instructions of each dependency, in the order in which they must be deployed.
+    if !compiler.dependencies.is_empty() {
+        // Create Aleo build/imports/ directory.
+        let build_imports_directory = ImportsDirectory::create(build)?;
+
+        for (name, instructions) in compiler.dependencies.iter() {
+            let mut import_file_path = build_imports_directory.clone();
+            import_file_path.push(format!("{name}.{}", program_id.network()));
+            write_instructions(&import_file_path, instructions)?;
+            if let Some(source_map) = compiler.dependency_source_maps.get(name) {
+                fingerprint = fingerprint.with_output(write_source_map(&import_file_path, source_map)?);
+            }
+            fingerprint = fingerprint.with_output(import_file_path);
+
+            tracing::info!("✅ Compiled '{name}.leo' into Aleo instructions");
+        }
+    }
+
+    // Report the statistics of each compiler stage.
+    if let Some(format) = timings {
+        report_timings(&compiler, format, outputs, file_name)?;
+    }
+
+    // Report the calls that were inlined.
+    if inlining_report {
+        report_inlining(&compiler, file_name);
+    }
+
+    // Report the loops that were unrolled.
+    if unroll_report {
+        report_unrolling(&compiler, file_name);
+    }
+
+    // Cache the declarations of the functions, structs, and records, which are needed to check and parse inputs when
+    // compilation is skipped.
+    let mut declarations = SymbolTable::default();
+    declarations.functions = symbol_table.functions;
+    declarations.structs = symbol_table.structs;
+    let json = serde_json::to_string(&declarations).map_err(CliError::failed_to_write_file)?;
+    std::fs::write(&declarations_file_path, json).map_err(CliError::failed_to_write_file)?;
+    fingerprint_file.write_to(outputs, &fingerprint.with_output(declarations_file_path))?;
+
+    tracing::info!("✅ Compiled '{}' into Aleo instructions", file_name);
+    Ok(declarations)
+}
+
+/// Writes an artifact requested with `--emit` to `emit_dir`, or to stdout if `emit_dir` is `-`.
+fn emit_artifact(emit_dir: &Path, file_name: &str, contents: &str) -> Result<()> {
+    if emit::is_stdout(Some(emit_dir)) {
+        println!("{contents}");
+    } else {
+        std::fs::create_dir_all(emit_dir).map_err(CliError::failed_to_write_file)?;
+        std::fs::write(emit_dir.join(file_name), contents).map_err(CliError::failed_to_write_file)?;
+    }
+    Ok(())
+}
+
+/// Reports the statistics of each stage of `compiler`, as a table or as a JSON file in the `outputs` directory.
+fn report_timings(compiler: &Compiler, format: TimingsFormat, outputs: &Path, file_name: &str) -> Result<()> {
+    match format {
+        TimingsFormat::Text => {
+            let mut table = format!("{:<28}{:>12}{:>12}{:>14}", "stage", "time (ms)", "AST nodes", "type entries");
+            for timing in compiler.timings.iter() {
+                table.push_str(&format!(
+                    "\n{:<28}{:>12.3}{:>12}{:>14}",
+                    timing.stage, timing.duration_ms, timing.ast_nodes, timing.type_table_entries
+                ));
+            }
+            tracing::info!("⏱️  Timings for '{file_name}'\n{table}");
+        }
+        TimingsFormat::Json => {
+            let path = outputs.join(format!("{}.timings.json", compiler.program_name));
+            let json = serde_json::to_string_pretty(&compiler.timings).map_err(CliError::failed_to_write_file)?;
+            std::fs::write(&path, json).map_err(CliError::failed_to_write_file)?;
+            tracing::info!("⏱️  Wrote the timings for '{file_name}' to {}", path.display().to_string().dimmed());
+        }
+    }
+    Ok(())
+}
+
+/// Reports each call to a local function in `compiler`'s program, and whether or not it was inlined.
+fn report_inlining(compiler: &Compiler, file_name: &str) {
+    let mut table = format!("{:<28}{:<28}{:>10}", "caller", "callee", "inlined");
+    for call in compiler.inlining_report.iter() {
+        table.push_str(&format!(
+            "\n{:<28}{:<28}{:>10}",
+            call.caller.to_string(),
+            call.callee.to_string(),
+            if call.inlined { "yes" } else { "no" }
+        ));
+    }
+    tracing::info!("📦 Inlining for '{file_name}'\n{table}");
+}
+
+/// Reports each loop in `compiler`'s program that was unrolled, along with its trip count and the statements it generated.
+fn report_unrolling(compiler: &Compiler, file_name: &str) {
+    let mut table = format!("{:<12}{:<20}{:>12}{:>12}", "loop", "location", "trip count", "statements");
+    for unrolled_loop in compiler.unroll_report.iter() {
+        table.push_str(&format!(
+            "\n{:<12}{:<20}{:>12}{:>12}",
+            format!("for {}", unrolled_loop.variable),
+            unrolled_loop.span.to_string(),
+            unrolled_loop.trip_count,
+            unrolled_loop.statements
+        ));
+    }
+    tracing::info!("🔁 Unrolled loops in '{file_name}'\n{table}");
+}
+
+/// Returns why a program must be compiled, or `None` if its last build is up to date.
+fn rebuild_reason(previous: Option<&Fingerprint>, fingerprint: &Fingerprint) -> Option<String> {
+    let previous = match previous {
+        Some(previous) => previous,
+        None => return Some("there is no previous build".to_string()),
+    };
+    if let Some(missing) = previous.outputs.iter().find(|path| !path.exists()) {
+        return Some(format!("'{}' is missing", missing.display()));
+    }
+    let changed = fingerprint.changed_inputs(previous);
+    match changed.is_empty() {
+        true => None,
+        false => Some(format!("the {} changed", changed.join(", "))),
+    }
+}
+
+/// Returns a checksum of the build options that affect the compiled program.
+/// The options that report on a build, or write its artifacts, are left out, since they always rebuild the program.
+fn options_checksum(options: &BuildOptions) -> String {
+    let options = serde_json::json!({
+        "enable_dce": options.enable_dce,
+        "enable_cse": options.enable_cse,
+        "enable_finalize_branching": options.enable_finalize_branching,
+        "optimization_level": options.optimization_level,
+        "inlining": options.inlining,
+        "max_unrolled_iterations": options.max_unrolled_iterations,
+        "source_comments": options.source_comments,
+        "dependency_cache": options.dependency_cache,
+    });
+    checksum(options.to_string().as_bytes())
+}
+
+/// Returns a checksum of the programs that imports may resolve to:
+/// the given files, the `imports` directory, and the dependency cache.
+fn imports_checksum(
+    package_path: &Path,
+    imports: &IndexMap<String, PathBuf>,
+    options: &BuildOptions,
+) -> Result<String> {
+    let mut summary = String::new();
+    for (name, path) in imports.iter() {
+        summary.push_str(&format!("{name} {}\n", file_checksum(path)?));
+    }
+
+    let directories = std::iter::once(package_path.join("imports")).chain(options.dependency_cache.clone());
+    for directory in directories.filter(|directory| directory.is_dir()) {
+        let mut files = std::fs::read_dir(&directory)
+            .map_err(|err| PackageError::failed_to_read_file(directory.display(), err))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| matches!(path.extension().and_then(|extension| extension.to_str()), Some("leo" | "aleo")))
+            .collect::<Vec<_>>();
+        files.sort();
+        for file in files {
+            summary.push_str(&format!("{} {}\n", file.display(), file_checksum(&file)?));
+        }
+    }
+
+    Ok(checksum(summary.as_bytes()))
+}
+
+/// Reads the declarations of the functions, structs, and records cached by the last build, if they are readable.
+fn read_declarations(path: &Path) -> Option<SymbolTable> {
+    let string = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&string).ok()
+}
+
+/// Writes Aleo instructions to the given file.
+fn write_instructions(aleo_file_path: &Path, instructions: &str) -> Result<()> {
+    std::fs::File::create(aleo_file_path)
         .map_err(CliError::failed_to_load_instructions)?
         .write_all(instructions.as_bytes())
         .map_err(CliError::failed_to_load_instructions)?;
+    Ok(())
+}
 
-    tracing::info!("✅ Compiled '{}' into Aleo instructions", file_name);
-    Ok(symbol_table.structs)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rebuild_reasons() {
+        let fingerprint = Fingerprint::default()
+            .with_input("source", "a".to_string())
+            .with_input("build options", options_checksum(&BuildOptions::default()));
+        assert_eq!(rebuild_reason(None, &fingerprint).unwrap(), "there is no previous build");
+        assert_eq!(rebuild_reason(Some(&fingerprint), &fingerprint), None);
+
+        let options = BuildOptions { enable_dce: true, ..Default::default() };
+        let changed = fingerprint.clone().with_input("build options", options_checksum(&options));
+        assert_eq!(rebuild_reason(Some(&fingerprint), &changed).unwrap(), "the build options changed");
+        let changed = changed.with_input("source", "b".to_string());
+        assert_eq!(rebuild_reason(Some(&fingerprint), &changed).unwrap(), "the source, build options changed");
+
+        // Options that do not affect the compiled program do not rebuild it.
+        let options = BuildOptions { enable_ast_spans: true, watch: true, ..Default::default() };
+        assert_eq!(options_checksum(&options), options_checksum(&BuildOptions::default()));
+
+        // A missing output rebuilds the program, even if its inputs are unchanged.
+        let missing = std::env::temp_dir().join("leo-missing-output").join("main.aleo");
+        let previous = fingerprint.clone().with_output(missing.clone());
+        let reason = rebuild_reason(Some(&previous), &fingerprint).unwrap();
+        assert_eq!(reason, format!("'{}' is missing", missing.display()));
+    }
 }
@@ -15,11 +15,12 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::cli::helpers::source_map::explain_with_source_map;
 
 use snarkvm::cli::Execute as SnarkVMExecute;
 
 /// Build, Prove and Run Leo program with inputs
-#[derive(Parser, Debug)]
+#[derive(Parser, Clone, Debug)]
 pub struct Execute {
     #[clap(name = "NAME", help = "The name of the program to execute.", default_value = "main")]
     name: String,
@@ -56,14 +57,14 @@ impl Command for Execute {
         // Otherwise, use the input file.
         let mut inputs = match self.inputs.is_empty() {
             true => match input {
-                (Some(input_ast), circuits) => input_ast.program_inputs(&self.name, circuits),
-                _ => Vec::new(),
+                Some(inputs) => inputs.program_inputs(&self.name)?,
+                None => Vec::new(),
             },
             false => self.inputs,
         };
 
         // Compose the `execute` command.
-        let mut arguments = vec![SNARKVM_COMMAND.to_string(), self.name];
+        let mut arguments = vec![SNARKVM_COMMAND.to_string(), self.name.clone()];
 
         // Add the program inputs to the arguments.
         arguments.append(&mut inputs);
@@ -78,7 +79,7 @@ impl Command for Execute {
         arguments.push(self.endpoint);
 
         // Open the Leo build/ directory
-        let path = context.dir()?;
+        let path = context.package_dir(self.compiler_options.package.as_deref())?;
         let build_directory = BuildDirectory::open(&path)?;
 
         // Change the cwd to the Leo build/ directory to compile aleo files.
@@ -91,7 +92,9 @@ impl Command for Execute {
         // Call the `execute` command.
         println!();
         let command = SnarkVMExecute::try_parse_from(&arguments).map_err(CliError::failed_to_parse_execute)?;
-        let res = command.parse().map_err(CliError::failed_to_execute_execute)?;
+        let res = command.parse().map_err(|error| {
+            CliError::failed_to_execute_execute(explain_with_source_map(&build_directory, &self.name, error))
+        })?;
 
         // Log the output of the `execute` command.
         tracing::info!("{}", res);
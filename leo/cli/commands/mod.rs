@@ -17,6 +17,12 @@
 pub mod account;
 pub use account::Account;
 
+pub mod add;
+pub use add::Add;
+
+pub mod bindings;
+pub use bindings::Bindings;
+
 pub mod build;
 pub use build::Build;
 
@@ -35,6 +41,9 @@ pub use execute::Execute;
 pub mod new;
 pub use new::New;
 
+pub mod remove;
+pub use remove::Remove;
+
 // pub mod node;
 // pub use node::Node;
 
@@ -45,12 +54,16 @@ pub mod update;
 pub use update::Update;
 
 use super::*;
-use crate::cli::helpers::context::*;
+use crate::cli::helpers::{
+    context::*,
+    emit::{self, EmitKind},
+};
 use leo_errors::{emitter::Handler, CliError, CompilerError, PackageError, Result};
 use leo_package::{build::*, outputs::OutputsDirectory, package::*};
 
 use clap::Parser;
 use colored::Colorize;
+use std::path::PathBuf;
 use tracing::span::Span;
 
 /// Base trait for the Leo CLI, see methods and their documentation for details.
@@ -121,34 +134,92 @@ pub trait Command {
 pub struct BuildOptions {
     #[clap(long, help = "Enables offline mode.")]
     pub offline: bool,
-    #[clap(long, help = "Enable spans in AST snapshots.")]
-    pub enable_symbol_table_spans: bool,
+    #[clap(long, help = "Rebuilds the package, and reruns the command, whenever its sources change.")]
+    pub watch: bool,
     #[clap(long, help = "Enables dead code elimination in the compiler.")]
-    pub enable_initial_symbol_table_snapshot: bool,
-    #[clap(long, help = "Writes symbol table snapshot of the type checked symbol table.")]
-    pub enable_type_checked_symbol_table_snapshot: bool,
-    #[clap(long, help = "Writes symbol table snapshot of the unrolled symbol table.")]
-    pub enable_unrolled_symbol_table_snapshot: bool,
+    pub enable_dce: bool,
+    #[clap(long, help = "Enables common subexpression elimination in the compiler.")]
+    pub enable_cse: bool,
+    #[clap(long, help = "Compiles the conditionals in finalize blocks to branches instead of flattening them.")]
+    pub enable_finalize_branching: bool,
+    #[clap(
+        long,
+        default_value = "0",
+        value_parser = clap::value_parser!(u8).range(0..=1),
+        help = "The optimization level. Level 1 optimizes the generated bytecode."
+    )]
+    pub optimization_level: u8,
+    #[clap(
+        long,
+        value_enum,
+        default_value = "always",
+        help = "The strategy that decides which `inline` functions are inlined at their call sites."
+    )]
+    pub inlining: Inlining,
+    #[clap(long, help = "Reports the calls to local functions, and whether or not they were inlined.")]
+    pub inlining_report: bool,
+    #[clap(
+        long,
+        default_value = "50000",
+        help = "The maximum total number of loop iterations to unroll, or 0 for no limit."
+    )]
+    pub max_unrolled_iterations: u64,
+    #[clap(long, help = "Reports each unrolled loop, its trip count, and the number of statements that it generated.")]
+    pub unroll_report: bool,
+    #[clap(long, help = "Annotates the generated Aleo instructions with the Leo source, as comments.")]
+    pub source_comments: bool,
     #[clap(long, help = "Enable spans in AST snapshots.")]
     pub enable_ast_spans: bool,
     #[clap(long, help = "Enable spans in symbol table snapshots.")]
-    pub enable_dce: bool,
-    #[clap(long, help = "Writes all AST snapshots for the different compiler phases.")]
-    pub enable_all_ast_snapshots: bool,
-    #[clap(long, help = "Writes Input AST snapshot of the initial parse.")]
-    pub enable_initial_input_ast_snapshot: bool,
-    #[clap(long, help = "Writes AST snapshot of the initial parse.")]
-    pub enable_initial_ast_snapshot: bool,
-    #[clap(long, help = "Writes AST snapshot of the unrolled AST.")]
-    pub enable_unrolled_ast_snapshot: bool,
-    #[clap(long, help = "Writes AST snapshot of the SSA AST.")]
-    pub enable_ssa_ast_snapshot: bool,
-    #[clap(long, help = "Writes AST snapshot of the flattened AST.")]
-    pub enable_flattened_ast_snapshot: bool,
-    #[clap(long, help = "Writes AST snapshot of the destructured AST.")]
-    pub enable_destructured_ast_snapshot: bool,
-    #[clap(long, help = "Writes AST snapshot of the inlined AST.")]
-    pub enable_inlined_ast_snapshot: bool,
-    #[clap(long, help = "Writes AST snapshot of the dead code eliminated (DCE) AST.")]
-    pub enable_dce_ast_snapshot: bool,
+    pub enable_symbol_table_spans: bool,
+    #[clap(
+        long,
+        value_delimiter = ',',
+        value_name = "KINDS",
+        help = "Writes the given artifacts, e.g. `ast@ssa,symbols@unrolled,leo@flattened,aleo`. Use `ast@all` or `leo@all` for every snapshot."
+    )]
+    pub emit: Vec<EmitKind>,
+    #[clap(
+        long,
+        value_name = "DIR",
+        help = "The directory to write the `--emit` artifacts to, or `-` for stdout. Defaults to `outputs`."
+    )]
+    pub emit_dir: Option<PathBuf>,
+    #[clap(long, help = "A directory of Aleo programs to search for imports that are not in the `imports` directory.")]
+    pub dependency_cache: Option<PathBuf>,
+    #[clap(long, env = "LEO_REGISTRY", help = "The local registry of programs. Defaults to `~/.leo/registry`.")]
+    pub registry: Option<PathBuf>,
+    #[clap(
+        long,
+        help = "The workspace member to run the command for. Defaults to the member in the current directory."
+    )]
+    pub package: Option<String>,
+    #[clap(
+        long,
+        value_name = "FORMAT",
+        num_args = 0..=1,
+        default_missing_value = "text",
+        help = "Reports the time spent in each compiler stage, and the number of AST nodes and type table entries after it."
+    )]
+    pub timings: Option<TimingsFormat>,
+}
+
+/// The strategy that `--inlining` selects.
+#[derive(clap::ValueEnum, serde::Serialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Inlining {
+    /// Inline every call to an `inline` function.
+    #[default]
+    Always,
+    /// Inline a call only if it is cheaper than calling the function as a closure.
+    Cost,
+}
+
+/// The format in which `--timings` reports the statistics of each compiler stage.
+#[derive(clap::ValueEnum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TimingsFormat {
+    /// A table in the log.
+    Text,
+    /// A JSON file in the `outputs` directory.
+    Json,
 }
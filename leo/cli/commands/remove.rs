@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_package::dependencies::{DependencyManifest, DependencyResolver};
+
+/// Remove a dependency from the current package.
+#[derive(Parser, Debug)]
+pub struct Remove {
+    #[clap(name = "NAME", help = "The name of the program, e.g. `token` or `token.aleo`.")]
+    pub(crate) name: String,
+
+    #[clap(long, env = "LEO_REGISTRY", help = "The local registry of programs. Defaults to `~/.leo/registry`.")]
+    pub(crate) registry: Option<PathBuf>,
+}
+
+impl Command for Remove {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let path = context.dir()?;
+        let name = self.name.trim_end_matches(".aleo");
+
+        // Remove the dependency from the manifest.
+        let mut manifest = DependencyManifest::read_from(&path)?;
+        if manifest.dependencies.shift_remove(name).is_none() {
+            return Err(PackageError::unknown_dependency(name).into());
+        }
+
+        // Resolve the remaining dependencies, so that the lockfile no longer records the removed one.
+        let lockfile = DependencyResolver::new(context.registry(self.registry)).resolve_with(&path, &manifest)?;
+        manifest.write_to(&path)?;
+        lockfile.write_to(&path)?;
+
+        tracing::info!("✅ Removed the dependency '{}'", name.dimmed());
+        Ok(())
+    }
+}
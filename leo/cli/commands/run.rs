@@ -15,11 +15,12 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::cli::helpers::source_map::explain_with_source_map;
 
 use snarkvm::cli::Run as SnarkVMRun;
 
 /// Build, Prove and Run Leo program with inputs
-#[derive(Parser, Debug)]
+#[derive(Parser, Clone, Debug)]
 pub struct Run {
     #[clap(name = "NAME", help = "The name of the program to run.", default_value = "main")]
     name: String,
@@ -48,20 +49,20 @@ impl Command for Run {
         // Otherwise, use the input file.
         let mut inputs = match self.inputs.is_empty() {
             true => match input {
-                (Some(input_ast), circuits) => input_ast.program_inputs(&self.name, circuits),
-                _ => Vec::new(),
+                Some(inputs) => inputs.program_inputs(&self.name)?,
+                None => Vec::new(),
             },
             false => self.inputs,
         };
 
         // Compose the `run` command.
-        let mut arguments = vec![SNARKVM_COMMAND.to_string(), self.name];
+        let mut arguments = vec![SNARKVM_COMMAND.to_string(), self.name.clone()];
 
         // Add the program inputs to the arguments.
         arguments.append(&mut inputs);
 
         // Open the Leo build/ directory
-        let path = context.dir()?;
+        let path = context.package_dir(self.compiler_options.package.as_deref())?;
         let build_directory = BuildDirectory::open(&path)?;
 
         // Change the cwd to the Leo build/ directory to compile aleo files.
@@ -74,7 +75,9 @@ impl Command for Run {
         // Call the `run` command.
         println!();
         let command = SnarkVMRun::try_parse_from(&arguments).map_err(CliError::failed_to_parse_run)?;
-        let res = command.parse().map_err(CliError::failed_to_execute_run)?;
+        let res = command.parse().map_err(|error| {
+            CliError::failed_to_execute_run(explain_with_source_map(&build_directory, &self.name, error))
+        })?;
 
         // Log the output of the `run` command.
         tracing::info!("{}", res);
@@ -0,0 +1,571 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Generates client bindings from the ABI of a program.
+
+use leo_compiler::{Abi, FieldAbi, StructAbi, TransitionAbi};
+use leo_errors::{CliError, Result};
+use leo_package::outputs::ABI_FILE_NAME;
+
+use std::{fmt::Write, path::Path};
+
+/// The code that the generated Rust bindings share, independently of the program.
+const RUST_PRELUDE: &str = r##"use std::fmt;
+
+/// The ID of the program.
+pub const PROGRAM_ID: &str = "{program}";
+
+/// An error that occurs while parsing an Aleo value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError(pub String);
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "failed to parse an Aleo value: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The tokens of an Aleo value, e.g. `{ owner: aleo1abc.private, amount: 5u64.private }`.
+pub struct Tokens<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Tokens<'a> {
+    /// Returns the tokens of `input`.
+    pub fn new(input: &'a str) -> Self {
+        Self { rest: input }
+    }
+
+    /// Returns the next token without consuming it.
+    pub fn peek(&self) -> Option<&'a str> {
+        let rest = self.rest.trim_start();
+        let first = rest.chars().next()?;
+        let length = match "{}[]:,".contains(first) {
+            true => 1,
+            false => rest.find(|c: char| c.is_whitespace() || "{}[]:,".contains(c)).unwrap_or(rest.len()),
+        };
+        Some(&rest[..length])
+    }
+
+    /// Consumes the next token.
+    pub fn next(&mut self) -> Result<&'a str, ParseError> {
+        let token = self.peek().ok_or_else(|| ParseError("unexpected end of input".to_string()))?;
+        self.rest = &self.rest.trim_start()[token.len()..];
+        Ok(token)
+    }
+
+    /// Consumes the next token, which must be `expected`.
+    pub fn expect(&mut self, expected: &str) -> Result<(), ParseError> {
+        match self.next()? {
+            token if token == expected => Ok(()),
+            token => Err(ParseError(format!("expected `{expected}`, found `{token}`"))),
+        }
+    }
+
+    /// Consumes the next literal, which must end with `suffix`, and returns it without its visibility and suffix.
+    pub fn literal(&mut self, suffix: &str) -> Result<&'a str, ParseError> {
+        let token = self.next()?;
+        let literal = [".private", ".public", ".constant"]
+            .iter()
+            .find_map(|visibility| token.strip_suffix(visibility))
+            .unwrap_or(token);
+        literal.strip_suffix(suffix).ok_or_else(|| ParseError(format!("expected a `{suffix}` literal, found `{token}`")))
+    }
+
+    /// Consumes the next value, and returns its source.
+    pub fn raw(&mut self) -> Result<&'a str, ParseError> {
+        let start = self.rest.trim_start();
+        let mut depth = 0usize;
+        loop {
+            match self.next()? {
+                "{" | "[" => depth += 1,
+                "}" | "]" => {
+                    depth = depth.checked_sub(1).ok_or_else(|| ParseError("unbalanced brackets".to_string()))?
+                }
+                _ => {}
+            }
+            if depth == 0 {
+                break;
+            }
+        }
+        Ok(start[..start.len() - self.rest.len()].trim_end())
+    }
+
+    /// Checks that every token has been consumed.
+    pub fn end(&self) -> Result<(), ParseError> {
+        match self.peek() {
+            None => Ok(()),
+            Some(token) => Err(ParseError(format!("unexpected `{token}`"))),
+        }
+    }
+}
+
+/// A value that has an Aleo plaintext representation.
+pub trait AleoValue: Sized {
+    /// Writes the value, appending `visibility`, e.g. `.private`, to each of its literals.
+    fn write_aleo(&self, out: &mut String, visibility: &str);
+
+    /// Parses the value from `tokens`, ignoring the visibility of its literals.
+    fn parse_aleo(tokens: &mut Tokens) -> Result<Self, ParseError>;
+
+    /// Returns the value as an Aleo plaintext, e.g. as an input of a transition.
+    fn to_aleo(&self) -> String {
+        let mut out = String::new();
+        self.write_aleo(&mut out, "");
+        out
+    }
+
+    /// Parses an Aleo value, e.g. an output of a transition.
+    fn from_aleo(input: &str) -> Result<Self, ParseError> {
+        let mut tokens = Tokens::new(input);
+        let value = Self::parse_aleo(&mut tokens)?;
+        tokens.end()?;
+        Ok(value)
+    }
+}
+
+macro_rules! integers {
+    ($($type_:ident),*) => {$(
+        impl AleoValue for $type_ {
+            fn write_aleo(&self, out: &mut String, visibility: &str) {
+                out.push_str(&format!("{}{}{}", self, stringify!($type_), visibility));
+            }
+
+            fn parse_aleo(tokens: &mut Tokens) -> Result<Self, ParseError> {
+                let literal = tokens.literal(stringify!($type_))?;
+                literal
+                    .replace('_', "")
+                    .parse()
+                    .map_err(|_| ParseError(format!("invalid `{}` literal `{}`", stringify!($type_), literal)))
+            }
+        }
+    )*};
+}
+
+integers!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl AleoValue for bool {
+    fn write_aleo(&self, out: &mut String, visibility: &str) {
+        out.push_str(&format!("{self}{visibility}"));
+    }
+
+    fn parse_aleo(tokens: &mut Tokens) -> Result<Self, ParseError> {
+        match tokens.literal("")? {
+            "true" => Ok(true),
+            "false" => Ok(false),
+            literal => Err(ParseError(format!("invalid `bool` literal `{literal}`"))),
+        }
+    }
+}
+
+macro_rules! literals {
+    ($($(#[$doc:meta])* $type_:ident = $suffix:literal,)*) => {$(
+        $(#[$doc])*
+        #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct $type_(pub String);
+
+        impl AleoValue for $type_ {
+            fn write_aleo(&self, out: &mut String, visibility: &str) {
+                out.push_str(&format!("{}{}{}", self.0, $suffix, visibility));
+            }
+
+            fn parse_aleo(tokens: &mut Tokens) -> Result<Self, ParseError> {
+                Ok(Self(tokens.literal($suffix)?.to_string()))
+            }
+        }
+
+        impl fmt::Display for $type_ {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(&self.to_aleo())
+            }
+        }
+    )*};
+}
+
+literals! {
+    /// An address, e.g. `Address("aleo1...".to_string())`.
+    Address = "",
+    /// A field element, without its `field` suffix, e.g. `Field("5".to_string())`.
+    Field = "field",
+    /// A group element, without its `group` suffix.
+    Group = "group",
+    /// A scalar, without its `scalar` suffix.
+    Scalar = "scalar",
+    /// A signature, e.g. `Signature("sign1...".to_string())`.
+    Signature = "",
+}
+
+impl<T: AleoValue, const N: usize> AleoValue for [T; N] {
+    fn write_aleo(&self, out: &mut String, visibility: &str) {
+        out.push('[');
+        for (index, element) in self.iter().enumerate() {
+            if index > 0 {
+                out.push_str(", ");
+            }
+            element.write_aleo(out, visibility);
+        }
+        out.push(']');
+    }
+
+    fn parse_aleo(tokens: &mut Tokens) -> Result<Self, ParseError> {
+        tokens.expect("[")?;
+        let mut elements = Vec::with_capacity(N);
+        while elements.len() < N {
+            if !elements.is_empty() {
+                tokens.expect(",")?;
+            }
+            elements.push(T::parse_aleo(tokens)?);
+        }
+        tokens.expect("]")?;
+        elements.try_into().map_err(|_| ParseError(format!("expected an array of {N} elements")))
+    }
+}
+
+/// A record of another program, as the Aleo plaintext or ciphertext that snarkVM prints.
+impl AleoValue for String {
+    fn write_aleo(&self, out: &mut String, _visibility: &str) {
+        out.push_str(self);
+    }
+
+    fn parse_aleo(tokens: &mut Tokens) -> Result<Self, ParseError> {
+        Ok(tokens.raw()?.to_string())
+    }
+}
+
+/// A call to a transition, with its inputs as Aleo values.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Call {
+    /// The name of the transition.
+    pub function: &'static str,
+    /// The inputs of the transition.
+    pub inputs: Vec<String>,
+}
+
+impl Call {
+    /// Returns the arguments of `leo run`, i.e. the name of the transition followed by its inputs.
+    pub fn arguments(&self) -> Vec<String> {
+        std::iter::once(self.function.to_string()).chain(self.inputs.iter().cloned()).collect()
+    }
+}
+"##;
+
+/// The keywords of Rust, which are written as raw identifiers when they name a Leo item.
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "crate", "do", "dyn", "else", "enum", "extern",
+    "false", "final", "fn", "for", "if", "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut",
+    "override", "priv", "pub", "ref", "return", "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe",
+    "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Returns the Rust identifier for the Leo identifier `name`.
+fn rust_ident(name: &str) -> String {
+    match RUST_KEYWORDS.contains(&name) {
+        true => format!("r#{name}"),
+        false => name.to_string(),
+    }
+}
+
+/// Returns the Rust type for the Leo type `type_`, as it is written in the ABI.
+fn rust_type(type_: &str) -> String {
+    match type_ {
+        "boolean" | "bool" => "bool".to_string(),
+        "u8" | "u16" | "u32" | "u64" | "u128" | "i8" | "i16" | "i32" | "i64" | "i128" => type_.to_string(),
+        "address" => "Address".to_string(),
+        "field" => "Field".to_string(),
+        "group" => "Group".to_string(),
+        "scalar" => "Scalar".to_string(),
+        "signature" => "Signature".to_string(),
+        // Arrays are written `[u8; 4]`.
+        _ if type_.starts_with('[') && type_.ends_with(']') => {
+            let (element, length) = type_[1..type_.len() - 1].rsplit_once(';').unwrap_or((type_, "0"));
+            let length: String = length.trim().chars().take_while(char::is_ascii_digit).collect();
+            format!("[{}; {length}]", rust_type(element.trim()))
+        }
+        // Records of other programs are written `credits.aleo/credits`.
+        _ if type_.contains('/') => "String".to_string(),
+        _ => rust_ident(type_),
+    }
+}
+
+/// Reads the ABI of the program that the last build wrote to `build_directory`.
+pub fn read_abi(build_directory: &Path) -> Result<Abi> {
+    let path = build_directory.join(ABI_FILE_NAME);
+    let abi = std::fs::read_to_string(&path).map_err(|error| CliError::failed_to_read_abi(path.display(), error))?;
+    Ok(serde_json::from_str(&abi).map_err(|error| CliError::failed_to_read_abi(path.display(), error))?)
+}
+
+/// Generates a Rust module with typed bindings for the structs, records, and transitions of a program.
+pub fn rust_bindings(abi: &Abi) -> String {
+    let mut out = format!(
+        "//! Bindings for the `{}` program, generated by `leo bindings rust` from its ABI.\n\
+         //! Do not edit this file, since it is regenerated whenever the bindings are.\n\n\
+         #![allow(dead_code)]\n\n",
+        abi.program
+    );
+    out.push_str(&RUST_PRELUDE.replace("{program}", &abi.program));
+    for struct_ in abi.structs.iter() {
+        write_rust_struct(&mut out, struct_, false);
+    }
+    for record in abi.records.iter() {
+        write_rust_struct(&mut out, record, true);
+    }
+    for transition in abi.transitions.iter() {
+        write_rust_transition(&mut out, transition);
+    }
+    out
+}
+
+/// Writes a struct or record, along with its `AleoValue` and `Display` implementations.
+/// The fields of a record are written with their visibility, followed by its nonce.
+fn write_rust_struct(out: &mut String, struct_: &StructAbi, is_record: bool) {
+    let name = rust_ident(&struct_.name);
+    // Aleo records declare their owner first.
+    let mut fields: Vec<&FieldAbi> = struct_.fields.iter().collect();
+    if is_record {
+        fields.sort_by_key(|field| field.name != "owner");
+    }
+
+    let kind = if is_record { "record" } else { "struct" };
+    let _ = writeln!(out, "\n/// The `{}` {kind}.", struct_.name);
+    let _ = writeln!(out, "#[derive(Clone, Debug, PartialEq, Eq)]\npub struct {name} {{");
+    for field in fields.iter() {
+        let _ = writeln!(out, "    pub {}: {},", rust_ident(&field.name), rust_type(&field.type_));
+    }
+    if is_record {
+        let _ = writeln!(out, "    /// The nonce of the record.\n    pub nonce: Group,");
+    }
+    out.push_str("}\n");
+
+    let visibility = if is_record { "_visibility" } else { "visibility" };
+    let _ = writeln!(out, "\nimpl AleoValue for {name} {{");
+    let _ = writeln!(out, "    fn write_aleo(&self, out: &mut String, {visibility}: &str) {{");
+    for (index, field) in fields.iter().enumerate() {
+        let separator = if index == 0 { "{ " } else { ", " };
+        let _ = writeln!(out, "        out.push_str(\"{separator}{}: \");", field.name);
+        let visibility = match (is_record, &field.mode) {
+            (true, Some(mode)) => format!("\".{mode}\""),
+            _ => "visibility".to_string(),
+        };
+        let _ = writeln!(out, "        self.{}.write_aleo(out, {visibility});", rust_ident(&field.name));
+    }
+    if is_record {
+        out.push_str("        out.push_str(\", _nonce: \");\n        self.nonce.write_aleo(out, \".public\");\n");
+    }
+    out.push_str("        out.push_str(\" }\");\n    }\n\n");
+
+    out.push_str(
+        "    fn parse_aleo(tokens: &mut Tokens) -> Result<Self, ParseError> {\n        tokens.expect(\"{\")?;\n",
+    );
+    let mut names: Vec<(String, String)> =
+        fields.iter().map(|field| (field.name.clone(), rust_ident(&field.name))).collect();
+    if is_record {
+        names.push(("_nonce".to_string(), "nonce".to_string()));
+    }
+    for (index, (leo_name, rust_name)) in names.iter().enumerate() {
+        if index > 0 {
+            out.push_str("        tokens.expect(\",\")?;\n");
+        }
+        let _ = writeln!(out, "        tokens.expect(\"{leo_name}\")?;\n        tokens.expect(\":\")?;");
+        let _ = writeln!(out, "        let {rust_name} = AleoValue::parse_aleo(tokens)?;");
+    }
+    let initializers = names.iter().map(|(_, rust_name)| rust_name.as_str()).collect::<Vec<_>>().join(", ");
+    let _ = writeln!(out, "        tokens.expect(\"}}\")?;\n        Ok(Self {{ {initializers} }})\n    }}\n}}");
+
+    let _ = writeln!(
+        out,
+        "\nimpl fmt::Display for {name} {{\n    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {{\n        \
+         f.write_str(&self.to_aleo())\n    }}\n}}"
+    );
+}
+
+/// Writes a module with a builder for the calls to a transition, and a parser for its outputs.
+fn write_rust_transition(out: &mut String, transition: &TransitionAbi) {
+    let _ = writeln!(out, "\n/// The `{}` transition.", transition.name);
+    let _ = writeln!(out, "pub mod {} {{\n    use super::*;", rust_ident(&transition.name));
+
+    // The outputs are a single value, or a tuple of values.
+    let types: Vec<String> = transition.outputs.iter().map(|output| rust_type(&output.type_)).collect();
+    let outputs = match types.len() {
+        1 => types[0].clone(),
+        _ => format!("({})", types.iter().map(|type_| format!("{type_},")).collect::<Vec<_>>().join(" ")),
+    };
+    let _ = writeln!(out, "\n    /// The outputs of the transition.\n    pub type Outputs = {outputs};");
+
+    let parameters = transition
+        .inputs
+        .iter()
+        .map(|input| format!("{}: &{}", rust_ident(&input.name), rust_type(&input.type_)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let inputs = transition
+        .inputs
+        .iter()
+        .map(|input| format!("{}.to_aleo()", rust_ident(&input.name)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let _ = writeln!(out, "\n    /// Builds a call to the transition.");
+    let _ = writeln!(out, "    pub fn call({parameters}) -> Call {{");
+    let _ = writeln!(out, "        Call {{ function: \"{}\", inputs: vec![{inputs}] }}\n    }}", transition.name);
+
+    let bindings = (0..types.len()).map(|index| format!("output_{index}")).collect::<Vec<_>>();
+    let values = bindings.iter().map(|binding| format!("AleoValue::from_aleo({binding})?")).collect::<Vec<_>>();
+    let value = match values.len() {
+        1 => values[0].clone(),
+        _ => format!("({})", values.iter().map(|value| format!("{value},")).collect::<Vec<_>>().join(" ")),
+    };
+    let _ = writeln!(out, "\n    /// Parses the outputs of the transition, as snarkVM prints them.");
+    let _ = writeln!(out, "    pub fn parse_outputs(outputs: &[&str]) -> Result<Outputs, ParseError> {{");
+    let _ = writeln!(out, "        match outputs {{");
+    let _ = writeln!(out, "            [{}] => Ok({value}),", bindings.join(", "));
+    let _ = writeln!(
+        out,
+        "            _ => Err(ParseError(format!(\"expected {} outputs, found {{}}\", outputs.len()))),",
+        types.len()
+    );
+    out.push_str("        }\n    }\n}\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_compiler::OutputAbi;
+
+    fn field(name: &str, type_: &str, mode: Option<&str>) -> FieldAbi {
+        FieldAbi { name: name.to_string(), type_: type_.to_string(), mode: mode.map(str::to_string) }
+    }
+
+    #[test]
+    fn maps_leo_types_to_rust_types() {
+        assert_eq!(rust_type("boolean"), "bool");
+        assert_eq!(rust_type("u64"), "u64");
+        assert_eq!(rust_type("address"), "Address");
+        assert_eq!(rust_type("[[field; 2]; 3u32]"), "[[Field; 2]; 3]");
+        assert_eq!(rust_type("credits.aleo/credits"), "String");
+        assert_eq!(rust_type("type"), "r#type");
+    }
+
+    #[test]
+    fn generates_structs_records_and_transitions() {
+        let abi = Abi {
+            program: "token.aleo".to_string(),
+            structs: vec![StructAbi { name: "Point".to_string(), fields: vec![field("x", "i8", None)] }],
+            records: vec![StructAbi {
+                name: "Token".to_string(),
+                fields: vec![field("amount", "u64", Some("private")), field("owner", "address", Some("private"))],
+            }],
+            transitions: vec![TransitionAbi {
+                name: "mint".to_string(),
+                inputs: vec![field("receiver", "address", Some("private")), field("amount", "u64", Some("public"))],
+                outputs: vec![OutputAbi { type_: "Token".to_string(), mode: "private".to_string() }],
+                finalize: None,
+            }],
+            ..Default::default()
+        };
+        let bindings = rust_bindings(&abi);
+
+        assert!(bindings.contains("pub const PROGRAM_ID: &str = \"token.aleo\";"));
+        assert!(bindings.contains("pub struct Point {\n    pub x: i8,\n}"));
+        // The owner of a record comes first, and its nonce last.
+        assert!(bindings.contains("pub struct Token {\n    pub owner: Address,\n    pub amount: u64,\n"));
+        assert!(bindings.contains("self.amount.write_aleo(out, \".private\");"));
+        assert!(bindings.contains("pub fn call(receiver: &Address, amount: &u64) -> Call {"));
+        assert!(bindings.contains("pub type Outputs = Token;"));
+        assert!(bindings.contains("[output_0] => Ok(AleoValue::from_aleo(output_0)?),"));
+    }
+
+    #[test]
+    fn generated_bindings_compile_and_round_trip() {
+        let abi = Abi {
+            program: "token.aleo".to_string(),
+            structs: vec![StructAbi {
+                name: "Point".to_string(),
+                fields: vec![field("x", "i8", None), field("ys", "[field; 2u32]", None)],
+            }],
+            records: vec![StructAbi {
+                name: "Token".to_string(),
+                fields: vec![
+                    field("amount", "u64", Some("private")),
+                    field("origin", "Point", Some("public")),
+                    field("owner", "address", Some("private")),
+                ],
+            }],
+            transitions: vec![TransitionAbi {
+                name: "mint".to_string(),
+                inputs: vec![field("receiver", "address", Some("private")), field("origin", "Point", Some("public"))],
+                outputs: vec![OutputAbi { type_: "Token".to_string(), mode: "private".to_string() }, OutputAbi {
+                    type_: "boolean".to_string(),
+                    mode: "public".to_string(),
+                }],
+                finalize: None,
+            }],
+            ..Default::default()
+        };
+
+        // Compiles the bindings along with a program that round-trips a struct and a record, and runs it.
+        let main = r#"mod bindings;
+use bindings::*;
+
+const OWNER: &str = "aleo1rhgdu77hgyqd3xjj8ucu3jj9r2krwz6mnzyd80gncr5fxcwlh5rsvzp9px";
+
+fn main() {
+    let origin = Point { x: -3, ys: [Field("1".to_string()), Field("2".to_string())] };
+    assert_eq!(origin.to_aleo(), "{ x: -3i8, ys: [1field, 2field] }");
+    assert_eq!(Point::from_aleo(&origin.to_aleo()), Ok(origin.clone()));
+
+    let token = Token {
+        owner: Address(OWNER.to_string()),
+        amount: 100,
+        origin: origin.clone(),
+        nonce: Group("0".to_string()),
+    };
+    let aleo = token.to_aleo();
+    assert_eq!(
+        aleo,
+        format!(
+            "{{ owner: {OWNER}.private, amount: 100u64.private, origin: {{ x: -3i8.public, ys: [1field.public, \
+             2field.public] }}, _nonce: 0group.public }}"
+        )
+    );
+    assert_eq!(Token::from_aleo(&aleo), Ok(token.clone()));
+
+    let call = mint::call(&token.owner, &origin);
+    assert_eq!(call.arguments(), vec!["mint".to_string(), OWNER.to_string(), origin.to_aleo()]);
+    assert_eq!(mint::parse_outputs(&[&aleo, "true"]), Ok((token, true)));
+    assert!(mint::parse_outputs(&[&aleo]).is_err());
+}
+"#;
+        let directory = std::env::temp_dir().join(format!("leo-bindings-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("bindings.rs"), rust_bindings(&abi)).unwrap();
+        std::fs::write(directory.join("main.rs"), main).unwrap();
+
+        let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+        let binary = directory.join("bindings_test");
+        let output = std::process::Command::new(rustc)
+            .args(["--edition", "2021", "--crate-name", "bindings_test", "-D", "warnings", "-o"])
+            .arg(&binary)
+            .arg(directory.join("main.rs"))
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+        let output = std::process::Command::new(&binary).output().unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
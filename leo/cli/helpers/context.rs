@@ -16,7 +16,10 @@
 
 use super::*;
 use leo_errors::{CliError, PackageError, Result};
-use leo_package::build::{BuildDirectory, BUILD_DIRECTORY_NAME};
+use leo_package::{
+    build::{BuildDirectory, BUILD_DIRECTORY_NAME},
+    workspace::Workspace,
+};
 
 use snarkvm::file::Manifest;
 
@@ -48,6 +51,32 @@ impl Context {
         }
     }
 
+    /// Returns the workspace containing the package, if the package is the root or a member of a workspace.
+    pub fn workspace(&self) -> Result<Option<Workspace>> {
+        let path = self.dir()?;
+        Ok(Workspace::find(&path)?.filter(|workspace| workspace.root == path || workspace.member_at(&path).is_some()))
+    }
+
+    /// Returns the path to the package that a command runs for.
+    /// In a workspace, this is the member named `package`, or the member containing the current directory.
+    pub fn package_dir(&self, package: Option<&str>) -> Result<PathBuf> {
+        let path = self.dir()?;
+        let workspace = match self.workspace()? {
+            Some(workspace) => workspace,
+            None => return Ok(path),
+        };
+        let member = match package {
+            Some(name) => workspace.member(name).ok_or_else(|| PackageError::unknown_workspace_member(name))?,
+            None => workspace.member_at(&path).ok_or_else(PackageError::workspace_member_required)?,
+        };
+        Ok(member.path.clone())
+    }
+
+    /// Returns the path to the local registry of programs, which defaults to `~/.leo/registry`.
+    pub fn registry(&self, registry: Option<PathBuf>) -> Option<PathBuf> {
+        registry.or_else(|| dirs::home_dir().map(|home| home.join(".leo").join("registry")))
+    }
+
     /// Returns the package name as a String.
     /// Opens the manifest file `program.json` and creates the build directory if it doesn't exist.
     pub fn open_manifest(&self) -> Result<Manifest<CurrentNetwork>> {
@@ -0,0 +1,162 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::OutputOptions;
+
+use std::{fmt, path::Path, str::FromStr};
+
+/// An artifact that `--emit` writes alongside the build.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EmitKind {
+    InitialAst,
+    UnrolledAst,
+    SsaAst,
+    FlattenedAst,
+    DestructuredAst,
+    InlinedAst,
+    CseAst,
+    DceAst,
+    AllAsts,
+    InitialSymbols,
+    TypeCheckedSymbols,
+    UnrolledSymbols,
+    InitialLeo,
+    UnrolledLeo,
+    SsaLeo,
+    FlattenedLeo,
+    DestructuredLeo,
+    InlinedLeo,
+    CseLeo,
+    DceLeo,
+    AllLeo,
+    InputAst,
+    Aleo,
+    Abi,
+}
+
+/// The name of each artifact kind, as it is given to `--emit`.
+const EMIT_KINDS: &[(&str, EmitKind)] = &[
+    ("ast@initial", EmitKind::InitialAst),
+    ("ast@unrolled", EmitKind::UnrolledAst),
+    ("ast@ssa", EmitKind::SsaAst),
+    ("ast@flattened", EmitKind::FlattenedAst),
+    ("ast@destructured", EmitKind::DestructuredAst),
+    ("ast@inlined", EmitKind::InlinedAst),
+    ("ast@cse", EmitKind::CseAst),
+    ("ast@dce", EmitKind::DceAst),
+    ("ast@all", EmitKind::AllAsts),
+    ("symbols@initial", EmitKind::InitialSymbols),
+    ("symbols@type_checked", EmitKind::TypeCheckedSymbols),
+    ("symbols@unrolled", EmitKind::UnrolledSymbols),
+    ("leo@initial", EmitKind::InitialLeo),
+    ("leo@unrolled", EmitKind::UnrolledLeo),
+    ("leo@ssa", EmitKind::SsaLeo),
+    ("leo@flattened", EmitKind::FlattenedLeo),
+    ("leo@destructured", EmitKind::DestructuredLeo),
+    ("leo@inlined", EmitKind::InlinedLeo),
+    ("leo@cse", EmitKind::CseLeo),
+    ("leo@dce", EmitKind::DceLeo),
+    ("leo@all", EmitKind::AllLeo),
+    ("input-ast", EmitKind::InputAst),
+    ("aleo", EmitKind::Aleo),
+    ("abi", EmitKind::Abi),
+];
+
+impl EmitKind {
+    /// Enables the compiler snapshots needed to emit this artifact.
+    /// Artifacts that are not compiler snapshots are written by the CLI itself.
+    pub fn enable(self, output: &mut OutputOptions) {
+        match self {
+            EmitKind::InitialAst => output.initial_ast = true,
+            EmitKind::UnrolledAst => output.unrolled_ast = true,
+            EmitKind::SsaAst => output.ssa_ast = true,
+            EmitKind::FlattenedAst => output.flattened_ast = true,
+            EmitKind::DestructuredAst => output.destructured_ast = true,
+            EmitKind::InlinedAst => output.inlined_ast = true,
+            EmitKind::CseAst => output.cse_ast = true,
+            EmitKind::DceAst => output.dce_ast = true,
+            EmitKind::AllAsts => {
+                output.initial_ast = true;
+                output.unrolled_ast = true;
+                output.ssa_ast = true;
+                output.flattened_ast = true;
+                output.destructured_ast = true;
+                output.inlined_ast = true;
+                output.cse_ast = true;
+                output.dce_ast = true;
+            }
+            EmitKind::InitialSymbols => output.initial_symbol_table = true,
+            EmitKind::TypeCheckedSymbols => output.type_checked_symbol_table = true,
+            EmitKind::UnrolledSymbols => output.unrolled_symbol_table = true,
+            EmitKind::InitialLeo => output.initial_leo = true,
+            EmitKind::UnrolledLeo => output.unrolled_leo = true,
+            EmitKind::SsaLeo => output.ssa_leo = true,
+            EmitKind::FlattenedLeo => output.flattened_leo = true,
+            EmitKind::DestructuredLeo => output.destructured_leo = true,
+            EmitKind::InlinedLeo => output.inlined_leo = true,
+            EmitKind::CseLeo => output.cse_leo = true,
+            EmitKind::DceLeo => output.dce_leo = true,
+            EmitKind::AllLeo => {
+                output.initial_leo = true;
+                output.unrolled_leo = true;
+                output.ssa_leo = true;
+                output.flattened_leo = true;
+                output.destructured_leo = true;
+                output.inlined_leo = true;
+                output.cse_leo = true;
+                output.dce_leo = true;
+            }
+            EmitKind::InputAst | EmitKind::Aleo | EmitKind::Abi => {}
+        }
+    }
+}
+
+impl FromStr for EmitKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EMIT_KINDS.iter().find(|(name, _)| *name == s).map(|(_, kind)| *kind).ok_or_else(|| {
+            let names = EMIT_KINDS.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+            format!("unknown artifact kind `{s}`, expected one of: {}", names.join(", "))
+        })
+    }
+}
+
+impl fmt::Display for EmitKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (name, _) = EMIT_KINDS.iter().find(|(_, kind)| kind == self).expect("every kind has a name");
+        write!(f, "{name}")
+    }
+}
+
+/// Returns whether `--emit-dir` asks for the artifacts to be written to stdout.
+pub fn is_stdout(emit_dir: Option<&Path>) -> bool {
+    emit_dir == Some(Path::new("-"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_every_kind_by_name() {
+        for (name, kind) in EMIT_KINDS {
+            assert_eq!(name.parse::<EmitKind>().unwrap(), *kind);
+            assert_eq!(kind.to_string(), *name);
+        }
+        assert!("ast@unknown".parse::<EmitKind>().is_err());
+    }
+}
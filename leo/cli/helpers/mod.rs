@@ -16,6 +16,10 @@
 
 pub use super::*;
 
+pub mod bindgen;
 pub mod context;
+pub mod emit;
 pub mod logger;
+pub mod source_map;
 pub mod updater;
+pub mod watch;
@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::BytecodeSourceMap;
+use leo_errors::{CliError, Result};
+use leo_package::outputs::MAIN_ALEO_FILE_NAME;
+
+use std::path::{Path, PathBuf};
+
+/// Returns the path of the source map of the Aleo file at `aleo_file_path`, e.g. `build/main.aleo.map`.
+pub fn source_map_path(aleo_file_path: &Path) -> PathBuf {
+    let mut path = aleo_file_path.as_os_str().to_owned();
+    path.push(".map");
+    PathBuf::from(path)
+}
+
+/// Writes the source map of the Aleo file at `aleo_file_path` next to it, and returns its path.
+pub fn write_source_map(aleo_file_path: &Path, source_map: &BytecodeSourceMap) -> Result<PathBuf> {
+    let path = source_map_path(aleo_file_path);
+    let json = serde_json::to_string_pretty(source_map).map_err(CliError::failed_to_write_file)?;
+    std::fs::write(&path, json).map_err(CliError::failed_to_write_file)?;
+    Ok(path)
+}
+
+/// Appends the Leo source of the instructions and registers that `error` mentions to `error`.
+/// The error is reported by snarkVM while running `function` of the `main.aleo` file in `build_directory`.
+pub fn explain_with_source_map(build_directory: &Path, function: &str, error: impl std::fmt::Display) -> String {
+    let error = error.to_string();
+    let explanation = std::fs::read_to_string(source_map_path(&build_directory.join(MAIN_ALEO_FILE_NAME)))
+        .ok()
+        .and_then(|json| serde_json::from_str::<BytecodeSourceMap>(&json).ok())
+        .and_then(|source_map| source_map.explain(function, &error));
+    match explanation {
+        Some(explanation) => format!("{error}\nLeo source:{explanation}"),
+        None => error,
+    }
+}
@@ -0,0 +1,162 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::cli::context::Context;
+use leo_errors::{CliError, Result};
+use leo_package::{
+    dependencies::MANIFEST_FILENAME,
+    imports::IMPORTS_DIRECTORY_NAME,
+    inputs::INPUTS_DIRECTORY_NAME,
+    source::SOURCE_DIRECTORY_NAME,
+};
+
+use colored::Colorize;
+use notify_debouncer_mini::{new_debouncer, notify::RecursiveMode, DebounceEventResult};
+use std::{
+    path::{Path, PathBuf},
+    sync::mpsc::{channel, Receiver},
+    time::Duration,
+};
+
+/// The time to wait for further changes after a change, so that saving several files triggers a single rebuild.
+const DEBOUNCE_TIMEOUT: Duration = Duration::from_millis(300);
+
+/// Runs `command`, and then runs it again whenever the sources, imports, inputs, or manifest of the package,
+/// or of any member of its workspace, change.
+/// Errors are reported without exiting, so that they can be fixed while watching.
+pub fn watch(context: Context, mut command: impl FnMut(Context) -> Result<()>) -> Result<()> {
+    // Commands may change the current directory, so the package path is fixed before the first run.
+    let path = context.dir()?;
+    let context = Context::new(Some(path.clone()))?;
+
+    let (sender, receiver) = channel();
+    let mut debouncer = new_debouncer(DEBOUNCE_TIMEOUT, sender).map_err(CliError::failed_to_watch)?;
+
+    // In a workspace, a change to any member may affect the command.
+    let packages: Vec<PathBuf> = match context.workspace()? {
+        Some(workspace) => workspace.members.into_iter().map(|member| member.path).collect(),
+        None => vec![path.clone()],
+    };
+    let watched = watched_paths(&packages);
+    for watched in watched.iter() {
+        debouncer.watcher().watch(watched, RecursiveMode::Recursive).map_err(CliError::failed_to_watch)?;
+    }
+
+    loop {
+        if let Err(err) = command(context.clone()) {
+            tracing::error!("{err}");
+        }
+        tracing::info!("👀 Watching {} for changes", path.display().to_string().dimmed());
+
+        // The watcher has stopped.
+        let Some(changed) = next_changes(&receiver, &watched)? else {
+            return Ok(());
+        };
+
+        // Separate the output of each run.
+        tracing::info!("{}", "─".repeat(80).dimmed());
+        for changed_path in changed.iter() {
+            let changed_path = changed_path.strip_prefix(&path).unwrap_or(changed_path);
+            tracing::info!("🔄 Changed {}", changed_path.display().to_string().dimmed());
+        }
+    }
+}
+
+/// Returns the sources, imports, inputs, and manifest of each package that exist.
+fn watched_paths(packages: &[PathBuf]) -> Vec<PathBuf> {
+    packages
+        .iter()
+        .flat_map(|package| {
+            [SOURCE_DIRECTORY_NAME, IMPORTS_DIRECTORY_NAME, INPUTS_DIRECTORY_NAME, MANIFEST_FILENAME]
+                .map(|name| package.join(name))
+        })
+        .filter(|path| path.exists())
+        .collect()
+}
+
+/// Returns whether a change to `path` should trigger a rebuild.
+/// The path must be within one of the `watched` paths, and must not be a hidden or backup file, e.g. a swap file.
+fn is_watched(path: &Path, watched: &[PathBuf]) -> bool {
+    let temporary = path.file_name().and_then(|name| name.to_str()).map_or(false, |name| {
+        name.starts_with('.') || name.ends_with('~') || name.ends_with(".swp") || name.ends_with(".tmp")
+    });
+    !temporary && watched.iter().any(|watched| path.starts_with(watched))
+}
+
+/// Waits for the next batch of debounced changes that touch a watched path, and returns the changed paths.
+/// Returns `None` once the watcher has stopped.
+fn next_changes(receiver: &Receiver<DebounceEventResult>, watched: &[PathBuf]) -> Result<Option<Vec<PathBuf>>> {
+    loop {
+        match receiver.recv() {
+            Ok(Ok(events)) => {
+                let mut changed: Vec<PathBuf> =
+                    events.into_iter().map(|event| event.path).filter(|path| is_watched(path, watched)).collect();
+                changed.sort();
+                changed.dedup();
+                if !changed.is_empty() {
+                    return Ok(Some(changed));
+                }
+            }
+            Ok(Err(err)) => return Err(CliError::failed_to_watch(err).into()),
+            Err(_) => return Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters_unwatched_and_temporary_paths() {
+        let package = PathBuf::from("/package");
+        let watched = vec![package.join("src"), package.join("program.json")];
+        assert!(is_watched(&package.join("src/main.leo"), &watched));
+        assert!(is_watched(&package.join("src/nested/lib.leo"), &watched));
+        assert!(is_watched(&package.join("program.json"), &watched));
+        assert!(!is_watched(&package.join("build/main.aleo"), &watched));
+        assert!(!is_watched(&package.join("outputs/main.ast.json"), &watched));
+        assert!(!is_watched(&package.join("src/.main.leo.swp"), &watched));
+        assert!(!is_watched(&package.join("src/main.leo~"), &watched));
+    }
+
+    #[test]
+    fn debounces_changes_into_a_single_batch() {
+        let directory = std::env::temp_dir().join(format!("leo-watch-{}", std::process::id()));
+        let source = directory.join(SOURCE_DIRECTORY_NAME);
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&source).unwrap();
+        let watched = watched_paths(&[directory.clone()]);
+        assert_eq!(watched, vec![source.clone()]);
+
+        let (sender, receiver) = channel();
+        let mut debouncer = new_debouncer(DEBOUNCE_TIMEOUT, sender).unwrap();
+        debouncer.watcher().watch(&source, RecursiveMode::Recursive).unwrap();
+
+        // Several saves within the debounce timeout, including one of an editor's swap file, make a single batch.
+        std::fs::write(source.join("main.leo"), "").unwrap();
+        std::fs::write(source.join(".main.leo.swp"), "").unwrap();
+        std::fs::write(source.join("lib.leo"), "").unwrap();
+        std::fs::write(source.join("main.leo"), "program").unwrap();
+        let changed = next_changes(&receiver, &watched).unwrap().unwrap();
+        assert_eq!(changed, vec![source.join("lib.leo"), source.join("main.leo")]);
+
+        // Once the watcher has stopped, there are no further changes.
+        drop(debouncer);
+        assert!(next_changes(&receiver, &watched).unwrap().is_none());
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -14,8 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::cli::{context::Context, helpers::source_map::explain_with_source_map, Build, Command, CurrentNetwork, Run};
 use leo_errors::Result;
-// use std::path::PathBuf;
+use leo_package::package::Package;
+use leo_span::symbol::create_session_if_not_set_then;
+
+use clap::Parser;
+use std::{
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
 
 /* use crate::{
     commands::{
@@ -44,6 +52,137 @@ pub fn format_event() -> Result<()> {
     Ok(())
 }
 
+/// The commands change the current directory, so they are run one at a time.
+static COMMAND_LOCK: Mutex<()> = Mutex::new(());
+
+/// Creates the package `name` from the `leo new` template in an empty temporary directory, and returns its path.
+fn create_package(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("leo-cli-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&path);
+    std::fs::create_dir_all(&path).unwrap();
+    Package::<CurrentNetwork>::initialize(name, &path).unwrap();
+    let manifest = format!(r#"{{"program": "{name}.aleo", "version": "0.0.0", "description": "", "license": "MIT"}}"#);
+    std::fs::write(path.join("program.json"), manifest).unwrap();
+    path
+}
+
+/// Runs the command parsed from `arguments` on the package at `path`, restoring the current directory afterwards.
+fn execute<C: Command + Parser>(path: &Path, arguments: &[&str]) -> Result<C::Output> {
+    let _lock = COMMAND_LOCK.lock().unwrap_or_else(|error| error.into_inner());
+    let current_dir = std::env::current_dir().unwrap();
+    let command = C::try_parse_from(arguments).unwrap();
+    let result = create_session_if_not_set_then(|_| command.execute(Context::new(Some(path.to_path_buf()))?));
+    std::env::set_current_dir(current_dir).unwrap();
+    result
+}
+
+#[test]
+fn run_errors_point_to_the_leo_source() {
+    let path = create_package("explain");
+    execute::<Build>(&path, &["build"]).unwrap();
+
+    // An error in the form that snarkVM reports a failing instruction with.
+    let error = "Failed to evaluate instruction (add r0 r1 into r2;): Integer overflow on addition of two constants";
+    let explanation = explain_with_source_map(&path.join("build"), "main", error);
+    assert!(explanation.starts_with(error), "{explanation}");
+    assert!(explanation.contains("Leo source:"), "{explanation}");
+    assert!(explanation.contains("main.leo:4:"), "{explanation}");
+    assert!(explanation.contains("`let c: u32 = a + b;`"), "{explanation}");
+
+    // Errors that mention neither an instruction nor a register are left as they are.
+    let error = "'explain.aleo/main' is not satisfied on the given inputs";
+    assert_eq!(explain_with_source_map(&path.join("build"), "main", error), error);
+
+    // Running the program reports the error of snarkVM.
+    let error = execute::<Run>(&path, &["run", "main", "4294967295u32", "1u32"]).unwrap_err().to_string();
+    assert!(error.contains("is not satisfied on the given inputs"), "{error}");
+    std::fs::remove_dir_all(path).unwrap();
+}
+
+#[test]
+fn leo_snapshots_are_emitted_after_each_stage() {
+    let path = create_package("snapshots");
+    let program = "program snapshots.aleo {
+    transition main(public a: u32, b: u32) -> u8 {
+        let c: u32 = a;
+        if a > b {
+            c = a - b;
+        }
+        return (c + 1u32) as u8;
+    }
+}";
+    std::fs::write(path.join("src").join("main.leo"), program).unwrap();
+    let emit_dir = path.join("emitted");
+    execute::<Build>(&path, &["build", "--emit", "leo@all", "--emit-dir", emit_dir.to_str().unwrap()]).unwrap();
+
+    let snapshot = |stage: &str| std::fs::read_to_string(emit_dir.join(format!("snapshots.{stage}.leo"))).unwrap();
+    for stage in ["initial", "unrolled", "ssa", "flattened", "destructured", "inlined", "cse", "dce"] {
+        assert!(snapshot(stage).contains("transition main(public a: u32, b: u32) -> u8"), "{stage}");
+    }
+    let initial = snapshot("initial");
+    assert!(initial.contains("if (a > b) {") && initial.contains("return (c + 1u32) as u8;"), "{initial}");
+    // Static single assignment renames each assignment, and keeps the conditional.
+    let ssa = snapshot("ssa");
+    assert!(ssa.contains("if (") && ssa.contains("c$"), "{ssa}");
+    // Flattening replaces the conditional with a ternary.
+    let flattened = snapshot("flattened");
+    assert!(!flattened.contains("if (") && flattened.contains(" ? "), "{flattened}");
+    std::fs::remove_dir_all(path).unwrap();
+}
+
+#[test]
+fn json_and_toml_input_files_are_typed_by_the_transitions() {
+    let path = create_package("values");
+    let program = "program values.aleo {
+    struct Point {
+        x: u32,
+        y: u32,
+    }
+
+    transition main(public a: u32, b: u32) -> u32 {
+        return a + b;
+    }
+
+    transition sum(p: Point) -> u32 {
+        return p.x + p.y;
+    }
+}";
+    std::fs::write(path.join("src").join("main.leo"), program).unwrap();
+    let inputs = path.join("inputs");
+    std::fs::remove_file(inputs.join("values.in")).unwrap();
+
+    // Numbers may be written with or without their type, and structs as objects.
+    let json = r#"{ "main": { "a": 1, "b": "2u32" }, "sum": { "p": { "x": 3, "y": 4 } } }"#;
+    std::fs::write(inputs.join("values.json"), json).unwrap();
+    execute::<Run>(&path, &["run", "main"]).unwrap();
+    execute::<Run>(&path, &["run", "sum"]).unwrap();
+    let error = execute::<Run>(&path, &["run", "other"]).unwrap_err().to_string();
+    assert!(error.contains("`other` is not a transition of the program."), "{error}");
+
+    std::fs::write(inputs.join("values.json"), r#"{ "main": { "a": 1, "b": 4294967296 } }"#).unwrap();
+    let error = execute::<Run>(&path, &["run", "main"]).unwrap_err().to_string();
+    assert!(error.contains("The value `4294967296` of the input `main.b` is not a valid `u32`."), "{error}");
+    std::fs::remove_file(inputs.join("values.json")).unwrap();
+
+    std::fs::write(inputs.join("values.toml"), "[main]\na = 1\nb = \"2u32\"\n").unwrap();
+    execute::<Run>(&path, &["run", "main"]).unwrap();
+    let error = execute::<Run>(&path, &["run", "sum"]).unwrap_err().to_string();
+    assert!(error.contains("The input file has no values for the inputs of `sum`."), "{error}");
+    std::fs::remove_dir_all(path).unwrap();
+}
+
+#[test]
+fn stale_struct_caches_are_removed() {
+    let path = create_package("stale");
+    let structs = path.join("outputs").join("stale.structs.json");
+    std::fs::create_dir_all(path.join("outputs")).unwrap();
+    std::fs::write(&structs, "{}").unwrap();
+    execute::<Build>(&path, &["build"]).unwrap();
+    assert!(!structs.exists());
+    assert!(path.join("outputs").join("stale.declarations.json").exists());
+    std::fs::remove_dir_all(path).unwrap();
+}
+
 // todo (collin): uncomment after refactor
 // #[test]
 // pub fn build_pedersen_hash() -> Result<()> {
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The `leo.lock` file, which records the resolved dependencies of a package.
+
+use leo_errors::{PackageError, Result};
+
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub static LOCKFILE_FILENAME: &str = "leo.lock";
+
+/// A dependency resolved to a file, along with the checksum of its contents.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedDependency {
+    /// The name of the program.
+    pub name: String,
+    /// The version of the program, if it was resolved from the registry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// The file declaring the program, relative to the package if possible.
+    pub file: PathBuf,
+    /// The SHA-256 checksum of the file.
+    pub checksum: String,
+}
+
+/// The dependencies of a package, including the dependencies of dependencies, in resolution order.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lockfile {
+    #[serde(default, rename = "dependency")]
+    pub dependencies: Vec<LockedDependency>,
+}
+
+impl Lockfile {
+    pub fn exists_at(path: &Path) -> bool {
+        Self::setup_file_path(path).exists()
+    }
+
+    /// Returns the locked dependency `name`, if it exists.
+    pub fn get(&self, name: &str) -> Option<&LockedDependency> {
+        self.dependencies.iter().find(|dependency| dependency.name == name)
+    }
+
+    /// Reads the lockfile of the package at `path`, or an empty lockfile if it does not exist.
+    pub fn read_from(path: &Path) -> Result<Self> {
+        let path = Self::setup_file_path(path);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let string = fs::read_to_string(&path).map_err(|err| PackageError::failed_to_read_lockfile(&path, err))?;
+        Ok(toml::from_str(&string).map_err(|err| PackageError::failed_to_read_lockfile(&path, err))?)
+    }
+
+    /// Writes the lockfile to the package at `path`.
+    /// A package without dependencies does not have a lockfile.
+    pub fn write_to(&self, path: &Path) -> Result<()> {
+        let path = Self::setup_file_path(path);
+        if self.dependencies.is_empty() {
+            if path.exists() {
+                fs::remove_file(&path).map_err(PackageError::failed_to_write_lockfile)?;
+            }
+            return Ok(());
+        }
+
+        let string = toml::to_string(self).map_err(PackageError::failed_to_write_lockfile)?;
+        fs::write(&path, format!("{}{string}", Self::header())).map_err(PackageError::failed_to_write_lockfile)?;
+        Ok(())
+    }
+
+    fn header() -> &'static str {
+        "# This file is generated by Leo. Do not edit it by hand.\n\n"
+    }
+
+    fn setup_file_path(path: &Path) -> PathBuf {
+        let mut path = path.to_path_buf();
+        if path.is_dir() {
+            path.push(LOCKFILE_FILENAME);
+        }
+        path
+    }
+}
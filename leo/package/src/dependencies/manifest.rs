@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The `dependencies` section of the `program.json` manifest.
+
+use leo_errors::{PackageError, Result};
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub static MANIFEST_FILENAME: &str = "program.json";
+
+static DEPENDENCIES_KEY: &str = "dependencies";
+
+/// A program declared as a dependency in the manifest.
+/// A dependency is either a version of a program in the local registry, at `<registry>/<name>/<version>/`,
+/// or a path relative to the package, e.g. a Leo package, a git checkout, or a `.leo` or `.aleo` file.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Dependency {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+}
+
+/// The dependencies declared in a manifest, keyed by program name.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DependencyManifest {
+    pub dependencies: IndexMap<String, Dependency>,
+}
+
+impl DependencyManifest {
+    pub fn exists_at(path: &Path) -> bool {
+        Self::setup_file_path(path).exists()
+    }
+
+    /// Reads the dependencies declared in the manifest of the package at `path`.
+    /// A manifest without a `dependencies` section declares no dependencies.
+    pub fn read_from(path: &Path) -> Result<Self> {
+        let manifest = Self::read_manifest(path)?;
+        let dependencies = match manifest.get(DEPENDENCIES_KEY) {
+            Some(dependencies) => serde_json::from_value(dependencies.clone())
+                .map_err(|err| PackageError::failed_to_open_manifest(format!("invalid dependencies: {err}")))?,
+            None => IndexMap::new(),
+        };
+        Ok(Self { dependencies })
+    }
+
+    /// Writes the dependencies to the manifest of the package at `path`, preserving the rest of the manifest.
+    pub fn write_to(&self, path: &Path) -> Result<()> {
+        let mut manifest = Self::read_manifest(path)?;
+        let object = manifest
+            .as_object_mut()
+            .ok_or_else(|| PackageError::failed_to_open_manifest("the manifest must be a JSON object"))?;
+        if self.dependencies.is_empty() {
+            object.remove(DEPENDENCIES_KEY);
+        } else {
+            let dependencies =
+                serde_json::to_value(&self.dependencies).map_err(PackageError::failed_to_write_manifest)?;
+            object.insert(DEPENDENCIES_KEY.to_string(), dependencies);
+        }
+
+        let string = serde_json::to_string_pretty(&manifest).map_err(PackageError::failed_to_write_manifest)?;
+        fs::write(Self::setup_file_path(path), format!("{string}\n"))
+            .map_err(PackageError::failed_to_write_manifest)?;
+        Ok(())
+    }
+
+    fn read_manifest(path: &Path) -> Result<Value> {
+        let path = Self::setup_file_path(path);
+        let string = fs::read_to_string(&path)
+            .map_err(|err| PackageError::failed_to_open_manifest(format!("{}: {err}", path.display())))?;
+        Ok(serde_json::from_str(&string).map_err(PackageError::failed_to_open_manifest)?)
+    }
+
+    fn setup_file_path(path: &Path) -> PathBuf {
+        let mut path = path.to_path_buf();
+        if path.is_dir() {
+            path.push(MANIFEST_FILENAME);
+        }
+        path
+    }
+}
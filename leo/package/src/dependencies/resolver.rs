@@ -0,0 +1,206 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Resolves the dependencies declared in a manifest to files, without accessing the network.
+
+use crate::{
+    dependencies::{Dependency, DependencyManifest, LockedDependency, Lockfile},
+    file_checksum,
+    source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+use leo_errors::{PackageError, Result};
+
+use indexmap::IndexMap;
+use std::path::{Path, PathBuf};
+
+/// Resolves dependencies from the local registry and from local directories.
+///
+/// A dependency on a version of `name` resolves to the directory `<registry>/<name>/<version>/`,
+/// and a dependency on a path resolves to the path, relative to the package declaring the dependency.
+/// A directory is either a Leo package, whose own dependencies are resolved in turn,
+/// or a directory containing `<name>.leo` or `<name>.aleo`.
+#[derive(Clone, Debug, Default)]
+pub struct DependencyResolver {
+    /// The root directory of the local registry.
+    registry: Option<PathBuf>,
+}
+
+impl DependencyResolver {
+    pub fn new(registry: Option<PathBuf>) -> Self {
+        Self { registry }
+    }
+
+    /// Resolves the dependencies of the package at `package_root`, including the dependencies of dependencies.
+    /// The contents of a dependency from the registry must match the checksum in the existing lockfile, if any.
+    pub fn resolve(&self, package_root: &Path) -> Result<Lockfile> {
+        self.resolve_with(package_root, &DependencyManifest::read_from(package_root)?)
+    }
+
+    /// Resolves the given dependencies of the package at `package_root`, e.g. before they are written to its manifest.
+    pub fn resolve_with(&self, package_root: &Path, manifest: &DependencyManifest) -> Result<Lockfile> {
+        let existing = Lockfile::read_from(package_root)?;
+
+        let mut resolved: IndexMap<String, LockedDependency> = IndexMap::new();
+        let mut pending = vec![(package_root.to_path_buf(), manifest.clone())];
+
+        while let Some((root, manifest)) = pending.pop() {
+            for (name, dependency) in manifest.dependencies.iter() {
+                let location = self.locate(name, dependency, &root)?;
+                let (file, package) = find_program(name, &location)?;
+                let file = file.strip_prefix(package_root).map(Path::to_path_buf).unwrap_or(file);
+
+                // A program may be depended on by several packages, but it must always resolve to the same file.
+                if let Some(locked) = resolved.get(name) {
+                    if locked.file != file {
+                        return Err(
+                            PackageError::conflicting_dependency(name, locked.file.display(), file.display()).into()
+                        );
+                    }
+                    continue;
+                }
+
+                let checksum = file_checksum(&package_root.join(&file))?;
+
+                // The contents of a version in the registry must not change.
+                if let Some(locked) = existing.get(name) {
+                    if dependency.version.is_some()
+                        && locked.version == dependency.version
+                        && locked.checksum != checksum
+                    {
+                        return Err(PackageError::dependency_checksum_mismatch(name, &locked.checksum, checksum).into());
+                    }
+                }
+
+                resolved.insert(name.clone(), LockedDependency {
+                    name: name.clone(),
+                    version: dependency.version.clone(),
+                    file,
+                    checksum,
+                });
+
+                if let Some(package) = package {
+                    let manifest = DependencyManifest::read_from(&package)?;
+                    pending.push((package, manifest));
+                }
+            }
+        }
+
+        Ok(Lockfile { dependencies: resolved.into_values().collect() })
+    }
+
+    /// Returns the location of the dependency `name`, declared by the package at `root`.
+    fn locate(&self, name: &str, dependency: &Dependency, root: &Path) -> Result<PathBuf> {
+        match (&dependency.version, &dependency.path) {
+            (Some(version), None) => match &self.registry {
+                Some(registry) => Ok(registry.join(name).join(version)),
+                None => Err(PackageError::registry_not_found(name).into()),
+            },
+            (None, Some(path)) => Ok(root.join(path)),
+            _ => Err(PackageError::invalid_dependency(name).into()),
+        }
+    }
+}
+
+/// Returns the file declaring the program `name` at `location`, along with the root of its package, if any.
+fn find_program(name: &str, location: &Path) -> Result<(PathBuf, Option<PathBuf>)> {
+    if location.is_file() {
+        return match location.extension().and_then(|extension| extension.to_str()) {
+            Some("leo") | Some("aleo") => Ok((location.to_path_buf(), None)),
+            _ => Err(PackageError::dependency_not_found(name, location.display()).into()),
+        };
+    }
+
+    let main_file = location.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME);
+    if DependencyManifest::exists_at(location) && main_file.exists() {
+        return Ok((main_file, Some(location.to_path_buf())));
+    }
+
+    for extension in ["leo", "aleo"] {
+        let file = location.join(format!("{name}.{extension}"));
+        if file.exists() {
+            return Ok((file, None));
+        }
+    }
+
+    Err(PackageError::dependency_not_found(name, location.display()).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+
+    /// Creates an empty directory for a test.
+    fn test_directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("leo-dependencies-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    /// Creates a Leo package at `root` with the given dependencies.
+    fn write_package(root: &Path, name: &str, dependencies: &str) {
+        fs::create_dir_all(root.join(SOURCE_DIRECTORY_NAME)).unwrap();
+        fs::write(
+            root.join("program.json"),
+            format!("{{\"program\": \"{name}.aleo\", \"version\": \"0.0.0\", \"dependencies\": {dependencies}}}"),
+        )
+        .unwrap();
+        fs::write(root.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME), format!("program {name}.aleo {{}}")).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_registry_and_path_dependencies() {
+        let directory = test_directory("resolve");
+        let registry = directory.join("registry");
+
+        fs::create_dir_all(registry.join("token").join("0.1.0")).unwrap();
+        fs::write(registry.join("token").join("0.1.0").join("token.aleo"), "program token.aleo;").unwrap();
+        write_package(&directory.join("bank"), "bank", r#"{"token": {"version": "0.1.0"}}"#);
+        write_package(&directory.join("app"), "app", r#"{"bank": {"path": "../bank"}}"#);
+
+        let resolver = DependencyResolver::new(Some(registry.clone()));
+        let lockfile = resolver.resolve(&directory.join("app")).unwrap();
+        let names = lockfile.dependencies.iter().map(|dependency| dependency.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["bank", "token"]);
+        assert_eq!(lockfile.get("token").unwrap().version.as_deref(), Some("0.1.0"));
+
+        // Changing the contents of a version in the registry is detected.
+        lockfile.write_to(&directory.join("app")).unwrap();
+        fs::write(registry.join("token").join("0.1.0").join("token.aleo"), "program token.aleo;\n").unwrap();
+        assert!(resolver.resolve(&directory.join("app")).is_err());
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_manifest_dependencies_round_trip() {
+        let directory = test_directory("manifest");
+        write_package(&directory, "app", "{}");
+
+        let mut manifest = DependencyManifest::read_from(&directory).unwrap();
+        assert!(manifest.dependencies.is_empty());
+
+        manifest
+            .dependencies
+            .insert("token".to_string(), Dependency { version: Some("0.1.0".to_string()), path: None });
+        manifest.write_to(&directory).unwrap();
+        assert_eq!(DependencyManifest::read_from(&directory).unwrap(), manifest);
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
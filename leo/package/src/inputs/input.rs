@@ -28,10 +28,12 @@ use std::{
         {self},
     },
     io::Write,
-    path::Path,
+    path::{Path, PathBuf},
 };
 
 pub static INPUT_FILE_EXTENSION: &str = ".in";
+pub static JSON_INPUT_FILE_EXTENSION: &str = ".json";
+pub static TOML_INPUT_FILE_EXTENSION: &str = ".toml";
 
 #[derive(Deserialize)]
 pub struct InputFile {
@@ -61,6 +63,28 @@ impl InputFile {
         Ok((input, path))
     }
 
+    /// Returns the path of the JSON or TOML input file of the package, which is used in place of the `.in` file.
+    pub fn values_file_path(&self, path: &Path) -> Option<PathBuf> {
+        let directory = match path.ends_with(INPUTS_DIRECTORY_NAME) {
+            true => path.to_path_buf(),
+            false => path.join(INPUTS_DIRECTORY_NAME),
+        };
+        [JSON_INPUT_FILE_EXTENSION, TOML_INPUT_FILE_EXTENSION]
+            .iter()
+            .map(|extension| directory.join(format!("{}{extension}", self.package_name)))
+            .find(|path| path.exists())
+    }
+
+    /// Reads the values of a JSON or TOML input file, which maps the name of each transition to the values of its inputs.
+    pub fn read_values(path: &Path) -> Result<serde_json::Value> {
+        let string = fs::read_to_string(path).map_err(|_| PackageError::failed_to_read_input_file(path))?;
+        let values = match path.extension().map_or(false, |extension| extension == "toml") {
+            true => toml::from_str(&string).map_err(|error| error.to_string()),
+            false => serde_json::from_str(&string).map_err(|error| error.to_string()),
+        };
+        Ok(values.map_err(|error| PackageError::failed_to_parse_input_file(path.display(), error))?)
+    }
+
     /// Writes the standard input format to a file.
     pub fn write_to(self, path: &Path) -> Result<()> {
         let path = self.setup_file_path(path);
@@ -18,19 +18,39 @@
 #![doc = include_str!("../README.md")]
 
 pub mod build;
+pub mod dependencies;
 pub mod imports;
 pub mod inputs;
 pub mod outputs;
 pub mod package;
 pub mod root;
 pub mod source;
+pub mod workspace;
 
 use leo_errors::{PackageError, Result};
 
-use std::{fs, fs::ReadDir, path::PathBuf};
+use sha2::{Digest, Sha256};
+use std::{
+    fs,
+    fs::ReadDir,
+    path::{Path, PathBuf},
+};
 
 pub static LEO_FILE_EXTENSION: &str = ".leo";
 
+/// Returns the hex-encoded SHA-256 checksum of `bytes`.
+pub fn checksum(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    format!("{:x}", hasher.finalize())
+}
+
+/// Returns the hex-encoded SHA-256 checksum of the file at `path`.
+pub fn file_checksum(path: &Path) -> Result<String> {
+    let bytes = fs::read(path).map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
+    Ok(checksum(&bytes))
+}
+
 pub(crate) fn parse_file_paths(directory: ReadDir, file_paths: &mut Vec<PathBuf>) -> Result<()> {
     for file_entry in directory {
         let file_entry = file_entry.map_err(PackageError::failed_to_get_leo_file_entry)?;
@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The build fingerprint file, which records the inputs of the last build of a program.
+
+use crate::outputs::OUTPUTS_DIRECTORY_NAME;
+use leo_errors::{PackageError, Result};
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::{
+    borrow::Cow,
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub static FINGERPRINT_FILE_EXTENSION: &str = ".fingerprint";
+
+/// The checksums of the inputs of a build, e.g. the source, the imports, and the build options, keyed by input,
+/// along with the files written by the build.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Fingerprint {
+    pub inputs: IndexMap<String, String>,
+    #[serde(default)]
+    pub outputs: Vec<PathBuf>,
+}
+
+impl Fingerprint {
+    /// Records the checksum of the input `name`.
+    pub fn with_input(mut self, name: &str, checksum: String) -> Self {
+        self.inputs.insert(name.to_string(), checksum);
+        self
+    }
+
+    /// Records a file written by the build.
+    pub fn with_output(mut self, path: PathBuf) -> Self {
+        self.outputs.push(path);
+        self
+    }
+
+    /// Returns the inputs that differ from the `previous` fingerprint, in order.
+    pub fn changed_inputs<'a>(&'a self, previous: &Fingerprint) -> Vec<&'a str> {
+        let mut changed = self
+            .inputs
+            .iter()
+            .filter(|(name, checksum)| previous.inputs.get(*name) != Some(*checksum))
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>();
+        if changed.is_empty() && previous.inputs.len() != self.inputs.len() {
+            changed.extend(self.inputs.keys().map(String::as_str));
+        }
+        changed
+    }
+}
+
+pub struct FingerprintFile {
+    pub program_name: String,
+}
+
+impl FingerprintFile {
+    pub fn new(program_name: &str) -> Self {
+        Self { program_name: program_name.to_string() }
+    }
+
+    /// Reads the fingerprint from the given file path if it exists.
+    /// A fingerprint that cannot be read is treated as missing, so that the program is rebuilt.
+    pub fn read_from(&self, path: &Path) -> Option<Fingerprint> {
+        let path = self.setup_file_path(path);
+        let string = fs::read_to_string(path).ok()?;
+        serde_json::from_str(&string).ok()
+    }
+
+    /// Writes the given fingerprint to a file.
+    pub fn write_to(&self, path: &Path, fingerprint: &Fingerprint) -> Result<()> {
+        let path = self.setup_file_path(path);
+        let string = serde_json::to_string_pretty(fingerprint).map_err(PackageError::io_error_checksum_file)?;
+        fs::write(path, string).map_err(PackageError::io_error_checksum_file)?;
+        Ok(())
+    }
+
+    fn setup_file_path<'a>(&self, path: &'a Path) -> Cow<'a, Path> {
+        let mut path = Cow::from(path);
+        if path.is_dir() {
+            if !path.ends_with(OUTPUTS_DIRECTORY_NAME) {
+                path.to_mut().push(OUTPUTS_DIRECTORY_NAME);
+            }
+            path.to_mut().push(format!("{}{FINGERPRINT_FILE_EXTENSION}", self.program_name));
+        }
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fingerprint(source: &str, options: &str) -> Fingerprint {
+        Fingerprint::default().with_input("source", source.to_string()).with_input("build options", options.to_string())
+    }
+
+    #[test]
+    fn test_changed_inputs() {
+        let previous = fingerprint("a", "x");
+        assert!(fingerprint("a", "x").changed_inputs(&previous).is_empty());
+        assert_eq!(fingerprint("b", "x").changed_inputs(&previous), vec!["source"]);
+        assert_eq!(fingerprint("b", "y").changed_inputs(&previous), vec!["source", "build options"]);
+
+        // An input that is added has changed, and when an input is removed, every remaining input has changed.
+        let added = fingerprint("a", "x").with_input("imports", "z".to_string());
+        assert_eq!(added.changed_inputs(&previous), vec!["imports"]);
+        let removed = Fingerprint::default().with_input("source", "a".to_string());
+        assert_eq!(removed.changed_inputs(&previous), vec!["source"]);
+    }
+
+    #[test]
+    fn test_read_and_write() {
+        let directory = std::env::temp_dir().join(format!("leo-fingerprint-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(directory.join(OUTPUTS_DIRECTORY_NAME)).unwrap();
+        let file = FingerprintFile::new("test");
+        assert_eq!(file.read_from(&directory), None);
+
+        let written = fingerprint("a", "x").with_output(directory.join("build").join("main.aleo"));
+        file.write_to(&directory, &written).unwrap();
+        assert_eq!(file.read_from(&directory), Some(written.clone()));
+        assert_eq!(file.read_from(&directory.join(OUTPUTS_DIRECTORY_NAME)), Some(written));
+
+        // A fingerprint that cannot be read is treated as missing.
+        fs::write(directory.join(OUTPUTS_DIRECTORY_NAME).join("test.fingerprint"), "{").unwrap();
+        assert_eq!(file.read_from(&directory), None);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
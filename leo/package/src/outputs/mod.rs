@@ -26,4 +26,8 @@ pub use self::checksum::*;
 pub mod directory;
 pub use directory::*;
 
+pub mod fingerprint;
+pub use self::fingerprint::*;
+
 pub static MAIN_ALEO_FILE_NAME: &str = "main.aleo";
+pub static ABI_FILE_NAME: &str = "abi.json";
@@ -0,0 +1,239 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The `workspace.json` manifest, which lists the member packages of a workspace.
+
+use crate::{
+    dependencies::{DependencyManifest, MANIFEST_FILENAME},
+    source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+use leo_ast::NodeBuilder;
+use leo_errors::{emitter::Handler, PackageError, Result};
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+use indexmap::IndexSet;
+use serde::Deserialize;
+use serde_json::Value;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub static WORKSPACE_FILENAME: &str = "workspace.json";
+
+#[derive(Deserialize)]
+struct WorkspaceManifest {
+    members: Vec<PathBuf>,
+}
+
+/// A package in a workspace.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorkspaceMember {
+    /// The name of the program declared by the package, without the `.aleo` suffix.
+    pub name: String,
+    /// The root directory of the package.
+    pub path: PathBuf,
+}
+
+impl WorkspaceMember {
+    /// Returns the path to the `main.leo` file of the package.
+    pub fn main_file(&self) -> PathBuf {
+        self.path.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME)
+    }
+
+    /// Returns the names of the programs imported by the package, or declared in its manifest.
+    fn imports(&self) -> Result<IndexSet<String>> {
+        let main_file = self.main_file();
+        let source = fs::read_to_string(&main_file)
+            .map_err(|err| PackageError::failed_to_read_file(main_file.display(), err))?;
+
+        // The file is parsed, so that imports that are commented out are skipped, and imports may share a line.
+        let mut imports = create_session_if_not_set_then(|s| -> Result<IndexSet<String>> {
+            let source_file = s.source_map.new_source(&source, FileName::Real(main_file.clone()));
+            let program = leo_parser::parse(
+                &Handler::default(),
+                &NodeBuilder::default(),
+                &source_file.src,
+                source_file.start_pos,
+            )?;
+            Ok(program.imports.keys().map(|name| name.to_string()).collect())
+        })?;
+        imports.extend(DependencyManifest::read_from(&self.path)?.dependencies.into_keys());
+        Ok(imports)
+    }
+}
+
+/// A set of Leo packages that are built together, and may import each other's programs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Workspace {
+    /// The root directory of the workspace.
+    pub root: PathBuf,
+    /// The member packages, in the order in which they are listed.
+    pub members: Vec<WorkspaceMember>,
+}
+
+impl Workspace {
+    pub fn exists_at(path: &Path) -> bool {
+        path.join(WORKSPACE_FILENAME).exists()
+    }
+
+    /// Returns the workspace containing `path`, if any, by searching `path` and its ancestors.
+    pub fn find(path: &Path) -> Result<Option<Self>> {
+        match path.ancestors().find(|ancestor| Self::exists_at(ancestor)) {
+            Some(root) => Ok(Some(Self::open(root)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Opens the workspace at `root`, and reads the program name of each member.
+    pub fn open(root: &Path) -> Result<Self> {
+        let manifest_path = root.join(WORKSPACE_FILENAME);
+        let string = fs::read_to_string(&manifest_path)
+            .map_err(|err| PackageError::failed_to_read_workspace(&manifest_path, err))?;
+        let manifest: WorkspaceManifest =
+            serde_json::from_str(&string).map_err(|err| PackageError::failed_to_read_workspace(&manifest_path, err))?;
+
+        let members = manifest
+            .members
+            .iter()
+            .map(|member| {
+                let path = root.join(member);
+                let name = program_name(&path)?;
+                Ok(WorkspaceMember { name, path })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { root: root.to_path_buf(), members })
+    }
+
+    /// Returns the member whose program is `name`.
+    pub fn member(&self, name: &str) -> Option<&WorkspaceMember> {
+        let name = name.trim_end_matches(".aleo");
+        self.members.iter().find(|member| member.name == name)
+    }
+
+    /// Returns the member containing `path`, if any.
+    pub fn member_at(&self, path: &Path) -> Option<&WorkspaceMember> {
+        self.members.iter().find(|member| path.starts_with(&member.path))
+    }
+
+    /// Returns the members in the order in which they must be built, so that each member follows the members it imports.
+    pub fn build_order(&self) -> Result<Vec<&WorkspaceMember>> {
+        let mut order = Vec::with_capacity(self.members.len());
+        let mut visiting = IndexSet::new();
+        for member in self.members.iter() {
+            self.visit(member, &mut visiting, &mut order)?;
+        }
+        Ok(order)
+    }
+
+    /// Appends `member` to `order`, after the members it imports.
+    fn visit<'a>(
+        &'a self,
+        member: &'a WorkspaceMember,
+        visiting: &mut IndexSet<String>,
+        order: &mut Vec<&'a WorkspaceMember>,
+    ) -> Result<()> {
+        if order.contains(&member) {
+            return Ok(());
+        }
+        if !visiting.insert(member.name.clone()) {
+            let cycle = visiting.iter().skip_while(|name| **name != member.name).cloned().collect::<Vec<_>>();
+            return Err(PackageError::cyclic_workspace_members(cycle.join(" -> ")).into());
+        }
+
+        for import in member.imports()? {
+            if let Some(imported) = self.member(&import) {
+                self.visit(imported, visiting, order)?;
+            }
+        }
+
+        visiting.shift_remove(&member.name);
+        order.push(member);
+        Ok(())
+    }
+}
+
+/// Returns the name of the program declared in the manifest of the package at `path`, without the `.aleo` suffix.
+fn program_name(path: &Path) -> Result<String> {
+    let manifest_path = path.join(MANIFEST_FILENAME);
+    let string = fs::read_to_string(&manifest_path)
+        .map_err(|err| PackageError::failed_to_open_manifest(format!("{}: {err}", manifest_path.display())))?;
+    let manifest: Value = serde_json::from_str(&string).map_err(PackageError::failed_to_open_manifest)?;
+    match manifest.get("program").and_then(Value::as_str) {
+        Some(program) => Ok(program.trim_end_matches(".aleo").to_string()),
+        None => {
+            Err(PackageError::failed_to_open_manifest(format!("{} does not name a program", manifest_path.display()))
+                .into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates a Leo package at `root` whose program imports the given programs.
+    fn write_member(root: &Path, name: &str, imports: &[&str]) {
+        fs::create_dir_all(root.join(SOURCE_DIRECTORY_NAME)).unwrap();
+        fs::write(root.join(MANIFEST_FILENAME), format!("{{\"program\": \"{name}.aleo\", \"version\": \"0.0.0\"}}"))
+            .unwrap();
+        let imports = imports.iter().map(|import| format!("import {import}.aleo;\n")).collect::<String>();
+        fs::write(root.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME), format!("{imports}program {name}.aleo {{}}"))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_build_order() {
+        let root = std::env::temp_dir().join(format!("leo-workspace-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join(WORKSPACE_FILENAME), r#"{"members": ["vault", "token", "registry"]}"#).unwrap();
+        write_member(&root.join("vault"), "vault", &["token", "registry", "credits"]);
+        write_member(&root.join("token"), "token", &[]);
+        write_member(&root.join("registry"), "registry", &["token"]);
+
+        let workspace = Workspace::find(&root.join("vault").join("src")).unwrap().unwrap();
+        assert_eq!(workspace.member_at(&root.join("vault").join("src")).unwrap().name, "vault");
+        let order = workspace.build_order().unwrap().into_iter().map(|member| member.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(order, vec!["token", "registry", "vault"]);
+
+        // A member may not import itself through other members.
+        write_member(&root.join("token"), "token", &["vault"]);
+        assert!(workspace.build_order().is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_imports_are_parsed() {
+        let root = std::env::temp_dir().join(format!("leo-workspace-imports-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        write_member(&root, "vault", &[]);
+        let main = "// import ghost.aleo;
+/* import phantom.aleo;
+   import spectre.aleo; */
+import token.aleo; import registry.leo;
+program vault.aleo {}";
+        fs::write(root.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME), main).unwrap();
+
+        let member = WorkspaceMember { name: "vault".to_string(), path: root.clone() };
+        let imports = member.imports().unwrap().into_iter().collect::<Vec<_>>();
+        assert_eq!(imports, vec!["token", "registry"]);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
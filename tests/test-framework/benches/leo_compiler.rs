@@ -16,7 +16,7 @@
 
 //! This file contains tools for benchmarking the Leo compiler and its stages.
 
-use leo_compiler::{BuildOptions, Compiler, CompilerOptions, OutputOptions};
+use leo_compiler::{BuildOptions, Compiler, CompilerOptions, InliningStrategy, OutputOptions, VirtualResolver};
 use leo_errors::emitter::{Emitter, Handler};
 use leo_span::{source_map::FileName, symbol::SESSION_GLOBALS};
 use leo_test_framework::get_benches;
@@ -24,6 +24,7 @@ use leo_test_framework::get_benches;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use std::{
     path::PathBuf,
+    rc::Rc,
     time::{Duration, Instant},
 };
 
@@ -88,7 +89,15 @@ fn new_compiler(handler: &Handler) -> Compiler<'_> {
         PathBuf::from(String::new()),
         PathBuf::from(String::new()),
         Some(CompilerOptions {
-            build: BuildOptions { dce_enabled: true },
+            build: BuildOptions {
+                dce_enabled: true,
+                cse_enabled: false,
+                finalize_branching: false,
+                optimization_level: 0,
+                inlining_strategy: InliningStrategy::Always,
+                max_unrolled_iterations: None,
+                source_comments: false,
+            },
             output: OutputOptions {
                 symbol_table_spans_enabled: false,
                 initial_symbol_table: false,
@@ -102,9 +111,21 @@ fn new_compiler(handler: &Handler) -> Compiler<'_> {
                 flattened_ast: false,
                 destructured_ast: false,
                 inlined_ast: false,
+                cse_ast: false,
                 dce_ast: false,
+                initial_leo: false,
+                unrolled_leo: false,
+                ssa_leo: false,
+                flattened_leo: false,
+                destructured_leo: false,
+                inlined_leo: false,
+                cse_leo: false,
+                dce_leo: false,
+                stage_timings: false,
+                emit_to_stdout: false,
             },
         }),
+        Rc::new(VirtualResolver::default()),
     )
 }
 
@@ -276,6 +297,7 @@ impl Sample {
             compiler.flattening_pass(&symbol_table).expect("failed to run flattener pass");
             compiler.destructuring_pass().expect("failed to run destructurer pass");
             compiler.function_inlining_pass(&call_graph).expect("failed to run inliner pass");
+            compiler.common_subexpression_elimination_pass().expect("failed to run cse pass");
             let start = Instant::now();
             let out = compiler.dead_code_elimination_pass();
             let time = start.elapsed();
@@ -294,6 +316,7 @@ impl Sample {
             compiler.flattening_pass(&symbol_table).expect("failed to run flattener pass");
             compiler.destructuring_pass().expect("failed to run destructurer pass");
             compiler.function_inlining_pass(&call_graph).expect("failed to run inliner pass");
+            compiler.common_subexpression_elimination_pass().expect("failed to run cse pass");
             compiler.dead_code_elimination_pass().expect("failed to run dce pass");
             let start = Instant::now();
             let out = compiler.code_generation_pass(&symbol_table, &struct_graph, &call_graph);
@@ -316,6 +339,7 @@ impl Sample {
             compiler.flattening_pass(&symbol_table).expect("failed to run flattening pass");
             compiler.destructuring_pass().expect("failed to run destructuring pass");
             compiler.function_inlining_pass(&call_graph).expect("failed to run function inlining pass");
+            compiler.common_subexpression_elimination_pass().expect("failed to run cse pass");
             compiler.dead_code_elimination_pass().expect("failed to run dce pass");
             compiler
                 .code_generation_pass(&symbol_table, &struct_graph, &call_graph)